@@ -1,17 +1,17 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
-use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
 use crate::accounts::client::client_account::signer_account_params::SignerAccountParams;
-use crate::accounts::devol_account::DevolAccount;
 
 pub struct DvlIndexParam {
     pub id: u32,
 }
 
 pub struct DvlClientParam<'a> {
-    pub client_account: &'a ClientAccount,
+    pub client_account: &'a ClientAccountSnapshot,
 }
 
 pub struct DvlClientParams<'a> {
@@ -23,6 +23,10 @@ pub struct DvlClientParams<'a> {
 pub trait DvlReadable {
     type DvlReadParams<'a>;
 
+    /// Owned value returned by the readers: `Self` for fixed-size accounts, a snapshot for
+    /// accounts with an extendable tail (see `ClientAccountSnapshot`).
+    type DvlReadOutput: Send;
+
     async fn get_public_key<'a>(
         client: &DvlClient,
         params: &Self::DvlReadParams<'a>
@@ -33,25 +37,27 @@ pub trait DvlReadable {
     async fn read<'a>(
         client: &DvlClient,
         params: &Self::DvlReadParams<'a>
-    ) -> Result<Box<Self>, Box<dyn Error>>
+    ) -> Result<Box<Self::DvlReadOutput>, Box<dyn Error>>
+        where
+            Self: Sized;
+
+    /// Converts raw account data received from RPC, checking only tag, version, root and owner.
+    fn from_rpc_account(
+        client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<Self::DvlReadOutput>, Box<dyn Error>>
         where
             Self: Sized;
 
     async fn read_by_public_key(
         client: &DvlClient,
         public_key: &Pubkey,
-    ) -> Result<Box<Self>, Box<dyn Error>>
+    ) -> Result<Box<Self::DvlReadOutput>, Box<dyn Error>>
         where
-            Self: Sized + DevolAccount + Copy
+            Self: Sized
     {
-        let mut rpc_data = client.rpc_client.get_account(public_key).await?;
-        let account = Self::from_account_basic(
-            public_key,
-            &mut rpc_data,
-            &client.root_pda.key,
-            &client.program_id,
-        )?;
-
-        Ok(account)
+        let mut rpc_data = client.source.get_account(public_key).await?;
+        Self::from_rpc_account(client, public_key, &mut rpc_data)
     }
 }
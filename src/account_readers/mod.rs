@@ -11,4 +11,5 @@ pub mod read_tasks_log_account;
 pub mod read_pools_trace_account;
 pub mod read_pools_log_account;
 pub mod read_client_account;
-pub mod read_payoff_log_account;
\ No newline at end of file
+pub mod read_payoff_log_account;
+pub mod read_trade_log_account;
\ No newline at end of file
@@ -1,15 +1,18 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::account_readers::dvl_readable::{DvlReadable, DvlClientParams};
 use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
 use crate::generate_pda::dvl_generate_pda;
 
 #[async_trait]
 impl DvlReadable for ClientAccount
 {
     type DvlReadParams<'a> = DvlClientParams<'a>;
+    type DvlReadOutput = ClientAccountSnapshot;
 
     async fn get_public_key<'a>(
         dvl_client: &DvlClient,
@@ -24,9 +27,9 @@ impl DvlReadable for ClientAccount
     async fn read<'a>(
         dvl_client: &DvlClient,
         params: &Self::DvlReadParams<'a>
-    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+    ) -> Result<Box<ClientAccountSnapshot>, Box<dyn Error>> where Self: Sized {
         let public_key = &*Self::get_public_key(dvl_client, params).await?;
-        let mut rpc_data = dvl_client.rpc_client.get_account(public_key).await?;
+        let mut rpc_data = dvl_client.source.get_account(public_key).await?;
         let account = Self::from_account(
             public_key,
             &mut rpc_data,
@@ -36,6 +39,14 @@ impl DvlReadable for ClientAccount
         )?;
         Ok(account)
     }
+
+    fn from_rpc_account(
+        dvl_client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<ClientAccountSnapshot>, Box<dyn Error>> where Self: Sized {
+        Self::from_account(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id, None)
+    }
 }
 
 #[cfg(test)]
@@ -79,10 +90,8 @@ mod tests {
             signer_account_params: None,
         }).await?;
         println!("pools: {}", _client_account.get_pools_count());
-        for i in 0.._client_account.get_pools_count(){
-            println!("pool {} worker: {}, id: {}",i,
-                     _client_account.get_pool(i as usize)?.worker_id,
-                     _client_account.get_pool(i as usize)?.id);
+        for (i, pool) in _client_account.pools_iter().enumerate() {
+            println!("pool {} worker: {}, id: {}", i, pool.worker_id, pool.id);
         }
         Ok(())
     }
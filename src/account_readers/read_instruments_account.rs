@@ -1,21 +1,23 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::account_readers::dvl_readable::{DvlReadable};
-use crate::accounts::devol_regular_account::DevolRegularAccount;
+use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::instruments::instruments_account::InstrumentsAccount;
 use crate::accounts::root::root_account::RootAccount;
 
 #[async_trait]
 impl DvlReadable for InstrumentsAccount {
     type DvlReadParams<'a> = ();
+    type DvlReadOutput = Self;
 
     async fn get_public_key<'a>(
         dvl_client: &DvlClient,
         _params: &Self::DvlReadParams<'a>
     ) -> Result<Box<Pubkey>, Box<dyn Error>> where Self: Sized {
-        let root = dvl_client.get_account::<RootAccount>(()).await?;
+        let root = RootAccount::read(dvl_client, &()).await?;
         Ok(Box::from(root.instruments_address))
     }
 
@@ -23,15 +25,19 @@ impl DvlReadable for InstrumentsAccount {
         dvl_client: &DvlClient,
         params: &Self::DvlReadParams<'a>
     ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        if let Some(account) = dvl_client.cached_account::<Self>() {
+            return Ok(account);
+        }
         let public_key = &*Self::get_public_key(dvl_client, params).await?;
-        let mut rpc_data = dvl_client.rpc_client.get_account(public_key).await?;
-        let account = Self::from_account(
-            public_key,
-            &mut rpc_data,
-            &dvl_client.root_pda.key,
-            &dvl_client.program_id,
-        )?;
-        Ok(account)
+        dvl_client.read_cacheable(public_key).await
+    }
+
+    fn from_rpc_account(
+        dvl_client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        Self::from_account_basic(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id)
     }
 }
 
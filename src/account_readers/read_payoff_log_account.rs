@@ -1,14 +1,17 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::account_readers::dvl_readable::{DvlClientParam, DvlReadable};
+use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::client::payoff_log::payoff_log_account::PayoffLogAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 
 #[async_trait]
 impl DvlReadable for PayoffLogAccount {
     type DvlReadParams<'a> = DvlClientParam<'a>;
+    type DvlReadOutput = Self;
 
     async fn get_public_key<'a>(
         _dvl_client: &DvlClient,
@@ -22,7 +25,7 @@ impl DvlReadable for PayoffLogAccount {
         params: &Self::DvlReadParams<'a>
     ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
         let public_key = &*Self::get_public_key(client, params).await?;
-        let mut rpc_data = client.rpc_client.get_account(public_key).await?;
+        let mut rpc_data = client.source.get_account(public_key).await?;
         let account = Self::from_account(
             public_key,
             &mut rpc_data,
@@ -32,6 +35,14 @@ impl DvlReadable for PayoffLogAccount {
         )?;
         Ok(account)
     }
+
+    fn from_rpc_account(
+        dvl_client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        Self::from_account_basic(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id)
+    }
 }
 
 #[cfg(test)]
@@ -1,9 +1,11 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::account_readers::dvl_readable::{DvlReadable, DvlIndexParam};
 use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
 use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
@@ -14,12 +16,13 @@ macro_rules! impl_dvl_readable {
             #[async_trait]
             impl DvlReadable for $struct_name {
                 type DvlReadParams<'a> = DvlIndexParam;
+                type DvlReadOutput = Self;
 
                 async fn get_public_key<'a>(
                     dvl_client: &DvlClient,
                     params: &Self::DvlReadParams<'a>
                 ) -> Result<Box<Pubkey>, Box<dyn Error>> where Self: Sized {
-                    let workers_account = dvl_client.get_account::<AllWorkersAccount>(()).await?;
+                    let workers_account = AllWorkersAccount::read(dvl_client, &()).await?;
                     let worker = workers_account.workers[params.id as usize];
                     Ok(Box::from(worker.pools_log_address))
                 }
@@ -29,7 +32,7 @@ macro_rules! impl_dvl_readable {
                     params: &Self::DvlReadParams<'a>
                 ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
                     let public_key = &*Self::get_public_key(dvl_client, params).await?;
-                    let mut rpc_data = dvl_client.rpc_client.get_account(public_key).await?;
+                    let mut rpc_data = dvl_client.source.get_account(public_key).await?;
                     let account =  Self::from_account(
                         public_key,
                         &mut rpc_data,
@@ -39,6 +42,14 @@ macro_rules! impl_dvl_readable {
                     )?;
                     Ok(account)
                 }
+
+                fn from_rpc_account(
+                    dvl_client: &DvlClient,
+                    public_key: &Pubkey,
+                    rpc_data: &mut Account,
+                ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+                    Self::from_account_basic(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id)
+                }
             }
         )+
     };
@@ -49,10 +60,48 @@ impl_dvl_readable!(PoolLogsAccount, PoolLogsAccountV8);
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::tests::tests::setup_memory_client;
     use crate::tests::tests::setup_devol_client;
     use std::error::Error;
     use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
     use crate::accounts::worker::pool_logs::v9::pools_log_account_v9::PoolLogsAccountV9;
+    use crate::accounts::root::root_account::RootAccount;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    #[tokio::test]
+    async fn test_read_pools_log_account_from_v8_data() -> Result<(), Box<dyn Error>> {
+        let (source, client) = setup_memory_client();
+        let root_key = client.root_pda.key;
+        let workers_key = Pubkey::new_unique();
+        let pools_log_key = Pubkey::new_unique();
+        source.insert_fixture(&DevolAccountBuilder::<RootAccount>::new()
+            .key(root_key)
+            .with(|root| root.workers_address = workers_key)
+            .build());
+        source.insert_fixture(&DevolAccountBuilder::<AllWorkersAccount>::new()
+            .key(workers_key)
+            .root(root_key)
+            .with(|workers| {
+                workers.workers[2].pools_log_address = pools_log_key;
+                workers.workers[3].pools_log_address = pools_log_key;
+            })
+            .build());
+        source.insert_fixture(&DevolAccountBuilder::<PoolLogsAccountV8>::new()
+            .key(pools_log_key)
+            .root(root_key)
+            .with(|pools_log| {
+                pools_log.worker_id = 2;
+                pools_log.count = 1;
+            })
+            .build());
+
+        let pools_log = client.get_account::<PoolLogsAccount>(DvlIndexParam { id: 2 }).await?;
+        assert_eq!(pools_log.header.version, PoolLogsAccount::expected_version());
+        assert_eq!(pools_log.worker_id, 2);
+        assert_eq!(pools_log.pools_count, 1);
+        assert!(client.get_account::<PoolLogsAccount>(DvlIndexParam { id: 3 }).await.is_err());
+        Ok(())
+    }
 
     #[cfg(not(feature = "pools_log_migration"))]
     #[tokio::test]
@@ -119,7 +168,7 @@ mod tests {
         fs::create_dir_all(&pools_backup_dir)?;
         for i in 0..=7 {
             let pubkey = &workers_account.workers[0].pools_log_address;
-            let _pool_log = dvl_client.rpc_client.get_account(pubkey).await?;
+            let _pool_log = dvl_client.source.get_account(pubkey).await?;
             let file_name = format!("{}/pool_log_{}.bin", pools_backup_dir, i);
             let mut file = File::create(file_name).expect("Unable to create file");
             file.write_all(&_pool_log.data).expect("Unable to write data");
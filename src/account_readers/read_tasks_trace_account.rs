@@ -1,8 +1,10 @@
 use std::error::Error;
 use async_trait::async_trait;
 use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::account_readers::dvl_readable::{DvlReadable, DvlIndexParam};
+use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
@@ -10,12 +12,13 @@ use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount
 #[async_trait]
 impl DvlReadable for TasksTraceAccount {
     type DvlReadParams<'a> = DvlIndexParam;
+    type DvlReadOutput = Self;
 
     async fn get_public_key<'a>(
         dvl_client: &DvlClient,
         params: &Self::DvlReadParams<'a>
     ) -> Result<Box<Pubkey>, Box<dyn Error>> where Self: Sized {
-        let workers_account = dvl_client.get_account::<AllWorkersAccount>(()).await?;
+        let workers_account = AllWorkersAccount::read(dvl_client, &()).await?;
         let worker = workers_account.workers[params.id as usize];
         Ok(Box::from(worker.tasks_trace_address))
     }
@@ -25,7 +28,7 @@ impl DvlReadable for TasksTraceAccount {
         params: &Self::DvlReadParams<'a>
     ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
         let public_key = &*Self::get_public_key(dvl_client, params).await?;
-        let mut rpc_data = dvl_client.rpc_client.get_account(public_key).await?;
+        let mut rpc_data = dvl_client.source.get_account(public_key).await?;
         let account =  Self::from_account(
             public_key,
             &mut rpc_data,
@@ -35,6 +38,14 @@ impl DvlReadable for TasksTraceAccount {
         )?;
         Ok(account)
     }
+
+    fn from_rpc_account(
+        dvl_client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        Self::from_account_basic(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id)
+    }
 }
 
 #[cfg(test)]
@@ -0,0 +1,179 @@
+use std::error::Error;
+use async_trait::async_trait;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_client::dvl_client_error::DvlClientError;
+use crate::account_readers::dvl_readable::{DvlClientParam, DvlReadable};
+use crate::accounts::client::trade_log::trade_log_account::TradeLogAccount;
+use crate::accounts::devol_account::DevolAccount;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+use crate::generate_pda::dvl_generate_pda;
+
+/// The trade log is found by scanning for the one holding the address of the client account
+/// (`main_address`), which is a PDA of the client wallet. The address found is remembered by the
+/// client, the scan is repeated only when the remembered account no longer matches.
+#[async_trait]
+impl DvlReadable for TradeLogAccount {
+    type DvlReadParams<'a> = DvlClientParam<'a>;
+    type DvlReadOutput = Self;
+
+    async fn get_public_key<'a>(
+        dvl_client: &DvlClient,
+        params: &Self::DvlReadParams<'a>
+    ) -> Result<Box<Pubkey>, Box<dyn Error>> where Self: Sized {
+        let client_pda = dvl_generate_pda(&params.client_account.owner_address, &dvl_client.main_seed, &dvl_client.program_id);
+        if let Some(public_key) = dvl_client.trade_logs.read().unwrap().get(&client_pda.key) {
+            return Ok(Box::from(*public_key));
+        }
+        Ok(Box::from(find_trade_log(dvl_client, &client_pda.key).await?))
+    }
+
+    async fn read<'a>(
+        dvl_client: &DvlClient,
+        params: &Self::DvlReadParams<'a>
+    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        let client_pda = dvl_generate_pda(&params.client_account.owner_address, &dvl_client.main_seed, &dvl_client.program_id);
+        let known_key = dvl_client.trade_logs.read().unwrap().get(&client_pda.key).copied();
+        if let Some(public_key) = known_key {
+            match read_trade_log(dvl_client, &public_key, &client_pda.key).await {
+                Ok(account) => return Ok(account),
+                Err(_) => {
+                    dvl_client.trade_logs.write().unwrap().remove(&client_pda.key);
+                }
+            }
+        }
+        let public_key = find_trade_log(dvl_client, &client_pda.key).await?;
+        read_trade_log(dvl_client, &public_key, &client_pda.key).await
+    }
+
+    fn from_rpc_account(
+        dvl_client: &DvlClient,
+        public_key: &Pubkey,
+        rpc_data: &mut Account,
+    ) -> Result<Box<Self>, Box<dyn Error>> where Self: Sized {
+        Self::from_account_basic(public_key, rpc_data, &dvl_client.root_pda.key, &dvl_client.program_id)
+    }
+}
+
+/// Scans for the trade log of the client account at `client_pda` and remembers its address.
+async fn find_trade_log(dvl_client: &DvlClient, client_pda: &Pubkey) -> Result<Pubkey, Box<dyn Error>> {
+    let keys = dvl_client.scan::<TradeLogAccount>().owner(client_pda).keys().await?;
+    match keys.as_slice() {
+        [] => Err(Box::new(DvlError::new_with_account(AccountTag::TradeLog, ContractError::AccountIsMissing))),
+        [public_key] => {
+            dvl_client.trade_logs.write().unwrap().insert(*client_pda, *public_key);
+            Ok(*public_key)
+        }
+        _ => Err(Box::new(DvlClientError::Ambiguous(keys))),
+    }
+}
+
+async fn read_trade_log(dvl_client: &DvlClient, public_key: &Pubkey, client_pda: &Pubkey) -> Result<Box<TradeLogAccount>, Box<dyn Error>> {
+    let mut rpc_data = dvl_client.source.get_account(public_key).await?;
+    let account = TradeLogAccount::from_rpc_account(dvl_client, public_key, &mut rpc_data)?;
+    if account.main_address != *client_pda {
+        return Err(Box::new(DvlError::new_with_account(AccountTag::TradeLog, ContractError::InvalidAccountId)));
+    }
+    Ok(account)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::account_readers::dvl_readable::DvlClientParams;
+    use crate::accounts::client::client_account::client_account::ClientAccount;
+    use crate::constants::test_constants::ADMIN_PUBLIC_KEY;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+    use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+    use crate::fixtures::devol_account_builder::FIXTURE_PROGRAM_ID;
+    use crate::tests::tests::setup_devol_client;
+    use std::str::FromStr;
+
+    #[tokio::test]
+    async fn test_read_trade_log_account() -> Result<(), Box<dyn Error>> {
+        let client = setup_devol_client();
+        let client_wallet_public_key = Pubkey::from_str(ADMIN_PUBLIC_KEY).unwrap();
+        let client_account = client.get_account::<ClientAccount>(DvlClientParams {
+            client_address: &client_wallet_public_key,
+            signer_account_params: None,
+        }).await?;
+
+        let _trade_log = client.get_account::<TradeLogAccount>(DvlClientParam {
+            client_account: &client_account,
+        }).await?;
+        Ok(())
+    }
+
+    #[test]
+    fn test_trade_logs_from_rpc_account() {
+        let client = setup_devol_client();
+        let fixture = DevolAccountBuilder::<TradeLogAccount>::new()
+            .program_id(client.program_id)
+            .root(client.root_pda.key)
+            .with(|account| account.count = 3)
+            .build();
+        let account = TradeLogAccount::from_rpc_account(&client, &fixture.key, &mut fixture.to_account()).unwrap();
+        assert_eq!(account.count, 3);
+
+        let fixture = DevolAccountBuilder::<TradeLogAccount>::new()
+            .program_id(client.program_id)
+            .root(Pubkey::new_unique())
+            .build();
+        assert!(TradeLogAccount::from_rpc_account(&client, &fixture.key, &mut fixture.to_account()).is_err());
+    }
+
+    #[tokio::test]
+    async fn test_read_trade_log_account_by_scan() {
+        let (source, client) = setup_memory_client();
+        let root = client.root_pda.key;
+        let owner = Pubkey::new_unique();
+        let client_pda = dvl_generate_pda(&owner, &client.main_seed, &client.program_id);
+        let client_account = ClientAccountSnapshot::from_account_data(ClientAccountBuilder::new().owner_address(owner).data()).unwrap();
+        source.insert_fixture(&DevolAccountBuilder::<TradeLogAccount>::new()
+            .root(root)
+            .with(|account| account.main_address = Pubkey::new_unique())
+            .build());
+        let params = DvlClientParam { client_account: &client_account };
+        assert!(client.get_account::<TradeLogAccount>(params).await.is_err());
+
+        let first = DevolAccountBuilder::<TradeLogAccount>::new()
+            .root(root)
+            .with(|account| {
+                account.main_address = client_pda.key;
+                account.count = 2;
+            })
+            .build();
+        source.insert_fixture(&first);
+        let params = DvlClientParam { client_account: &client_account };
+        assert_eq!(client.get_account::<TradeLogAccount>(params).await.unwrap().count, 2);
+
+        // The remembered address is read without a scan, which would now find two trade logs.
+        let second = DevolAccountBuilder::<TradeLogAccount>::new()
+            .root(root)
+            .with(|account| {
+                account.main_address = client_pda.key;
+                account.count = 5;
+            })
+            .build();
+        source.insert_fixture(&second);
+        let params = DvlClientParam { client_account: &client_account };
+        assert_eq!(*TradeLogAccount::get_public_key(&client, &params).await.unwrap(), first.key);
+        assert_eq!(client.get_account::<TradeLogAccount>(params).await.unwrap().count, 2);
+
+        let fresh_client = DvlClient::with_source(source.clone(), 1, client.admin_public_key, FIXTURE_PROGRAM_ID);
+        let params = DvlClientParam { client_account: &client_account };
+        let result = fresh_client.get_account::<TradeLogAccount>(params).await;
+        assert!(matches!(result, Err(DvlClientError::Ambiguous(keys)) if keys.len() == 2));
+
+        // A remembered account which is gone falls back to a scan.
+        source.remove(&first.key);
+        let params = DvlClientParam { client_account: &client_account };
+        assert_eq!(client.get_account::<TradeLogAccount>(params).await.unwrap().count, 5);
+        let params = DvlClientParam { client_account: &client_account };
+        assert_eq!(*TradeLogAccount::get_public_key(&client, &params).await.unwrap(), second.key);
+    }
+}
@@ -0,0 +1,23 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::account_header::AccountHeader;
+use crate::errors::AccountTag;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct AccountHeaderView {
+    pub tag: AccountTag,
+    pub version: u32,
+    #[serde(with = "base58_pubkey")]
+    pub root: Pubkey,
+}
+
+impl AccountHeaderView {
+    pub fn from_header(header: &AccountHeader) -> Self {
+        Self {
+            tag: u8::try_from(header.tag).map_or(AccountTag::AccountDecodeError, AccountTag::from_u8),
+            version: header.version,
+            root: header.root,
+        }
+    }
+}
@@ -0,0 +1,49 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::all_workers::all_workers_account::{AllWorkersAccount, MAX_ALL_WORKERS_COUNT};
+use crate::accounts::all_workers::worker::Worker;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WorkerAddressesView {
+    #[serde(with = "base58_pubkey")]
+    pub address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub pools_trace_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub tasks_trace_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub pools_log_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub tasks_log_address: Pubkey,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct AllWorkersAccountView {
+    pub header: AccountHeaderView,
+    pub workers: Vec<WorkerAddressesView>,
+}
+
+impl WorkerAddressesView {
+    pub fn from_worker(worker: &Worker) -> Self {
+        Self {
+            address: worker.address,
+            pools_trace_address: worker.pools_trace_address,
+            tasks_trace_address: worker.tasks_trace_address,
+            pools_log_address: worker.pools_log_address,
+            tasks_log_address: worker.tasks_log_address,
+        }
+    }
+}
+
+impl AllWorkersAccountView {
+    /// Only the first `count` workers are registered.
+    pub fn from_account(account: &AllWorkersAccount) -> Self {
+        let count = (account.count as usize).min(MAX_ALL_WORKERS_COUNT);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            workers: account.workers[..count].iter().map(WorkerAddressesView::from_worker).collect(),
+        }
+    }
+}
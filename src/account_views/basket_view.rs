@@ -0,0 +1,54 @@
+use serde::{Deserialize, Serialize};
+use crate::accounts::client::client_account::client_pool::ClientPool;
+use crate::accounts::client::client_account::client_pool_basket::{BasketData, CLIENT_POOL_MAX_BASKET_LENGTH};
+use crate::accounts::worker::pool_logs::pool_basket::{PoolRecordBasket, POOL_RECORD_BASKET_LENGTH};
+use crate::constants::VANILLA_MEMO_SIZE;
+use crate::utils::put_or_call::PutOrCall;
+
+/// Vanilla option of a traded basket, common for the client pools, trade logs and pool logs.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct BasketElementView {
+    pub strike: i64,
+    pub put_or_call: PutOrCall,
+    pub amount: i32,
+    pub vanilla_cost: i64,
+}
+
+impl BasketElementView {
+    pub fn from_basket_data(data: &BasketData, vanilla_cost: i64) -> Self {
+        Self {
+            strike: data.get_strike() as i64,
+            put_or_call: data.get_put_or_call(),
+            amount: data.get_amount(),
+            vanilla_cost,
+        }
+    }
+
+    /// Decodes the vanilla memo: the basket length followed by `BasketData` elements.
+    pub fn from_vanilla_memo(memo: &[u8; VANILLA_MEMO_SIZE], vanilla_cost: impl Fn(usize) -> i64) -> Vec<Self> {
+        BasketData::from_vanilla_memo(memo)
+            .enumerate()
+            .map(|(index, data)| Self::from_basket_data(&data, vanilla_cost(index)))
+            .collect()
+    }
+
+    pub fn from_client_pool(pool: &ClientPool) -> Vec<Self> {
+        let length = (pool.basket_length_memo as usize).min(CLIENT_POOL_MAX_BASKET_LENGTH);
+        pool.basket_memo[..length].iter()
+            .enumerate()
+            .map(|(index, data)| Self::from_basket_data(data, pool.get_vanilla_cost(index)))
+            .collect()
+    }
+
+    pub fn from_pool_record_basket(basket: &PoolRecordBasket) -> Vec<Self> {
+        let length = basket.length.clamp(0, POOL_RECORD_BASKET_LENGTH as i64) as usize;
+        basket.basket_elements[..length].iter()
+            .map(|element| Self {
+                strike: element.strike,
+                put_or_call: element.put_or_call,
+                amount: element.amount,
+                vanilla_cost: element.vanilla_cost,
+            })
+            .collect()
+    }
+}
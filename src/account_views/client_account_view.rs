@@ -0,0 +1,212 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::basket_view::BasketElementView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::client::client_account::client_account::MAX_CLIENT_LP_COUNT;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
+use crate::accounts::client::client_account::client_lp::ClientLp;
+use crate::accounts::client::client_account::client_mint::ClientMint;
+use crate::accounts::client::client_account::client_pool::ClientPool;
+use crate::accounts::client::client_account::client_sign_method::ClientSignMethod;
+use crate::accounts::client::client_account::kyc_status::KYCStatus;
+use crate::constants::{BOUNDS_COUNT, BUCKETS_COUNT};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ClientMintView {
+    pub available: i64,
+    pub blocked: i64,
+}
+
+/// `ClientLp` with the `FD` scaled pool share costs as decimals.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ClientLpView {
+    pub worker_id: u32,
+    pub task_id: u32,
+    pub counter: i64,
+    pub orig_counter: i64,
+    pub time: i64,
+    pub ps: u32,
+    pub ps_cost: f64,
+    pub ps_result: f64,
+    pub ps_trade_qty: i32,
+    pub ps_trade_cost: f64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ClientPoolView {
+    pub id: u32,
+    pub worker_id: u32,
+    pub instr_id: u32,
+    pub start: i64,
+    pub expiration: i64,
+    pub fractions: u32,
+    pub counter: i64,
+    pub orig_counter: i64,
+    pub time: i64,
+    pub depo: Vec<i64>,
+    pub cost: Vec<i64>,
+    pub result: Vec<i64>,
+    pub calls: Vec<u32>,
+    pub calls_cost: Vec<i64>,
+    pub calls_result: Vec<i64>,
+    pub puts: Vec<u32>,
+    pub puts_cost: Vec<i64>,
+    pub puts_result: Vec<i64>,
+    pub last_cost: i64,
+    pub last_fees: i64,
+    pub last_trade_quantity: Vec<i32>,
+    pub basket: Vec<BasketElementView>,
+    pub last_px: Vec<i64>,
+    pub strikes: Vec<i64>,
+    pub bounds: Vec<i64>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct ClientAccountView {
+    pub header: AccountHeaderView,
+    #[serde(with = "base58_pubkey")]
+    pub owner_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub signer_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub payoff_log: Pubkey,
+    pub id: u32,
+    pub ops_counter: i64,
+    pub sign_method: ClientSignMethod,
+    pub kyc_status: KYCStatus,
+    pub kyc_time: u32,
+    pub last_trade_day: u32,
+    pub last_trade_hour_since_epoch: u32,
+    pub hours_trade_volume: Vec<i64>,
+    pub refs: i64,
+    /// Indexed by the mint id.
+    pub mints: Vec<ClientMintView>,
+    pub lp: Vec<ClientLpView>,
+    pub pools: Vec<ClientPoolView>,
+}
+
+impl ClientMintView {
+    pub fn from_mint(mint: &ClientMint) -> Self {
+        Self {
+            available: mint.get_available(),
+            blocked: mint.get_blocked(),
+        }
+    }
+}
+
+impl ClientLpView {
+    pub fn from_lp(lp: &ClientLp) -> Self {
+        Self {
+            worker_id: lp.worker_id,
+            task_id: lp.task_id,
+            counter: lp.counter,
+            orig_counter: lp.orig_counter,
+            time: lp.time,
+            ps: lp.ps,
+            ps_cost: lp.get_ps_cost_f64(),
+            ps_result: lp.get_ps_result_f64(),
+            ps_trade_qty: lp.ps_trade_qty,
+            ps_trade_cost: lp.get_ps_trade_cost_f64(),
+        }
+    }
+}
+
+impl ClientPoolView {
+    pub fn from_pool(pool: &ClientPool) -> Self {
+        Self {
+            id: pool.id,
+            worker_id: pool.worker_id,
+            instr_id: pool.instr_id,
+            start: pool.get_start(),
+            expiration: pool.get_expiration(),
+            fractions: pool.fractions,
+            counter: pool.counter,
+            orig_counter: pool.orig_counter,
+            time: pool.time,
+            depo: pool.depo.to_vec(),
+            cost: pool.cost.to_vec(),
+            result: pool.result.to_vec(),
+            calls: pool.calls.to_vec(),
+            calls_cost: (0..BUCKETS_COUNT).map(|index| pool.get_calls_cost(index)).collect(),
+            calls_result: (0..BUCKETS_COUNT).map(|index| pool.get_calls_result(index)).collect(),
+            puts: pool.puts.to_vec(),
+            puts_cost: pool.puts_cost.to_vec(),
+            puts_result: pool.puts_result.to_vec(),
+            last_cost: pool.last_cost,
+            last_fees: pool.last_fees,
+            last_trade_quantity: pool.last_trade_quantity.to_vec(),
+            basket: BasketElementView::from_client_pool(pool),
+            last_px: (0..BUCKETS_COUNT).map(|index| pool.get_last_px(index)).collect(),
+            strikes: (0..BUCKETS_COUNT).map(|index| pool.get_strikes(index)).collect(),
+            bounds: (0..BOUNDS_COUNT).map(|index| pool.get_bounds(index)).collect(),
+        }
+    }
+}
+
+impl ClientAccountView {
+    /// Only the first `lp_count` liquidity provider records are kept.
+    pub fn from_snapshot(snapshot: &ClientAccountSnapshot) -> Self {
+        let lp_count = (snapshot.lp_count as usize).min(MAX_CLIENT_LP_COUNT);
+        Self {
+            header: AccountHeaderView::from_header(&snapshot.header),
+            owner_address: snapshot.owner_address,
+            signer_address: snapshot.signer_address,
+            payoff_log: snapshot.payoff_log,
+            id: snapshot.id,
+            ops_counter: snapshot.ops_counter,
+            sign_method: snapshot.sign_method,
+            kyc_status: snapshot.kyc_status,
+            kyc_time: snapshot.kyc_time,
+            last_trade_day: snapshot.last_trade_day,
+            last_trade_hour_since_epoch: snapshot.last_trade_hour_since_epoch,
+            hours_trade_volume: snapshot.hours_trade_volume.to_vec(),
+            refs: snapshot.refs,
+            mints: snapshot.mints.iter().map(ClientMintView::from_mint).collect(),
+            lp: snapshot.lp[..lp_count].iter().map(ClientLpView::from_lp).collect(),
+            pools: snapshot.pools.iter().map(ClientPoolView::from_pool).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::client::client_account::client_account::{ClientAccount, CLIENT_ACCOUNT_SIZE};
+    use crate::accounts::client::client_account::client_pool::CLIENT_POOL_SIZE;
+    use crate::accounts::devol_account::DevolAccount;
+
+    #[test]
+    fn test_client_account_view() {
+        let mut account = ClientAccount::default();
+        account.set_ops_counter(4);
+        account.owner_address = Pubkey::new_unique();
+        account.kyc_status = KYCStatus::Power;
+        account.lp_count = 1;
+        account.lp[0].set_ps_cost(3 << 18);
+        account.set_pools_count(1);
+        let mut pool = ClientPool::default();
+        pool.id = 6;
+        pool.set_bounds(BOUNDS_COUNT - 1, 12);
+        let mut data = vec![0u8; CLIENT_ACCOUNT_SIZE + CLIENT_POOL_SIZE];
+        let mut view = ClientAccount::view_mut(&mut data).unwrap();
+        view.write_record(0, CLIENT_ACCOUNT_SIZE, 0, &account).unwrap();
+        view.set_pool(0, &pool).unwrap();
+        let snapshot = ClientAccountSnapshot::from_account_data(&data).unwrap();
+
+        let view = ClientAccountView::from_snapshot(&snapshot);
+        assert_eq!(view.ops_counter, 4);
+        assert_eq!(view.lp.len(), 1);
+        assert_eq!(view.lp[0].ps_cost, 0.75);
+        assert_eq!(view.pools.len(), 1);
+        assert_eq!(view.pools[0].id, 6);
+        assert_eq!(view.pools[0].bounds[BOUNDS_COUNT - 1], 12);
+
+        let json = serde_json::to_value(&view).unwrap();
+        assert_eq!(json["owner_address"], account.owner_address.to_string());
+        assert_eq!(json["kyc_status"], "Power");
+        assert_eq!(json["sign_method"], "Wallet");
+        assert_eq!(json["header"]["tag"], "Client");
+        assert_eq!(serde_json::from_value::<ClientAccountView>(json).unwrap(), view);
+    }
+}
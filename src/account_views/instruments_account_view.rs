@@ -0,0 +1,53 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::{base58_pubkey, ticker_to_string};
+use crate::accounts::instruments::instruments_account::{InstrumentsAccount, INSTR_DATA_COUNT};
+use crate::accounts::instruments::instruments_data::InstrumentsData;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct InstrumentView {
+    #[serde(with = "base58_pubkey")]
+    pub spot_address: Pubkey,
+    pub mint_id: u32,
+    pub px_decimals: u32,
+    pub strike_decimals: u32,
+    pub asset_ticker: String,
+    pub assigned_oracle_num: i32,
+    pub oracle_time: i32,
+    pub workers_count: u32,
+    pub workers_data: Vec<u8>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct InstrumentsAccountView {
+    pub header: AccountHeaderView,
+    pub instruments: Vec<InstrumentView>,
+}
+
+impl InstrumentView {
+    pub fn from_instrument(instrument: &InstrumentsData) -> Self {
+        Self {
+            spot_address: instrument.spot_address,
+            mint_id: instrument.mint_id,
+            px_decimals: instrument.px_decimals,
+            strike_decimals: instrument.strike_decimals,
+            asset_ticker: ticker_to_string(&instrument.asset_ticker),
+            assigned_oracle_num: instrument.assigned_oracle_num,
+            oracle_time: instrument.oracle_time,
+            workers_count: instrument.workers_count,
+            workers_data: instrument.workers_data.to_vec(),
+        }
+    }
+}
+
+impl InstrumentsAccountView {
+    /// Only the first `count` instruments are registered.
+    pub fn from_account(account: &InstrumentsAccount) -> Self {
+        let count = (account.count as usize).min(INSTR_DATA_COUNT);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            instruments: account.data[..count].iter().map(InstrumentView::from_instrument).collect(),
+        }
+    }
+}
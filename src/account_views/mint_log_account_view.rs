@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::mints::mint_log::mint_log::MintLog;
+use crate::accounts::mints::mint_log::mint_log_account::{MintLogAccount, MINT_LOG_BUFFER_CAPACITY};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MintLogView {
+    pub id: i64,
+    pub time: i64,
+    pub event_type: u32,
+    #[serde(with = "base58_pubkey")]
+    pub pubkey: Pubkey,
+    pub own: i64,
+    pub client: i64,
+    pub sum: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MintLogAccountView {
+    pub header: AccountHeaderView,
+    #[serde(with = "base58_pubkey")]
+    pub mints_address: Pubkey,
+    pub mint_id: u32,
+    pub last: u32,
+    pub count: u32,
+    pub records: Vec<MintLogView>,
+}
+
+impl MintLogView {
+    pub fn from_log(log: &MintLog) -> Self {
+        Self {
+            id: log.get_id(),
+            time: log.get_time(),
+            event_type: log.event_type,
+            pubkey: log.pubkey,
+            own: log.get_own(),
+            client: log.get_client(),
+            sum: log.get_sum(),
+        }
+    }
+}
+
+impl MintLogAccountView {
+    pub fn from_account(account: &MintLogAccount) -> Self {
+        let count = (account.count as usize).min(MINT_LOG_BUFFER_CAPACITY);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            mints_address: account.mints_address,
+            mint_id: account.mint_id,
+            last: account.last,
+            count: account.count,
+            records: account.data[..count].iter().map(MintLogView::from_log).collect(),
+        }
+    }
+}
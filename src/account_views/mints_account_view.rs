@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::{base58_pubkey, ticker_to_string};
+use crate::accounts::mints::mint::Mint;
+use crate::accounts::mints::mints_account::{MintsAccount, MAX_MINTS_COUNT};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MintView {
+    #[serde(with = "base58_pubkey")]
+    pub address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub program_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub log_address: Pubkey,
+    pub ops_counter: i64,
+    pub ticker: String,
+    pub own: i64,
+    pub client: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct MintsAccountView {
+    pub header: AccountHeaderView,
+    pub mints: Vec<MintView>,
+}
+
+impl MintView {
+    pub fn from_mint(mint: &Mint) -> Self {
+        Self {
+            address: mint.address,
+            program_address: mint.program_address,
+            log_address: mint.log_address,
+            ops_counter: mint.get_ops_counter(),
+            ticker: ticker_to_string(&mint.ticker),
+            own: mint.get_own(),
+            client: mint.get_client(),
+        }
+    }
+}
+
+impl MintsAccountView {
+    /// Only the first `count` mints are registered.
+    pub fn from_account(account: &MintsAccount) -> Self {
+        let count = (account.count as usize).min(MAX_MINTS_COUNT);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            mints: account.data[..count].iter().map(MintView::from_mint).collect(),
+        }
+    }
+}
@@ -0,0 +1,16 @@
+pub mod serde_helpers;
+pub mod account_header_view;
+pub mod basket_view;
+pub mod root_account_view;
+pub mod mints_account_view;
+pub mod mint_log_account_view;
+pub mod instruments_account_view;
+pub mod all_workers_account_view;
+pub mod worker_account_view;
+pub mod pools_trace_account_view;
+pub mod tasks_trace_account_view;
+pub mod pool_logs_account_view;
+pub mod tasks_log_account_view;
+pub mod client_account_view;
+pub mod trade_log_account_view;
+pub mod payoff_log_account_view;
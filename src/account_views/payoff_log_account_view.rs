@@ -0,0 +1,57 @@
+use serde::{Deserialize, Serialize};
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::accounts::client::payoff_log::payoff_log::PayoffLog;
+use crate::accounts::client::payoff_log::payoff_log_account::{PayoffLogAccount, PAYOFF_LOG_BUFFER_CAPACITY};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PayoffLogView {
+    pub id: i64,
+    pub worker_id: u32,
+    pub pool_id: u32,
+    pub instr_id: u32,
+    pub trade_time: i64,
+    pub update_time: i64,
+    pub settlement_px: i64,
+    pub payoff: i64,
+    pub unblocked_collateral: i64,
+    pub result: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PayoffLogAccountView {
+    pub header: AccountHeaderView,
+    pub id: u32,
+    pub last: u32,
+    pub count: u32,
+    pub records: Vec<PayoffLogView>,
+}
+
+impl PayoffLogView {
+    pub fn from_log(log: &PayoffLog) -> Self {
+        Self {
+            id: log.get_id(),
+            worker_id: log.worker_id,
+            pool_id: log.pool_id,
+            instr_id: log.instr_id,
+            trade_time: log.get_trade_time(),
+            update_time: log.get_update_time(),
+            settlement_px: log.get_settlement_px(),
+            payoff: log.get_payoff(),
+            unblocked_collateral: log.get_unblocked_collateral(),
+            result: log.get_result(),
+        }
+    }
+}
+
+impl PayoffLogAccountView {
+    pub fn from_account(account: &PayoffLogAccount) -> Self {
+        let count = (account.count as usize).min(PAYOFF_LOG_BUFFER_CAPACITY);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            id: account.id,
+            last: account.last,
+            count: account.count,
+            records: account.data[..count].iter().map(PayoffLogView::from_log).collect(),
+        }
+    }
+}
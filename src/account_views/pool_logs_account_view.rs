@@ -0,0 +1,69 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::basket_view::BasketElementView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
+use crate::accounts::worker::pool_logs::pool_logs_account::{PoolLogsAccount, POOLS_LOG_BUFFER_CAPACITY};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PoolLogRecordView {
+    pub worker_operations_count: u64,
+    pub pool_trades_count: u64,
+    pub pool_id: u32,
+    pub instrument_id: u32,
+    pub task_id: u32,
+    pub fractions: u32,
+    pub trade_time: i64,
+    #[serde(with = "base58_pubkey")]
+    pub client_pubkey: Pubkey,
+    pub cost: i64,
+    pub price_distribution: Vec<i64>,
+    pub trade_quantity: Vec<i32>,
+    pub basket_page: u16,
+    pub basket_pages_count: u16,
+    pub traded_basket: Vec<BasketElementView>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PoolLogsAccountView {
+    pub header: AccountHeaderView,
+    pub worker_id: u32,
+    pub last_pool_id: u32,
+    pub pools_count: u32,
+    pub records: Vec<PoolLogRecordView>,
+}
+
+impl PoolLogRecordView {
+    pub fn from_record(record: &PoolLogRecord) -> Self {
+        Self {
+            worker_operations_count: record.worker_operations_count,
+            pool_trades_count: record.pool_trades_count,
+            pool_id: record.pool_id,
+            instrument_id: record.instrument_id,
+            task_id: record.task_id,
+            fractions: record.fractions,
+            trade_time: record.trade_time,
+            client_pubkey: record.client_pubkey,
+            cost: record.cost,
+            price_distribution: record.price_distribution.to_vec(),
+            trade_quantity: record.trade_quantity.to_vec(),
+            basket_page: record.basket_page,
+            basket_pages_count: record.basket_pages_count,
+            traded_basket: BasketElementView::from_pool_record_basket(&record.traded_basket),
+        }
+    }
+}
+
+impl PoolLogsAccountView {
+    pub fn from_account(account: &PoolLogsAccount) -> Self {
+        let count = (account.pools_count as usize).min(POOLS_LOG_BUFFER_CAPACITY);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            worker_id: account.worker_id,
+            last_pool_id: account.last_pool_id,
+            pools_count: account.pools_count,
+            records: account.pool_log_records[..count].iter().map(PoolLogRecordView::from_record).collect(),
+        }
+    }
+}
@@ -0,0 +1,59 @@
+use serde::{Deserialize, Serialize};
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::accounts::worker::pools_trace::pools_trace::PoolsTrace;
+use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PoolsTraceView {
+    pub task_id: u32,
+    pub expiration: i64,
+    pub update_time: i64,
+    pub settlement_px: i64,
+    pub cps_px: i64,
+    pub ps: u32,
+    pub ps_px: i64,
+    pub task_fees: i64,
+    pub payoff_long: i64,
+    pub payoff_short: i64,
+    pub rest_of_payoff_long: i64,
+    pub rest_of_payoff_short: i64,
+    pub payoff_time: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct PoolsTraceAccountView {
+    pub header: AccountHeaderView,
+    pub worker_id: u32,
+    /// Indexed by the pool id.
+    pub pools: Vec<PoolsTraceView>,
+}
+
+impl PoolsTraceView {
+    pub fn from_trace(trace: &PoolsTrace) -> Self {
+        Self {
+            task_id: trace.task_id,
+            expiration: trace.get_expiration(),
+            update_time: trace.get_update_time(),
+            settlement_px: trace.get_settlement_px(),
+            cps_px: trace.get_cps_px(),
+            ps: trace.ps,
+            ps_px: trace.get_ps_px(),
+            task_fees: trace.get_task_fees(),
+            payoff_long: trace.get_payoff_long(),
+            payoff_short: trace.get_payoff_short(),
+            rest_of_payoff_long: trace.get_rest_of_payoff_long(),
+            rest_of_payoff_short: trace.get_rest_of_payoff_short(),
+            payoff_time: trace.get_payoff_time(),
+        }
+    }
+}
+
+impl PoolsTraceAccountView {
+    pub fn from_account(account: &PoolsTraceAccount) -> Self {
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            worker_id: account.worker_id,
+            pools: account.data.iter().map(PoolsTraceView::from_trace).collect(),
+        }
+    }
+}
@@ -0,0 +1,39 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::root::root_account::{OpenAccountFeePayer, RootAccount};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct RootAccountView {
+    pub header: AccountHeaderView,
+    #[serde(with = "base58_pubkey")]
+    pub wallet_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub kyc_provider: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub mints_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub instruments_address: Pubkey,
+    #[serde(with = "base58_pubkey")]
+    pub workers_address: Pubkey,
+    pub clients_count: u32,
+    pub fee_payer: OpenAccountFeePayer,
+    pub light_trader_daily_trade_limit: u64,
+}
+
+impl RootAccountView {
+    pub fn from_account(account: &RootAccount) -> Self {
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            wallet_address: account.wallet_address,
+            kyc_provider: account.kyc_provider,
+            mints_address: account.mints_address,
+            instruments_address: account.instruments_address,
+            workers_address: account.workers_address,
+            clients_count: account.clients_count,
+            fee_payer: account.fee_payer,
+            light_trader_daily_trade_limit: account.light_trader_daily_trade_limit,
+        }
+    }
+}
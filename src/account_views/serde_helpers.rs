@@ -0,0 +1,60 @@
+use crate::constants::FD;
+
+/// Serializes a `Pubkey` as a base58 string instead of a byte array.
+pub mod base58_pubkey {
+    use std::str::FromStr;
+    use serde::{de::Error, Deserialize, Deserializer, Serializer};
+    use solana_program::pubkey::Pubkey;
+
+    pub fn serialize<S: Serializer>(pubkey: &Pubkey, serializer: S) -> Result<S::Ok, S::Error> {
+        serializer.serialize_str(&pubkey.to_string())
+    }
+
+    pub fn deserialize<'de, D: Deserializer<'de>>(deserializer: D) -> Result<Pubkey, D::Error> {
+        let value = String::deserialize(deserializer)?;
+        Pubkey::from_str(&value).map_err(D::Error::custom)
+    }
+}
+
+/// Converts a value stored with `DECIMAL_SHIFT` fractional bits to a decimal. The conversion is
+/// exact while the value fits the 53 bits of the `f64` mantissa.
+#[inline(always)]
+pub fn fd_to_decimal(value: i64) -> f64 {
+    value as f64 / FD
+}
+
+/// Zero-padded ASCII ticker, e.g. `BTC\0\0\0\0\0`, as a string.
+pub fn ticker_to_string(ticker: &[u8; 8]) -> String {
+    let length = ticker.iter().position(|byte| *byte == 0).unwrap_or(ticker.len());
+    String::from_utf8_lossy(&ticker[..length]).into_owned()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use serde::{Deserialize, Serialize};
+    use solana_program::pubkey::Pubkey;
+
+    #[derive(Serialize, Deserialize, PartialEq, Debug)]
+    struct TestView {
+        #[serde(with = "base58_pubkey")]
+        key: Pubkey,
+    }
+
+    #[test]
+    fn test_base58_pubkey() {
+        let view = TestView { key: Pubkey::new_unique() };
+        let json = serde_json::to_string(&view).unwrap();
+        assert_eq!(json, format!("{{\"key\":\"{}\"}}", view.key));
+        assert_eq!(serde_json::from_str::<TestView>(&json).unwrap(), view);
+        assert!(serde_json::from_str::<TestView>("{\"key\":\"not base58\"}").is_err());
+    }
+
+    #[test]
+    fn test_fd_to_decimal_and_ticker() {
+        assert_eq!(fd_to_decimal(3 << 19), 1.5);
+        assert_eq!(fd_to_decimal(-(1 << 20)), -1.0);
+        assert_eq!(ticker_to_string(b"BTC\0\0\0\0\0"), "BTC");
+        assert_eq!(ticker_to_string(b"ABCDEFGH"), "ABCDEFGH");
+    }
+}
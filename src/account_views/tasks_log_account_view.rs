@@ -0,0 +1,66 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::worker::tasks_log::task_log::TasksLog;
+use crate::accounts::worker::tasks_log::tasks_log_account::{TasksLogAccount, TASKS_LOG_BUFFER_CAPACITY};
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TasksLogView {
+    pub id: i64,
+    pub time: i64,
+    pub event_type: u32,
+    pub task_id: u32,
+    pub pool_id: u32,
+    pub instr_id: u32,
+    pub counter: i64,
+    #[serde(with = "base58_pubkey")]
+    pub pubkey: Pubkey,
+    pub cost: i64,
+    pub trade_qty: i32,
+    pub trade_px: i64,
+    pub ps: u32,
+    pub ps_px: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TasksLogAccountView {
+    pub header: AccountHeaderView,
+    pub worker_id: u32,
+    pub last: u32,
+    pub count: u32,
+    pub records: Vec<TasksLogView>,
+}
+
+impl TasksLogView {
+    pub fn from_log(log: &TasksLog) -> Self {
+        Self {
+            id: log.get_id(),
+            time: log.get_time(),
+            event_type: log.event_type,
+            task_id: log.task_id,
+            pool_id: log.pool_id,
+            instr_id: log.instr_id,
+            counter: log.get_counter(),
+            pubkey: log.pubkey,
+            cost: log.get_cost(),
+            trade_qty: log.trade_qty,
+            trade_px: log.get_trade_px(),
+            ps: log.ps,
+            ps_px: log.get_ps_px(),
+        }
+    }
+}
+
+impl TasksLogAccountView {
+    pub fn from_account(account: &TasksLogAccount) -> Self {
+        let count = (account.count as usize).min(TASKS_LOG_BUFFER_CAPACITY);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            worker_id: account.worker_id,
+            last: account.last,
+            count: account.count,
+            records: account.data[..count].iter().map(TasksLogView::from_log).collect(),
+        }
+    }
+}
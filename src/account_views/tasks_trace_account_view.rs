@@ -0,0 +1,51 @@
+use serde::{Deserialize, Serialize};
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::accounts::worker::tasks_trace::tasks_trace::TasksTrace;
+use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TasksTraceView {
+    pub instr_id: u32,
+    pub first_time: i64,
+    pub duration: u32,
+    pub init_px: i64,
+    pub update_time: i64,
+    pub ps: u32,
+    pub ps_px: i64,
+    pub cancel_time: i64,
+    pub payoff_time: i64,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TasksTraceAccountView {
+    pub header: AccountHeaderView,
+    pub worker_id: u32,
+    /// Indexed by the task id.
+    pub tasks: Vec<TasksTraceView>,
+}
+
+impl TasksTraceView {
+    pub fn from_trace(trace: &TasksTrace) -> Self {
+        Self {
+            instr_id: trace.instr_id,
+            first_time: trace.get_first_time(),
+            duration: trace.duration,
+            init_px: trace.get_init_px(),
+            update_time: trace.get_update_time(),
+            ps: trace.ps,
+            ps_px: trace.get_ps_px(),
+            cancel_time: trace.get_cancel_time(),
+            payoff_time: trace.get_payoff_time(),
+        }
+    }
+}
+
+impl TasksTraceAccountView {
+    pub fn from_account(account: &TasksTraceAccount) -> Self {
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            worker_id: account.worker_id,
+            tasks: account.data.iter().map(TasksTraceView::from_trace).collect(),
+        }
+    }
+}
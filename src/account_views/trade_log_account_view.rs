@@ -0,0 +1,97 @@
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::basket_view::BasketElementView;
+use crate::account_views::serde_helpers::base58_pubkey;
+use crate::accounts::client::trade_log::trade_log::TradeLog;
+use crate::accounts::client::trade_log::trade_log_account::{TradeLogAccount, TRADE_LOG_BUFFER_CAPACITY};
+use crate::constants::BUCKETS_COUNT;
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TradeLogView {
+    pub id: i64,
+    pub time: i64,
+    pub fractions: u32,
+    pub worker_id: u32,
+    pub task_id: u32,
+    pub pool_id: u32,
+    pub instr_id: u32,
+    pub counter: i64,
+    pub cost: i64,
+    pub px: Vec<i64>,
+    pub qty: Vec<i32>,
+    pub basket: Vec<BasketElementView>,
+}
+
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct TradeLogAccountView {
+    pub header: AccountHeaderView,
+    #[serde(with = "base58_pubkey")]
+    pub main_address: Pubkey,
+    pub last: u32,
+    pub count: u32,
+    pub records: Vec<TradeLogView>,
+}
+
+impl TradeLogView {
+    pub fn from_log(log: &TradeLog) -> Self {
+        Self {
+            id: log.get_id(),
+            time: log.get_time(),
+            fractions: log.get_fractions(),
+            worker_id: log.get_worker_id(),
+            task_id: log.get_task_id(),
+            pool_id: log.get_pool_id(),
+            instr_id: log.get_instr_id(),
+            counter: log.get_counter(),
+            cost: log.get_cost(),
+            px: (0..BUCKETS_COUNT).map(|index| log.get_px(index)).collect(),
+            qty: (0..BUCKETS_COUNT).map(|index| log.get_qty(index)).collect(),
+            basket: BasketElementView::from_vanilla_memo(&log.vanilla_memo, |index| log.get_vanilla_cost(index)),
+        }
+    }
+}
+
+impl TradeLogAccountView {
+    pub fn from_account(account: &TradeLogAccount) -> Self {
+        let count = (account.count as usize).min(TRADE_LOG_BUFFER_CAPACITY);
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            main_address: account.main_address,
+            last: account.last,
+            count: account.count,
+            records: account.data[..count].iter().map(TradeLogView::from_log).collect(),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::utils::put_or_call::PutOrCall;
+
+    #[test]
+    fn test_trade_log_view() {
+        let mut log = TradeLog::default();
+        log.set_id(9);
+        log.set_px(3, -15);
+        log.vanilla_memo[0] = 2;
+        log.vanilla_memo[1..5].copy_from_slice(&100u32.to_ne_bytes());
+        log.vanilla_memo[13..17].copy_from_slice(&200u32.to_ne_bytes());
+        log.vanilla_memo[17..21].copy_from_slice(&(PutOrCall::PUT as u32).to_ne_bytes());
+        log.vanilla_memo[21..25].copy_from_slice(&(-4i32).to_ne_bytes());
+        log.set_vanilla_cost(1, 55);
+
+        let view = TradeLogView::from_log(&log);
+        assert_eq!(view.id, 9);
+        assert_eq!(view.px[3], -15);
+        assert_eq!(view.basket.len(), 2);
+        assert_eq!(view.basket[0].strike, 100);
+        assert_eq!(view.basket[0].put_or_call, PutOrCall::CALL);
+        assert_eq!(view.basket[1], BasketElementView { strike: 200, put_or_call: PutOrCall::PUT, amount: -4, vanilla_cost: 55 });
+
+        let json = serde_json::to_string(&view).unwrap();
+        assert!(json.contains("\"put_or_call\":\"PUT\""));
+        assert_eq!(serde_json::from_str::<TradeLogView>(&json).unwrap(), view);
+    }
+}
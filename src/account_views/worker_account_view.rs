@@ -0,0 +1,164 @@
+use serde::{Deserialize, Serialize};
+use crate::account_views::account_header_view::AccountHeaderView;
+use crate::account_views::serde_helpers::fd_to_decimal;
+use crate::accounts::worker::band::Band;
+use crate::accounts::worker::svm_params::SvmParams;
+use crate::accounts::worker::worker_account::WorkerAccount;
+use crate::accounts::worker::worker_state::WorkerState;
+
+/// `WorkerAccount` with decoded integers. The margins and the limits set by `StartNextPool` in
+/// `FD` units are decimals.
+#[derive(Clone, PartialEq, Debug, Serialize, Deserialize)]
+pub struct WorkerAccountView {
+    pub header: AccountHeaderView,
+    pub id: u32,
+    pub state: WorkerState,
+    pub task_id: u32,
+    pub instr_id: u32,
+    pub ops_counter: i64,
+    pub first_time: i64,
+    pub duration: u32,
+    pub init_px: i64,
+    pub own_ps: u32,
+    pub width_factor: i64,
+    pub fee_rate: i64,
+    pub fee_ratio: i64,
+    pub inventories_ratio: i64,
+    pub fractions: u32,
+    pub max_order: i64,
+    pub new_width_factor: i64,
+    pub new_fee_rate: i64,
+    pub new_fee_ratio: i64,
+    pub new_inventories_ratio: i64,
+    pub new_fractions: u32,
+    pub new_max_order: i64,
+    pub new_max_inventories: i64,
+    pub pool_time: u64,
+    pub lp_counter: u64,
+    pub last_lp_time: u64,
+    pub pool_size: i64,
+    pub cps: i64,
+    pub cps_px: i64,
+    pub cps_in_ps: i64,
+    pub ps: u32,
+    pub ps_px: i64,
+    pub task_fees: i64,
+    pub rest_of_fees: i64,
+    pub pool_id: u32,
+    pub start: i64,
+    pub expiration: i64,
+    pub pool_cashflow: i64,
+    pub settlement_px: u64,
+    pub pool_depo: i64,
+    pub pool_fees: u64,
+    pub pool_counter: u64,
+    pub pool_strikes: Vec<i64>,
+    pub pool_bounds: Vec<i64>,
+    pub pool_distrib: Vec<Band>,
+    pub svm_params: SvmParams,
+    pub margin_vega: f64,
+    pub margin_vanna: f64,
+    pub margin_volga: f64,
+    pub range_lr: f64,
+    pub w_lr: f64,
+    pub max_lr: f64,
+    pub max_pct_pool: f64,
+    pub perm_impact: f64,
+}
+
+impl WorkerAccountView {
+    pub fn from_account(account: &WorkerAccount) -> Self {
+        Self {
+            header: AccountHeaderView::from_header(&account.header),
+            id: account.id,
+            state: account.state,
+            task_id: account.task_id,
+            instr_id: account.instr_id,
+            ops_counter: account.ops_counter,
+            first_time: account.first_time,
+            duration: account.duration,
+            init_px: account.get_init_px(),
+            own_ps: account.own_ps,
+            width_factor: account.width_factor,
+            fee_rate: account.fee_rate,
+            fee_ratio: account.fee_ratio,
+            inventories_ratio: account.inventories_ratio,
+            fractions: account.fractions,
+            max_order: account.get_max_order(),
+            new_width_factor: account.get_new_width_factor(),
+            new_fee_rate: account.get_new_fee_rate(),
+            new_fee_ratio: account.get_new_fee_ratio(),
+            new_inventories_ratio: account.get_new_inventories_ratio(),
+            new_fractions: account.new_fractions,
+            new_max_order: account.new_max_order,
+            new_max_inventories: account.new_max_inventories,
+            pool_time: account.pool_time,
+            lp_counter: account.lp_counter,
+            last_lp_time: account.last_lp_time,
+            pool_size: account.pool_size,
+            cps: account.cps,
+            cps_px: account.cps_px,
+            cps_in_ps: account.cps_in_ps,
+            ps: account.ps,
+            ps_px: account.get_ps_px(),
+            task_fees: account.get_task_fees(),
+            rest_of_fees: account.get_rest_of_fees(),
+            pool_id: account.pool_id,
+            start: account.start,
+            expiration: account.expiration,
+            pool_cashflow: account.pool_cashflow,
+            settlement_px: account.settlement_px,
+            pool_depo: account.pool_depo,
+            pool_fees: account.pool_fees,
+            pool_counter: account.pool_counter,
+            pool_strikes: account.pool_strikes.to_vec(),
+            pool_bounds: account.pool_bounds.to_vec(),
+            pool_distrib: account.pool_distrib.to_vec(),
+            svm_params: account.svm_params,
+            margin_vega: fd_to_decimal(account.margin_vega),
+            margin_vanna: fd_to_decimal(account.margin_vanna),
+            margin_volga: fd_to_decimal(account.margin_volga),
+            range_lr: fd_to_decimal(account.range_lr),
+            w_lr: fd_to_decimal(account.w_lr),
+            max_lr: fd_to_decimal(account.max_lr),
+            max_pct_pool: fd_to_decimal(account.max_pct_pool),
+            perm_impact: account.perm_impact,
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::pubkey::Pubkey;
+    use crate::accounts::worker::worker_account::{WORKER_ACCOUNT_TAG, WORKER_ACCOUNT_VERSION};
+    use crate::constants::{BOUNDS_COUNT, BUCKETS_COUNT};
+    use crate::errors::AccountTag;
+
+    #[test]
+    fn test_worker_account_view() {
+        let mut account = WorkerAccount::default();
+        account.header.tag = WORKER_ACCOUNT_TAG as u32;
+        account.header.version = WORKER_ACCOUNT_VERSION;
+        account.header.root = Pubkey::new_unique();
+        account.state = WorkerState::Active;
+        account.set_init_px(42);
+        account.margin_vega = 5 << 19;
+        account.pool_strikes[BUCKETS_COUNT - 1] = 7;
+
+        let view = WorkerAccountView::from_account(&account);
+        assert_eq!(view.header.tag, AccountTag::Worker);
+        assert_eq!(view.init_px, 42);
+        assert_eq!(view.margin_vega, 2.5);
+        assert_eq!(view.pool_strikes.len(), BUCKETS_COUNT);
+        assert_eq!(view.pool_bounds.len(), BOUNDS_COUNT);
+
+        let json = serde_json::to_value(&view).unwrap();
+        assert_eq!(json["header"]["tag"], "Worker");
+        assert_eq!(json["header"]["root"], account.header.root.to_string());
+        assert_eq!(json["state"], "Active");
+        assert_eq!(json["margin_vega"], 2.5);
+        assert_eq!(json["pool_strikes"][BUCKETS_COUNT - 1], 7);
+        assert_eq!(serde_json::from_value::<WorkerAccountView>(json).unwrap(), view);
+    }
+}
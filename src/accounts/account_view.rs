@@ -0,0 +1,288 @@
+use std::alloc::{alloc, handle_alloc_error, Layout};
+use std::cell::{Ref, RefMut};
+use std::marker::PhantomData;
+use std::mem::{align_of, size_of, MaybeUninit};
+use solana_program::account_info::AccountInfo;
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Read-only view over raw account data laid out as `T`.
+///
+/// Account data is not guaranteed to be aligned for `T` (RPC buffers, packed tails like the
+/// `ClientPool` records at `CLIENT_ACCOUNT_POOLS_OFFSET`), so the view either copies values out
+/// with unaligned reads or hands out a reference only after checking the size and the alignment.
+/// Either way the data is first checked with `DevolParse::check_at`, so enum and `bool` fields
+/// never hold a value outside of their type.
+pub struct AccountView<'a, T> {
+    tag: AccountTag,
+    data: &'a [u8],
+    _layout: PhantomData<T>,
+}
+
+/// Mutable counterpart of `AccountView`.
+pub struct AccountViewMut<'a, T> {
+    tag: AccountTag,
+    data: &'a mut [u8],
+    _layout: PhantomData<T>,
+}
+
+impl<'a, T> AccountView<'a, T> {
+    /// `size` is the size of the fixed part of the account. It may be less than `size_of::<T>()`
+    /// only by the trailing padding of `T`, which is covered by the layout tests of each account.
+    #[inline(always)]
+    pub(crate) fn new(tag: AccountTag, data: &'a [u8], size: usize) -> Result<Self, DvlError> {
+        if data.len() < size {
+            return Err(DvlError::new_with_account(tag, ContractError::AccountSize));
+        }
+        Ok(Self { tag, data, _layout: PhantomData })
+    }
+
+    #[inline(always)]
+    pub fn data(&self) -> &'a [u8] { self.data }
+
+    #[inline(always)]
+    pub fn is_aligned(&self) -> bool { is_aligned::<T>(self.data.as_ptr()) }
+
+    /// Copies a value located at `offset` of the account data.
+    #[inline(always)]
+    pub fn read_at<U: DevolParse + Copy>(&self, offset: usize) -> Result<U, DvlError> {
+        self.read_record(offset, size_of::<U>(), 0)
+    }
+
+    /// Copies the `index` record of a packed array starting at `offset` with `size` stride.
+    /// As for the account itself, `size` may be less than `size_of::<U>()` only by the padding.
+    #[inline(always)]
+    pub(crate) fn read_record<U: DevolParse + Copy>(&self, offset: usize, size: usize, index: usize) -> Result<U, DvlError> {
+        let record_offset = check_range::<U>(self.tag, self.data.len(), offset, size, index)?;
+        check_value::<U>(self.tag, &self.data[record_offset..record_offset + size])?;
+        Ok(copy_out(&self.data[record_offset..], size))
+    }
+}
+
+impl<'a, T: DevolParse> AccountView<'a, T> {
+    /// Zero-copy reference to the account, fails if the data is shorter than `T`, misaligned or
+    /// holds an invalid value.
+    #[inline(always)]
+    pub fn as_ref(&self) -> Result<&'a T, DvlError> {
+        check_reference::<T>(self.tag, self.data.as_ptr(), self.data.len())?;
+        check_value::<T>(self.tag, self.data)?;
+        Ok(unsafe { &*(self.data.as_ptr() as *const T) })
+    }
+}
+
+impl<'a, T: DevolParse + Copy> AccountView<'a, T> {
+    /// Copies the account out of the data regardless of its alignment.
+    #[inline(always)]
+    pub fn read(&self) -> Result<T, DvlError> {
+        check_value::<T>(self.tag, self.data)?;
+        Ok(copy_out(self.data, self.data.len().min(size_of::<T>())))
+    }
+
+    /// Copies the account straight into a heap allocation, large accounts like the pools log
+    /// would not fit the stack otherwise.
+    pub fn read_boxed(&self) -> Result<Box<T>, DvlError> {
+        let layout = Layout::new::<T>();
+        if layout.size() == 0 {
+            return self.read().map(Box::new);
+        }
+        check_value::<T>(self.tag, self.data)?;
+        unsafe {
+            let ptr = alloc(layout) as *mut T;
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+            std::ptr::copy_nonoverlapping(self.data.as_ptr(), ptr as *mut u8, self.data.len().min(size_of::<T>()));
+            Ok(Box::from_raw(ptr))
+        }
+    }
+}
+
+impl<'a, T> AccountViewMut<'a, T> {
+    #[inline(always)]
+    pub(crate) fn new(tag: AccountTag, data: &'a mut [u8], size: usize) -> Result<Self, DvlError> {
+        if data.len() < size {
+            return Err(DvlError::new_with_account(tag, ContractError::AccountSize));
+        }
+        Ok(Self { tag, data, _layout: PhantomData })
+    }
+
+    #[inline(always)]
+    pub fn as_view(&self) -> AccountView<'_, T> {
+        AccountView { tag: self.tag, data: self.data, _layout: PhantomData }
+    }
+
+    /// Writes a value at `offset` of the account data field by field, the bytes under its padding
+    /// are left as they are.
+    #[inline(always)]
+    pub fn write_at<U: DevolWrite>(&mut self, offset: usize, value: &U) -> Result<(), DvlError> {
+        self.write_record(offset, size_of::<U>(), 0, value)
+    }
+
+    /// Writes the `index` record of a packed array starting at `offset` with `size` stride.
+    #[inline(always)]
+    pub(crate) fn write_record<U: DevolWrite>(&mut self, offset: usize, size: usize, index: usize, value: &U) -> Result<(), DvlError> {
+        let record_offset = check_range::<U>(self.tag, self.data.len(), offset, size, index)?;
+        let mut writer = LeWriter::new(self.tag, &mut self.data[record_offset..record_offset + size], size)?;
+        value.write_at(&mut writer, 0)
+    }
+}
+
+impl<'a, T: DevolParse> AccountViewMut<'a, T> {
+    /// Zero-copy mutable reference to the account, fails if the data is shorter than `T`,
+    /// misaligned or holds an invalid value.
+    #[inline(always)]
+    pub fn into_mut(self) -> Result<&'a mut T, DvlError> {
+        check_reference::<T>(self.tag, self.data.as_ptr(), self.data.len())?;
+        check_value::<T>(self.tag, self.data)?;
+        Ok(unsafe { &mut *(self.data.as_mut_ptr() as *mut T) })
+    }
+}
+
+/// The account data of `account_info` as a `T`, checked like `AccountView::as_ref`. The data
+/// stays borrowed from its `RefCell` as long as the returned guard lives.
+#[inline(always)]
+pub(crate) fn borrow_account<'a, T: DevolParse>(
+    tag: AccountTag,
+    account_info: &'a AccountInfo,
+    size: usize,
+) -> Result<Ref<'a, T>, DvlError> {
+    let data = account_info.data.borrow();
+    AccountView::<T>::new(tag, &data, size)?.as_ref()?;
+    Ok(Ref::map(data, |data| unsafe { &*(data.as_ptr() as *const T) }))
+}
+
+/// Mutable counterpart of `borrow_account`.
+#[inline(always)]
+pub(crate) fn borrow_account_mut<'a, T: DevolParse>(
+    tag: AccountTag,
+    account_info: &'a AccountInfo,
+    size: usize,
+) -> Result<RefMut<'a, T>, DvlError> {
+    let mut data = account_info.data.borrow_mut();
+    AccountViewMut::<T>::new(tag, &mut data, size)?.into_mut()?;
+    Ok(RefMut::map(data, |data| unsafe { &mut *(data.as_mut_ptr() as *mut T) }))
+}
+
+#[inline(always)]
+fn is_aligned<T>(ptr: *const u8) -> bool {
+    ptr.align_offset(align_of::<T>()) == 0
+}
+
+#[inline(always)]
+fn check_reference<T>(tag: AccountTag, ptr: *const u8, len: usize) -> Result<(), DvlError> {
+    if len < size_of::<T>() {
+        Err(DvlError::new_with_account(tag, ContractError::AccountSize))
+    } else if !is_aligned::<T>(ptr) {
+        Err(DvlError::new_with_account(tag, ContractError::AccountAlignment))
+    } else {
+        Ok(())
+    }
+}
+
+#[inline(always)]
+fn check_range<U>(tag: AccountTag, len: usize, offset: usize, size: usize, index: usize) -> Result<usize, DvlError> {
+    debug_assert!(size <= size_of::<U>());
+    let record_offset = size.checked_mul(index).and_then(|shift| shift.checked_add(offset));
+    match record_offset {
+        Some(record_offset) if record_offset.checked_add(size).is_some_and(|end| end <= len) => Ok(record_offset),
+        _ => Err(DvlError::new_with_account(tag, ContractError::AccountSize)),
+    }
+}
+
+/// Checks the enum and `bool` fields of the `U` at the start of `data`, see `DevolParse::check_at`.
+#[inline(always)]
+fn check_value<U: DevolParse>(tag: AccountTag, data: &[u8]) -> Result<(), DvlError> {
+    U::check_at(&LeParser::new(tag, data, 0)?, 0)
+}
+
+/// Copies the first `size` bytes of `data` into a new `U`, bytes above `size` are padding.
+/// `U` must have been checked with `check_value`.
+#[inline(always)]
+fn copy_out<U: Copy>(data: &[u8], size: usize) -> U {
+    let mut value = MaybeUninit::<U>::uninit();
+    unsafe {
+        std::ptr::copy_nonoverlapping(data[..size].as_ptr(), value.as_mut_ptr() as *mut u8, size);
+        value.assume_init()
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::account_header::AccountHeader;
+    use crate::accounts::devol_account::DevolAccount;
+    use crate::accounts::devol_layout::devol_layout;
+    use crate::accounts::worker::worker_account::{WORKER_ACCOUNT_STATE_OFFSET, WorkerAccount};
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    #[derive(Clone, Copy, PartialEq, Debug)]
+    #[repr(C)]
+    struct TestRecord {
+        id: u32,
+        value: i64,
+    }
+
+    devol_layout! {
+        TestRecord, TEST_RECORD_SIZE = 16, test_test_record_layout;
+        id: u32 = 0,
+        value: i64 = 8,
+    }
+
+    #[test]
+    fn test_account_view_misaligned() {
+        let record = TestRecord { id: 7, value: -42 };
+        let mut buffer = vec![0u64; 4];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, 32) };
+        let mut view = AccountViewMut::<TestRecord>::new(AccountTag::Client, &mut bytes[1..], 16).unwrap();
+        view.write_at(0, &record).unwrap();
+
+        let view = view.as_view();
+        assert!(!view.is_aligned());
+        assert_eq!(view.read().unwrap(), record);
+        assert_eq!(*view.read_boxed().unwrap(), record);
+        assert_eq!(view.read_at::<u32>(0).unwrap(), 7);
+        assert!(view.as_ref().is_err());
+        assert!(view.read_at::<TestRecord>(16).is_err());
+        assert!(view.read_record::<TestRecord>(0, 16, usize::MAX).is_err());
+    }
+
+    #[test]
+    fn test_account_view_aligned() {
+        let mut buffer = vec![0u64; 6];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, 48) };
+        let mut view = AccountViewMut::<TestRecord>::new(AccountTag::Client, &mut bytes[8..], 16).unwrap();
+        view.write_record(0, 16, 1, &TestRecord { id: 3, value: 9 }).unwrap();
+        assert_eq!(view.as_view().read_record::<TestRecord>(0, 16, 1).unwrap().value, 9);
+        view.into_mut().unwrap().id = 5;
+
+        let view = AccountView::<TestRecord>::new(AccountTag::Client, &bytes[8..], 16).unwrap();
+        assert_eq!(view.as_ref().unwrap().id, 5);
+        assert!(AccountView::<AccountHeader>::new(AccountTag::Client, &bytes[..8], 40).is_err());
+    }
+
+    #[test]
+    fn test_account_view_write_skips_padding() {
+        let mut bytes = [0xaau8; 16];
+        let mut view = AccountViewMut::<TestRecord>::new(AccountTag::Client, &mut bytes, 16).unwrap();
+        view.write_at(0, &TestRecord { id: 1, value: -1 }).unwrap();
+        assert_eq!(bytes[..8], [1, 0, 0, 0, 0xaa, 0xaa, 0xaa, 0xaa]);
+        assert_eq!(bytes[8..], [0xff; 8]);
+    }
+
+    #[test]
+    fn test_account_view_invalid_value() {
+        let mut fixture = DevolAccountBuilder::<WorkerAccount>::new().build();
+        fixture.data_mut()[WORKER_ACCOUNT_STATE_OFFSET..WORKER_ACCOUNT_STATE_OFFSET + 4].copy_from_slice(&7u32.to_le_bytes());
+        let mut buffer = vec![0u64; size_of::<WorkerAccount>().div_ceil(8)];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, size_of::<WorkerAccount>()) };
+        bytes[..fixture.data().len()].copy_from_slice(fixture.data());
+        let view = WorkerAccount::view(bytes).unwrap();
+        let expected = DvlError::new_with_account(AccountTag::Worker, ContractError::AccountFieldValue).encode();
+        assert_eq!(view.as_ref().err().unwrap().encode(), expected);
+        assert_eq!(view.read().err().unwrap().encode(), expected);
+        assert_eq!(view.read_boxed().err().unwrap().encode(), expected);
+        assert!(view.read_at::<u32>(WORKER_ACCOUNT_STATE_OFFSET).is_ok());
+        assert!(view.read_at::<AccountHeader>(0).is_ok());
+    }
+}
@@ -1,12 +1,6 @@
 use serde::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
-
-pub const WORKER_ADDRESS_OFFSET: usize = 0;
-pub const WORKER_POOLS_TRACE_ADDRESS_OFFSET: usize = 32;
-pub const WORKER_TASKS_TRACE_ADDRESS_OFFSET: usize = 64;
-pub const WORKER_POOLS_LOG_ADDRESS_OFFSET: usize = 96;
-pub const WORKER_TASKS_LOG_ADDRESS_OFFSET: usize = 128;
-pub const WORKER_SIZE: usize = 160;
+use crate::accounts::devol_layout::devol_layout;
 
 #[derive(Copy, Clone, Serialize, Deserialize)]
 #[repr(C)]
@@ -18,6 +12,15 @@ pub struct Worker {
     pub tasks_log_address: Pubkey,
 }
 
+devol_layout! {
+    Worker, WORKER_SIZE = 160, test_worker_layout;
+    address: Pubkey => WORKER_ADDRESS_OFFSET = 0,
+    pools_trace_address: Pubkey => WORKER_POOLS_TRACE_ADDRESS_OFFSET = 32,
+    tasks_trace_address: Pubkey => WORKER_TASKS_TRACE_ADDRESS_OFFSET = 64,
+    pools_log_address: Pubkey => WORKER_POOLS_LOG_ADDRESS_OFFSET = 96,
+    tasks_log_address: Pubkey => WORKER_TASKS_LOG_ADDRESS_OFFSET = 128,
+}
+
 #[cfg(test)]
 impl Default for Worker {
     fn default() -> Self {
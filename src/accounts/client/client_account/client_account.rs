@@ -1,8 +1,10 @@
-use std::cell::Ref;
+use std::cell::{Ref, RefMut};
 use std::error::Error;
 use solana_program::account_info::{Account, AccountInfo, IntoAccountInfo};
 use solana_program::pubkey::Pubkey;
 use crate::accounts::account_header::AccountHeader;
+use crate::accounts::account_view::{borrow_account, borrow_account_mut, AccountView, AccountViewMut};
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
 use crate::accounts::client::client_account::client_lp::ClientLp;
 use crate::accounts::client::client_account::client_mint::ClientMint;
 use crate::accounts::client::client_account::client_pool::{CLIENT_POOL_SIZE, ClientPool};
@@ -15,27 +17,10 @@ use crate::accounts::mints::mints_account::MAX_MINTS_COUNT;
 use crate::constants::HOURS;
 use crate::dvl_error::DvlError;
 use crate::errors::*;
+use crate::accounts::devol_layout::devol_layout;
 
-pub const CLIENT_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const CLIENT_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET: usize = 40;
-pub const CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET: usize = 72;
-pub const CLIENT_ACCOUNT_PAYOFF_LOG_OFFSET: usize = 104;
-pub const CLIENT_ACCOUNT_ID_OFFSET: usize = 136;
-pub const CLIENT_ACCOUNT_OPS_COUNTER_OFFSET: usize = 140;
-pub const CLIENT_ACCOUNT_SIGN_METHOD_OFFSET: usize = 148;
-pub const CLIENT_ACCOUNT_KYC_OFFSET: usize = 152;
-pub const CLIENT_ACCOUNT_KYC_TIME_OFFSET: usize = 160;
-pub const CLIENT_ACCOUNT_LAST_DAY_OFFSET: usize = 164;
-pub const CLIENT_ACCOUNT_LAST_HOUR_OFFSET: usize = 168;
-pub const CLIENT_ACCOUNT_LAST_TRADES_OFFSET: usize = 172;
-pub const CLIENT_ACCOUNT_REFS_OFFSET: usize = 364;
-pub const CLIENT_ACCOUNT_MINTS_OFFSET: usize = 372;
-pub const CLIENT_ACCOUNT_LP_COUNT_OFFSET: usize = 884;
-pub const CLIENT_ACCOUNT_LP_OFFSET: usize = 888;
-pub const CLIENT_ACCOUNT_POOLS_COUNT_OFFSET: usize = 9080;
 pub const CLIENT_ACCOUNT_POOLS_OFFSET: usize = 9084;
-pub const CLIENT_ACCOUNT_SIZE: usize = 9084;
 pub const CLIENT_ACCOUNT_TAG: u8 = 8;
 pub const CLIENT_ACCOUNT_VERSION: usize = 10;
 pub const MAX_CLIENT_LP_COUNT: usize = 128;
@@ -61,16 +46,36 @@ pub struct ClientAccount {
     pub lp_count: u32,                  // 4 bytes, CLIENT_ACCOUNT_LP_COUNT_OFFSET
     pub lp: [ClientLp; MAX_CLIENT_LP_COUNT],    // 8192 bytes, CLIENT_ACCOUNT_LP_OFFSET
     pub pools_count: [u8; 4],           // 4 bytes, CLIENT_ACCOUNT_POOLS_COUNT_OFFSET
-    /// WARNING!!! Unaligned, wrong address, use getter and setter!
+    /// WARNING!!! Unaligned, wrong address, use `AccountView::get_pool` and `AccountViewMut::set_pool`!
     pools: [ClientPool; 0],             // extendable size, CLIENT_ACCOUNT_POOLS_OFFSET
 }
 
+// `pools` is the zero-sized tail at `CLIENT_ACCOUNT_POOLS_OFFSET`, it is not a part of the table.
+devol_layout! {
+    ClientAccount, CLIENT_ACCOUNT_SIZE = 9084, test_client_account_layout;
+    header: AccountHeader => CLIENT_ACCOUNT_VERSION_OFFSET = 0,
+    owner_address: Pubkey => CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET = 40,
+    signer_address: Pubkey => CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET = 72,
+    payoff_log: Pubkey => CLIENT_ACCOUNT_PAYOFF_LOG_OFFSET = 104,
+    id: u32 => CLIENT_ACCOUNT_ID_OFFSET = 136,
+    ops_counter: [u8; 8] => CLIENT_ACCOUNT_OPS_COUNTER_OFFSET = 140,
+    sign_method: ClientSignMethod => CLIENT_ACCOUNT_SIGN_METHOD_OFFSET = 148,
+    kyc_status: KYCStatus => CLIENT_ACCOUNT_KYC_OFFSET = 152,
+    kyc_time: u32 => CLIENT_ACCOUNT_KYC_TIME_OFFSET = 160,
+    last_trade_day: u32 => CLIENT_ACCOUNT_LAST_DAY_OFFSET = 164,
+    last_trade_hour_since_epoch: u32 => CLIENT_ACCOUNT_LAST_HOUR_OFFSET = 168,
+    hours_trade_volume: [[u8; 8]; HOURS] => CLIENT_ACCOUNT_LAST_TRADES_OFFSET = 172,
+    refs: [u8; 8] => CLIENT_ACCOUNT_REFS_OFFSET = 364,
+    mints: [ClientMint; MAX_MINTS_COUNT] => CLIENT_ACCOUNT_MINTS_OFFSET = 372,
+    lp_count: u32 => CLIENT_ACCOUNT_LP_COUNT_OFFSET = 884,
+    lp: [ClientLp; MAX_CLIENT_LP_COUNT] => CLIENT_ACCOUNT_LP_OFFSET = 888,
+    pools_count: [u8; 4] => CLIENT_ACCOUNT_POOLS_COUNT_OFFSET = 9080,
+}
+
 impl DevolExpandableSizeAccount for ClientAccount {
     fn expected_expanded_size(account_data: Ref<&mut [u8]>) -> usize {
-        let account = unsafe { &*(account_data.as_ptr() as *const Self) };
-        let pools_count = account.get_pools_count();
-        let expected_size = CLIENT_ACCOUNT_SIZE + (pools_count as usize) * CLIENT_POOL_SIZE;
-        expected_size
+        Self::view(&account_data)
+            .map_or(usize::MAX, |view| CLIENT_ACCOUNT_SIZE + (view.get_pools_count() as usize) * CLIENT_POOL_SIZE)
     }
 }
 impl DevolAccount for ClientAccount {
@@ -119,40 +124,18 @@ impl ClientAccount {
         unsafe { *(self.hours_trade_volume.as_mut_ptr().add(index) as *mut [u8; 8]) = value.to_ne_bytes(); }
     }
 
-    #[inline(always)]
-    pub fn get_pool(&self, index: usize) -> Result<&ClientPool, DvlError> {
-        if index >= self.get_pools_count() as usize {
-            return Err(DvlError::new_with_account(AccountTag::Client, ContractError::PoolRecordNotFound));
-        }
-        let pools_count_ptr = self.pools_count.as_ptr();
-        let pools_ptr = unsafe {
-            pools_count_ptr.add(CLIENT_ACCOUNT_POOLS_OFFSET - CLIENT_ACCOUNT_POOLS_COUNT_OFFSET + CLIENT_POOL_SIZE * index) as *const ClientPool
-        };
-        Ok(unsafe { &*pools_ptr })
-    }
-
-    #[inline(always)]
-    pub fn get_pool_mut(&mut self, index: usize) -> Result<&mut ClientPool, DvlError> {
-        if index >= self.get_pools_count() as usize {
-            return Err(DvlError::new_with_account(AccountTag::Client, ContractError::PoolRecordNotFound));
-        }
-        let pools_count_ptr = self.pools_count.as_mut_ptr();
-        let pools_ptr = unsafe {
-            pools_count_ptr.add(CLIENT_ACCOUNT_POOLS_OFFSET - CLIENT_ACCOUNT_POOLS_COUNT_OFFSET + CLIENT_POOL_SIZE * index) as *mut ClientPool
-        };
-        Ok(unsafe { &mut *pools_ptr })
-    }
-
     #[inline(always)]
     fn check_signer(
-        account: &ClientAccount,
+        account: &AccountView<'_, ClientAccount>,
         signer_params: Option<&SignerAccountParams>,
     ) -> Result<(), DvlError> {
         if let Some(signer_params) = signer_params{
             let tag = AccountTag::from_u8(Self::expected_tag());
-            if signer_params.devol_sign && account.signer_address != *signer_params.signer {
+            let signer_address: Pubkey = account.read_at(CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET)?;
+            let owner_address: Pubkey = account.read_at(CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET)?;
+            if signer_params.devol_sign && signer_address != *signer_params.signer {
                 return Err(DvlError::new_with_account(tag, ContractError::AccountNotSigner))
-            } else if !signer_params.devol_sign && account.owner_address != *signer_params.signer {
+            } else if !signer_params.devol_sign && owner_address != *signer_params.signer {
                 return Err(DvlError::new_with_account(tag, ContractError::AccountNotSigner))
             }
         }
@@ -167,8 +150,7 @@ impl ClientAccount {
         signer_params: Option<&SignerAccountParams>,
     ) -> Result<(), DvlError> {
         Self::check_basic(account_info,root_addr,program_id)?;
-        let account = unsafe { &*(account_info.data.borrow().as_ptr() as *const Self) };
-        Self::check_signer(account, signer_params)?;
+        Self::check_signer(&Self::view(&account_info.data.borrow())?, signer_params)?;
         let tag = AccountTag::from_u8(Self::expected_tag());
         Self::check_expanded_size(tag, account_info.data.borrow())?;
         Ok(())
@@ -181,13 +163,12 @@ impl ClientAccount {
         root_addr: &Pubkey,
         program_id: &Pubkey,
         signer_params: Option<&SignerAccountParams>,
-    ) -> Result<&'a Self, DvlError>
+    ) -> Result<Ref<'a, Self>, DvlError>
         where
             Self: Sized,
     {
         Self::check_all(account_info, root_addr, program_id, signer_params)?;
-        let account = unsafe { &*(account_info.data.borrow().as_ptr() as *const Self) };
-        Ok(account)
+        borrow_account(AccountTag::from_u8(Self::expected_tag()), account_info, Self::expected_size())
     }
 
     /// Transforms `AccountInfo` into a mutable reference of `Self` for on-chain use with the intent to modify the data.
@@ -198,7 +179,7 @@ impl ClientAccount {
         root_addr: &Pubkey,
         program_id: &Pubkey,
         signer_params: Option<&SignerAccountParams>,
-    ) -> Result<&'a mut Self, DvlError>
+    ) -> Result<RefMut<'a, Self>, DvlError>
         where
             Self: Sized,
     {
@@ -206,11 +187,12 @@ impl ClientAccount {
         if !account_info.is_writable {
             return Err(DvlError::new_with_account(AccountTag::from_u8(Self::expected_tag()), ContractError::AccountWritableAttribute));
         }
-        let account = unsafe { &mut *(account_info.data.borrow_mut().as_ptr() as *mut Self) };
-        Ok(account)
+        borrow_account_mut(AccountTag::from_u8(Self::expected_tag()), account_info, Self::expected_size())
     }
 
-    /// Used off-chain to convert raw account data from RPC to a blockchain-utilized account structure.
+    /// Used off-chain to convert raw account data from RPC to an owned snapshot of the account.
+    /// Unlike the other accounts, a copy of `Self` would lose the pools tail, so all of the pools
+    /// are decoded into the `ClientAccountSnapshot`.
     #[inline(always)]
     pub fn from_account(
         key: &Pubkey,
@@ -218,13 +200,11 @@ impl ClientAccount {
         root_addr: &Pubkey,
         program_id: &Pubkey,
         signer_params: Option<&SignerAccountParams>,
-    ) -> Result<Box<Self>, Box<dyn Error>>
-        where
-            Self: Sized + Copy
-    {
+    ) -> Result<Box<ClientAccountSnapshot>, Box<dyn Error>> {
         let account_info = (key, account).into_account_info();
-        let account_ref = Self::from_account_info(&account_info, root_addr, program_id, signer_params)?;
-        Ok(Box::new(*account_ref))
+        Self::check_all(&account_info, root_addr, program_id, signer_params)?;
+        let snapshot = ClientAccountSnapshot::from_account_data(&account_info.data.borrow())?;
+        Ok(Box::new(snapshot))
     }
 
     #[cfg(test)]
@@ -263,10 +243,36 @@ impl Default for ClientAccount {
     }
 }
 
+/// Pool records are packed with `CLIENT_POOL_SIZE` stride starting at the unaligned
+/// `CLIENT_ACCOUNT_POOLS_OFFSET`, so they are only accessible through copies.
+impl<'a> AccountView<'a, ClientAccount> {
+    #[inline(always)]
+    pub fn get_pools_count(&self) -> u32 {
+        self.read_at::<[u8; 4]>(CLIENT_ACCOUNT_POOLS_COUNT_OFFSET).map_or(0, u32::from_ne_bytes)
+    }
+
+    #[inline(always)]
+    pub fn get_pool(&self, index: usize) -> Result<ClientPool, DvlError> {
+        if index >= self.get_pools_count() as usize {
+            return Err(DvlError::new_with_account(AccountTag::Client, ContractError::PoolRecordNotFound));
+        }
+        self.read_record(CLIENT_ACCOUNT_POOLS_OFFSET, CLIENT_POOL_SIZE, index)
+    }
+}
+
+impl<'a> AccountViewMut<'a, ClientAccount> {
+    #[inline(always)]
+    pub fn set_pool(&mut self, index: usize, pool: &ClientPool) -> Result<(), DvlError> {
+        if index >= self.as_view().get_pools_count() as usize {
+            return Err(DvlError::new_with_account(AccountTag::Client, ContractError::PoolRecordNotFound));
+        }
+        self.write_record(CLIENT_ACCOUNT_POOLS_OFFSET, CLIENT_POOL_SIZE, index, pool)
+    }
+}
+
 #[cfg(test)]
 mod tests {
     use std::cell::RefCell;
-    use std::mem;
     use std::rc::Rc;
     use std::str::FromStr;
     use crate::utils::type_size_helper::align_size;
@@ -274,34 +280,6 @@ mod tests {
     use solana_program::account_info::{AccountInfo};
     use crate::accounts::client::client_account::client_pool::{CLIENT_POOL_CALLS_RESULT_OFFSET, CLIENT_POOL_FRACTIONS_OFFSET, CLIENT_POOL_INSTR_ID_OFFSET};
     use crate::constants::test_constants;
-
-    #[test]
-    fn test_client_account_offsets() {
-        let account = ClientAccount::default();
-
-        let base_ptr = &account as *const _ as usize;
-
-        assert_eq!(&account.header as *const _ as usize - base_ptr, CLIENT_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.owner_address as *const _ as usize - base_ptr, CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET);
-        assert_eq!(&account.signer_address as *const _ as usize - base_ptr, CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET);
-        assert_eq!(&account.payoff_log as *const _ as usize - base_ptr, CLIENT_ACCOUNT_PAYOFF_LOG_OFFSET);
-        assert_eq!(&account.id as *const _ as usize - base_ptr, CLIENT_ACCOUNT_ID_OFFSET);
-        assert_eq!(&account.ops_counter as *const _ as usize - base_ptr, CLIENT_ACCOUNT_OPS_COUNTER_OFFSET);
-        assert_eq!(&account.sign_method as *const _ as usize - base_ptr, CLIENT_ACCOUNT_SIGN_METHOD_OFFSET);
-        assert_eq!(&account.kyc_status as *const _ as usize - base_ptr, CLIENT_ACCOUNT_KYC_OFFSET);
-        assert_eq!(&account.kyc_time as *const _ as usize - base_ptr, CLIENT_ACCOUNT_KYC_TIME_OFFSET);
-        assert_eq!(&account.last_trade_day as *const _ as usize - base_ptr, CLIENT_ACCOUNT_LAST_DAY_OFFSET);
-        assert_eq!(&account.last_trade_hour_since_epoch as *const _ as usize - base_ptr, CLIENT_ACCOUNT_LAST_HOUR_OFFSET);
-        assert_eq!(&account.hours_trade_volume as *const _ as usize - base_ptr, CLIENT_ACCOUNT_LAST_TRADES_OFFSET);
-        assert_eq!(&account.refs as *const _ as usize - base_ptr, CLIENT_ACCOUNT_REFS_OFFSET);
-        assert_eq!(&account.mints as *const _ as usize - base_ptr, CLIENT_ACCOUNT_MINTS_OFFSET);
-        assert_eq!(&account.lp as *const _ as usize - base_ptr, CLIENT_ACCOUNT_LP_OFFSET);
-        assert_eq!(&account.pools_count as *const _ as usize - base_ptr, CLIENT_ACCOUNT_POOLS_COUNT_OFFSET);
-        // WARNING: This test will not pass because of the alignment. Getter and setter use correct address.
-        // assert_eq!(&account.pools as *const _ as usize - base_ptr, CLIENT_ACCOUNT_POOLS_OFFSET);
-
-        assert_eq!(mem::size_of::<ClientAccount>(), align_size(CLIENT_ACCOUNT_SIZE, 8));
-    }
     #[test]
     fn test_client_account_pools() {
         let program_id = Pubkey::from_str(test_constants::PROGRAM_ID).unwrap();
@@ -310,33 +288,47 @@ mod tests {
         let mut lamports: u64 = 0;
 
         const TEST_POOLS_SIZE: usize = 10;
-        let total_size = align_size(CLIENT_ACCOUNT_SIZE + TEST_POOLS_SIZE * CLIENT_POOL_SIZE, 4);
+        let total_size = CLIENT_ACCOUNT_SIZE + TEST_POOLS_SIZE * CLIENT_POOL_SIZE;
 
-        let mut buffer_for_account = vec![0u8; total_size];
-        let account = unsafe { &mut *(buffer_for_account.as_mut_ptr() as *mut ClientAccount) };
-        let default_account = ClientAccount::default();
-        *account = default_account;
-        account.set_pools_count(TEST_POOLS_SIZE as u32);
+        // Backed by `u64` to get the alignment of the on-chain account data
+        let mut buffer = vec![0u64; align_size(total_size, 8) / 8];
+        let buffer_for_account = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, total_size) };
+        let mut default_account = ClientAccount::default();
+        default_account.set_pools_count(TEST_POOLS_SIZE as u32);
         let check_1 = (0, 228);
         let check_2 = (7, 69);
         let check_3 = (9, -999);
         let check_4 = (3, 200);
         let check_4_bucket = 50;
 
-        account.get_pool_mut(check_1.0).unwrap().fractions = check_1.1;
-        account.get_pool_mut(check_1.0).unwrap().instr_id = 1337;
-        account.get_pool_mut(check_2.0).unwrap().instr_id = check_2.1;
-        account.get_pool_mut(check_3.0).unwrap().last_cost = check_3.1;
-        account.get_pool_mut(check_4.0).unwrap().set_calls_result(check_4_bucket, check_4.1);
-
-        let key = &account.owner_address;
-        let signer = &account.signer_address;
-        let root = &account.header.root;
+        let mut view = ClientAccount::view_mut(buffer_for_account).unwrap();
+        view.write_record(0, CLIENT_ACCOUNT_SIZE, 0, &default_account).unwrap();
+        for index in 0..TEST_POOLS_SIZE {
+            view.set_pool(index, &ClientPool::default()).unwrap();
+        }
+        let mut pool = ClientPool::default();
+        pool.fractions = check_1.1;
+        pool.instr_id = 1337;
+        view.set_pool(check_1.0, &pool).unwrap();
+        pool = ClientPool::default();
+        pool.instr_id = check_2.1;
+        view.set_pool(check_2.0, &pool).unwrap();
+        pool = ClientPool::default();
+        pool.last_cost = check_3.1;
+        view.set_pool(check_3.0, &pool).unwrap();
+        pool = ClientPool::default();
+        pool.set_calls_result(check_4_bucket, check_4.1);
+        view.set_pool(check_4.0, &pool).unwrap();
+        assert!(view.set_pool(TEST_POOLS_SIZE, &pool).is_err());
+
+        let key = &default_account.owner_address;
+        let signer = &default_account.signer_address;
+        let root = &default_account.header.root;
 
         let account_info = AccountInfo{
             key: &key,
             lamports: Rc::new(RefCell::new(&mut lamports)),
-            data: Rc::new(RefCell::new(buffer_for_account.as_mut_slice())),
+            data: Rc::new(RefCell::new(buffer_for_account)),
             owner: &owner,
             rent_epoch: 0,
             is_signer: devol_sign,
@@ -352,7 +344,6 @@ mod tests {
         assert_eq!(new_account.owner_address, default_account.owner_address);
         assert_eq!(new_account.signer_address, default_account.signer_address);
         assert_eq!(new_account.payoff_log, default_account.payoff_log);
-        assert_eq!(new_account.id, default_account.id);
         assert_eq!(new_account.ops_counter, default_account.ops_counter);
         assert_eq!(new_account.sign_method, default_account.sign_method);
         assert_eq!(new_account.kyc_status, default_account.kyc_status);
@@ -361,37 +352,43 @@ mod tests {
         assert_eq!(new_account.last_trade_hour_since_epoch, default_account.last_trade_hour_since_epoch);
         assert_eq!(new_account.hours_trade_volume, default_account.hours_trade_volume);
         assert_eq!(new_account.refs, default_account.refs);
-        assert_ne!(new_account.pools_count, default_account.pools_count);
-        assert_ne!(new_account.get_pool(check_1.0).unwrap(), &ClientPool::default());
-        assert_eq!(new_account.get_pool(1).unwrap(), &ClientPool::default());
-        assert_ne!(new_account.get_pool(check_2.0).unwrap(), &ClientPool::default());
-
-        let base_ptr = new_account as *const _ as usize;
-
-        let pool_record_offset = CLIENT_ACCOUNT_SIZE + (check_4.0 as usize) * CLIENT_POOL_SIZE;
-
-        let result_offset = pool_record_offset
-            + CLIENT_POOL_CALLS_RESULT_OFFSET
-            + (check_4_bucket) * 8;
-        let result_ptr: *mut i64 =
-            account_info.data.borrow_mut()[result_offset..result_offset + 8].as_ptr() as *mut i64;
-        assert_eq!(unsafe { result_ptr.read_unaligned() }, new_account.get_pool(check_4.0).unwrap().get_calls_result(check_4_bucket));
-
-        let check_ptr = (base_ptr + CLIENT_ACCOUNT_POOLS_COUNT_OFFSET) as *const u32;
-        assert_eq!(unsafe { *check_ptr }, TEST_POOLS_SIZE as u32);
-        assert_eq!(unsafe { check_ptr.read_unaligned() }, TEST_POOLS_SIZE as u32);
-        let base_ptr = new_account as *const _ as usize;
-        let check_ptr = (base_ptr + CLIENT_ACCOUNT_SIZE + check_1.0 * CLIENT_POOL_SIZE + CLIENT_POOL_FRACTIONS_OFFSET) as *const u32;
-        assert_eq!(unsafe { *check_ptr }, check_1.1);
-
-        let check_ptr = (base_ptr + CLIENT_ACCOUNT_SIZE + check_1.0 * CLIENT_POOL_SIZE + CLIENT_POOL_INSTR_ID_OFFSET) as *const u32;
-        assert_eq!(unsafe { *check_ptr }, 1337);
-        let base_ptr = new_account as *const _ as usize;
-        let check_ptr = (base_ptr + CLIENT_ACCOUNT_SIZE + check_2.0 * CLIENT_POOL_SIZE + CLIENT_POOL_INSTR_ID_OFFSET) as *mut u32;
-        assert_eq!(unsafe { check_ptr.read_unaligned() }, check_2.1);
-        assert_eq!(new_account.get_pool(check_1.0).unwrap().fractions, check_1.1);
-        assert_eq!(new_account.get_pool(check_2.0).unwrap().instr_id, check_2.1);
-        assert_eq!(new_account.get_pool(check_3.0).unwrap().last_cost, check_3.1);
+        assert_eq!(new_account.pools_count, default_account.pools_count);
+        assert_eq!(new_account.get_pools_count(), TEST_POOLS_SIZE as u32);
+
+        let data = account_info.data.borrow();
+        let view = ClientAccount::view(&data).unwrap();
+        assert_eq!(view.get_pools_count(), TEST_POOLS_SIZE as u32);
+        assert_ne!(view.get_pool(check_1.0).unwrap(), ClientPool::default());
+        assert_eq!(view.get_pool(1).unwrap(), ClientPool::default());
+        assert_ne!(view.get_pool(check_2.0).unwrap(), ClientPool::default());
+        assert!(view.get_pool(TEST_POOLS_SIZE).is_err());
+
+        let pool_record_offset = CLIENT_ACCOUNT_SIZE + check_4.0 * CLIENT_POOL_SIZE;
+        let result_offset = pool_record_offset + CLIENT_POOL_CALLS_RESULT_OFFSET + check_4_bucket * 8;
+        assert_eq!(view.read_at::<i64>(result_offset).unwrap(), view.get_pool(check_4.0).unwrap().get_calls_result(check_4_bucket));
+        assert_eq!(view.read_at::<i64>(result_offset).unwrap(), check_4.1);
+
+        let fractions_offset = CLIENT_ACCOUNT_SIZE + check_1.0 * CLIENT_POOL_SIZE + CLIENT_POOL_FRACTIONS_OFFSET;
+        assert_eq!(view.read_at::<u32>(fractions_offset).unwrap(), check_1.1);
+        let instr_id_offset = CLIENT_ACCOUNT_SIZE + check_1.0 * CLIENT_POOL_SIZE + CLIENT_POOL_INSTR_ID_OFFSET;
+        assert_eq!(view.read_at::<u32>(instr_id_offset).unwrap(), 1337);
+        let instr_id_offset = CLIENT_ACCOUNT_SIZE + check_2.0 * CLIENT_POOL_SIZE + CLIENT_POOL_INSTR_ID_OFFSET;
+        assert_eq!(view.read_at::<u32>(instr_id_offset).unwrap(), check_2.1);
+        assert_eq!(view.get_pool(check_1.0).unwrap().fractions, check_1.1);
+        assert_eq!(view.get_pool(check_2.0).unwrap().instr_id, check_2.1);
+        assert_eq!(view.get_pool(check_3.0).unwrap().last_cost, check_3.1);
+    }
+    #[test]
+    fn test_client_account_misaligned_data() {
+        let mut buffer = vec![0u64; align_size(CLIENT_ACCOUNT_SIZE + 1, 8) / 8 + 1];
+        let bytes = unsafe { std::slice::from_raw_parts_mut(buffer.as_mut_ptr() as *mut u8, buffer.len() * 8) };
+        let account = ClientAccount { id: 17, ..ClientAccount::default() };
+        ClientAccount::view_mut(&mut bytes[1..]).unwrap().write_record(0, CLIENT_ACCOUNT_SIZE, 0, &account).unwrap();
+
+        let view = ClientAccount::view(&bytes[1..]).unwrap();
+        assert!(view.as_ref().is_err());
+        assert_eq!(view.read().unwrap().id, 17);
+        assert!(ClientAccount::view(&bytes[1..CLIENT_ACCOUNT_SIZE]).is_err());
     }
     #[test]
     fn test_client_account_check_no_signer() {
@@ -494,4 +491,3 @@ mod tests {
         assert!(result.is_ok());
     }
 }
-
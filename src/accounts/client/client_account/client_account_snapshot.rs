@@ -0,0 +1,149 @@
+use solana_program::pubkey::Pubkey;
+use crate::accounts::account_header::AccountHeader;
+use crate::accounts::client::client_account::client_account::{ClientAccount, MAX_CLIENT_LP_COUNT};
+use crate::accounts::client::client_account::client_lp::ClientLp;
+use crate::accounts::client::client_account::client_mint::ClientMint;
+use crate::accounts::client::client_account::client_pool::ClientPool;
+use crate::accounts::client::client_account::client_sign_method::ClientSignMethod;
+use crate::accounts::client::client_account::kyc_status::KYCStatus;
+use crate::accounts::mints::mints_account::MAX_MINTS_COUNT;
+use crate::accounts::devol_account::DevolAccount;
+use crate::constants::HOURS;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Owned copy of a `ClientAccount` including its extendable pools tail.
+///
+/// `ClientAccount` is a view over the fixed `CLIENT_ACCOUNT_SIZE` prefix only, so a boxed copy of it
+/// cannot be used to reach the pools. The snapshot decodes every pool record into a `Vec` instead.
+#[derive(Clone, PartialEq, Debug)]
+pub struct ClientAccountSnapshot {
+    pub header: AccountHeader,
+    pub owner_address: Pubkey,
+    pub signer_address: Pubkey,
+    pub payoff_log: Pubkey,
+    pub id: u32,
+    pub ops_counter: i64,
+    pub sign_method: ClientSignMethod,
+    pub kyc_status: KYCStatus,
+    pub kyc_time: u32,
+    pub last_trade_day: u32,
+    pub last_trade_hour_since_epoch: u32,
+    pub hours_trade_volume: [i64; HOURS],
+    pub refs: i64,
+    pub mints: [ClientMint; MAX_MINTS_COUNT],
+    pub lp_count: u32,
+    pub lp: [ClientLp; MAX_CLIENT_LP_COUNT],
+    pub pools: Vec<ClientPool>,
+}
+
+impl ClientAccountSnapshot {
+    /// Builds the snapshot from raw account data which has already passed `ClientAccount` checks.
+    pub fn from_account_data(data: &[u8]) -> Result<Self, DvlError> {
+        let view = ClientAccount::view(data)?;
+        let pools = (0..view.get_pools_count() as usize)
+            .map(|index| view.get_pool(index)
+                .map_err(|_| DvlError::new_with_account(AccountTag::Client, ContractError::AccountSize)))
+            .collect::<Result<Vec<_>, _>>()?;
+        Ok(Self::from_client_account(&view.read()?, pools))
+    }
+
+    fn from_client_account(account: &ClientAccount, pools: Vec<ClientPool>) -> Self {
+        let mut hours_trade_volume = [0; HOURS];
+        for (index, volume) in hours_trade_volume.iter_mut().enumerate() {
+            *volume = account.get_hours_trade_volume(index);
+        }
+        Self {
+            header: account.header,
+            owner_address: account.owner_address,
+            signer_address: account.signer_address,
+            payoff_log: account.payoff_log,
+            id: account.id,
+            ops_counter: account.get_ops_counter(),
+            sign_method: account.sign_method,
+            kyc_status: account.kyc_status,
+            kyc_time: account.kyc_time,
+            last_trade_day: account.last_trade_day,
+            last_trade_hour_since_epoch: account.last_trade_hour_since_epoch,
+            hours_trade_volume,
+            refs: account.get_refs(),
+            mints: account.mints,
+            lp_count: account.lp_count,
+            lp: account.lp,
+            pools,
+        }
+    }
+
+    #[inline(always)]
+    pub fn get_pools_count(&self) -> u32 { self.pools.len() as u32 }
+
+    #[inline(always)]
+    pub fn get_pool(&self, index: usize) -> Result<&ClientPool, DvlError> {
+        self.pools.get(index)
+            .ok_or(DvlError::new_with_account(AccountTag::Client, ContractError::PoolRecordNotFound))
+    }
+
+    #[inline(always)]
+    pub fn pools_iter(&self) -> impl Iterator<Item = &ClientPool> {
+        self.pools.iter()
+    }
+
+    /// Iterates over the pools the client holds with the given worker.
+    pub fn worker_pools_iter(&self, worker_id: u32) -> impl Iterator<Item = &ClientPool> {
+        self.pools.iter().filter(move |pool| pool.worker_id == worker_id)
+    }
+
+    /// Finds the client's record of the pool `id` of the worker `worker_id`.
+    pub fn find_pool(&self, worker_id: u32, id: u32) -> Option<&ClientPool> {
+        self.pools.iter().find(|pool| pool.worker_id == worker_id && pool.id == id)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::client::client_account::client_account::CLIENT_ACCOUNT_SIZE;
+    use crate::accounts::client::client_account::client_pool::CLIENT_POOL_SIZE;
+
+    #[test]
+    fn test_client_account_snapshot_pools() {
+        const TEST_POOLS_SIZE: usize = 3;
+        let total_size = CLIENT_ACCOUNT_SIZE + TEST_POOLS_SIZE * CLIENT_POOL_SIZE;
+        let mut buffer = vec![0u8; total_size];
+        let mut account = ClientAccount::default();
+        account.id = 42;
+        account.set_ops_counter(7);
+        account.set_pools_count(TEST_POOLS_SIZE as u32);
+        let mut view = ClientAccount::view_mut(&mut buffer).unwrap();
+        view.write_record(0, CLIENT_ACCOUNT_SIZE, 0, &account).unwrap();
+        for index in 0..TEST_POOLS_SIZE {
+            let mut pool = ClientPool::default();
+            pool.id = 10 + index as u32;
+            pool.worker_id = index as u32 % 2;
+            pool.set_strikes(3, -(index as i64));
+            view.set_pool(index, &pool).unwrap();
+        }
+
+        let snapshot = ClientAccountSnapshot::from_account_data(&buffer).unwrap();
+
+        assert_eq!(snapshot.id, 42);
+        assert_eq!(snapshot.ops_counter, 7);
+        assert_eq!(snapshot.get_pools_count(), TEST_POOLS_SIZE as u32);
+        assert_eq!(snapshot.get_pool(2).unwrap().id, 12);
+        assert_eq!(snapshot.get_pool(2).unwrap().get_strikes(3), -2);
+        assert!(snapshot.get_pool(TEST_POOLS_SIZE).is_err());
+        assert_eq!(snapshot.worker_pools_iter(0).count(), 2);
+        assert_eq!(snapshot.find_pool(1, 11).unwrap().id, 11);
+        assert!(snapshot.find_pool(1, 12).is_none());
+    }
+
+    #[test]
+    fn test_client_account_snapshot_truncated_tail() {
+        let mut buffer = vec![0u8; CLIENT_ACCOUNT_SIZE + CLIENT_POOL_SIZE];
+        let mut account = ClientAccount::default();
+        account.set_pools_count(2);
+        ClientAccount::view_mut(&mut buffer).unwrap().write_record(0, CLIENT_ACCOUNT_SIZE, 0, &account).unwrap();
+
+        assert!(ClientAccountSnapshot::from_account_data(&buffer).is_err());
+    }
+}
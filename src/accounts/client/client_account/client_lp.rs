@@ -1,16 +1,5 @@
 use crate::constants::FD;
-
-pub const CLIENT_LP_WORKER_ID_OFFSET: usize = 0;
-pub const CLIENT_LP_TASK_ID_OFFSET: usize = 4;
-pub const CLIENT_LP_COUNTER_OFFSET: usize = 8;
-pub const CLIENT_LP_ORIG_COUNTER_OFFSET: usize = 16;
-pub const CLIENT_LP_TIME_OFFSET: usize = 24;
-pub const CLIENT_LP_PS_OFFSET: usize = 32;
-pub const CLIENT_LP_PS_COST_OFFSET: usize = 36;
-pub const CLIENT_LP_PS_RESULT_OFFSET: usize = 44;
-pub const CLIENT_LP_PS_TRADE_QTY_OFFSET: usize = 52;
-pub const CLIENT_LP_PS_TRADE_COST_OFFSET: usize = 56;
-pub const CLIENT_LP_SIZE: usize = 64;
+use crate::accounts::devol_layout::devol_layout;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[repr(C)]
@@ -27,6 +16,20 @@ pub struct ClientLp {
     pub ps_trade_cost: [u8; 8],
 }
 
+devol_layout! {
+    ClientLp, CLIENT_LP_SIZE = 64, test_client_lp_layout;
+    worker_id: u32 => CLIENT_LP_WORKER_ID_OFFSET = 0,
+    task_id: u32 => CLIENT_LP_TASK_ID_OFFSET = 4,
+    counter: i64 => CLIENT_LP_COUNTER_OFFSET = 8,
+    orig_counter: i64 => CLIENT_LP_ORIG_COUNTER_OFFSET = 16,
+    time: i64 => CLIENT_LP_TIME_OFFSET = 24,
+    ps: u32 => CLIENT_LP_PS_OFFSET = 32,
+    ps_cost: [u8; 8] => CLIENT_LP_PS_COST_OFFSET = 36,
+    ps_result: [u8; 8] => CLIENT_LP_PS_RESULT_OFFSET = 44,
+    ps_trade_qty: i32 => CLIENT_LP_PS_TRADE_QTY_OFFSET = 52,
+    ps_trade_cost: [u8; 8] => CLIENT_LP_PS_TRADE_COST_OFFSET = 56,
+}
+
 impl ClientLp {
     #[inline(always)]
     pub fn get_ps_cost(&self) -> i64 { i64::from_ne_bytes(self.ps_cost) }
@@ -72,28 +75,3 @@ impl Default for ClientLp {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use crate::accounts::client::client_account::client_lp::*;
-
-    #[test]
-    fn test_client_lp_offsets() {
-        let client_lp = ClientLp::default();
-
-        let base_ptr = &client_lp as *const _ as usize;
-
-        assert_eq!(&client_lp.worker_id as *const _ as usize - base_ptr, CLIENT_LP_WORKER_ID_OFFSET);
-        assert_eq!(&client_lp.task_id as *const _ as usize - base_ptr, CLIENT_LP_TASK_ID_OFFSET);
-        assert_eq!(&client_lp.counter as *const _ as usize - base_ptr, CLIENT_LP_COUNTER_OFFSET);
-        assert_eq!(&client_lp.orig_counter as *const _ as usize - base_ptr, CLIENT_LP_ORIG_COUNTER_OFFSET);
-        assert_eq!(&client_lp.time as *const _ as usize - base_ptr, CLIENT_LP_TIME_OFFSET);
-        assert_eq!(&client_lp.ps as *const _ as usize - base_ptr, CLIENT_LP_PS_OFFSET);
-        assert_eq!(&client_lp.ps_cost as *const _ as usize - base_ptr, CLIENT_LP_PS_COST_OFFSET);
-        assert_eq!(&client_lp.ps_result as *const _ as usize - base_ptr, CLIENT_LP_PS_RESULT_OFFSET);
-        assert_eq!(&client_lp.ps_trade_qty as *const _ as usize - base_ptr, CLIENT_LP_PS_TRADE_QTY_OFFSET);
-        assert_eq!(&client_lp.ps_trade_cost as *const _ as usize - base_ptr, CLIENT_LP_PS_TRADE_COST_OFFSET);
-
-        assert_eq!(std::mem::size_of::<ClientLp>(), CLIENT_LP_SIZE);
-    }
-}
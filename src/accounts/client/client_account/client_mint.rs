@@ -1,7 +1,5 @@
+use crate::accounts::devol_layout::devol_layout;
 
-pub const CLIENT_MINT_AVAILABLE_OFFSET: usize = 0;
-pub const CLIENT_MINT_BLOCKED_OFFSET: usize = 8;
-pub const CLIENT_MINT_SIZE: usize = 16;
 
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[repr(C)]
@@ -10,6 +8,12 @@ pub struct ClientMint {
     pub blocked: [u8; 8],
 } // size: 16 bytes
 
+devol_layout! {
+    ClientMint, CLIENT_MINT_SIZE = 16, test_client_mint_layout;
+    available: [u8; 8] => CLIENT_MINT_AVAILABLE_OFFSET = 0,
+    blocked: [u8; 8] => CLIENT_MINT_BLOCKED_OFFSET = 8,
+}
+
 impl ClientMint {
     #[inline(always)]
     pub fn get_available(&self) -> i64 { i64::from_ne_bytes(self.available) }
@@ -32,20 +36,3 @@ impl Default for ClientMint {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_client_mint_offsets() {
-        let client_mint = ClientMint::default();
-
-        let base_ptr = &client_mint as *const _ as usize;
-
-        assert_eq!(&client_mint.available as *const _ as usize - base_ptr, CLIENT_MINT_AVAILABLE_OFFSET);
-        assert_eq!(&client_mint.blocked as *const _ as usize - base_ptr, CLIENT_MINT_BLOCKED_OFFSET);
-
-        assert_eq!(std::mem::size_of::<ClientMint>(), CLIENT_MINT_SIZE);
-    }
-}
@@ -1,35 +1,8 @@
 use crate::accounts::client::client_account::client_pool_basket::{BasketData, CLIENT_POOL_MAX_BASKET_LENGTH};
 use crate::constants::{BOUNDS_COUNT, BUCKETS_COUNT, VANILLA_COST_SIZE};
+use crate::accounts::devol_layout::devol_layout;
 
 
-pub const CLIENT_POOL_ID_OFFSET: usize = 0;
-pub const CLIENT_POOL_WORKER_ID_OFFSET: usize = 4;
-pub const CLIENT_POOL_INSTR_ID_OFFSET: usize = 8;
-pub const CLIENT_POOL_START_OFFSET: usize = 12;
-pub const CLIENT_POOL_EXPIRATION_OFFSET: usize = 20;
-pub const CLIENT_POOL_FRACTIONS_OFFSET: usize = 28;
-pub const CLIENT_POOL_COUNTER_OFFSET: usize = 32;
-pub const CLIENT_POOL_ORIG_COUNTER_OFFSET: usize = 40;
-pub const CLIENT_POOL_TIME_OFFSET: usize = 48;
-pub const CLIENT_POOL_DEPO_OFFSET: usize = 56;
-pub const CLIENT_POOL_COST_OFFSET: usize = 816;
-pub const CLIENT_POOL_RESULT_OFFSET: usize = 1576;
-pub const CLIENT_POOL_CALLS_OFFSET: usize = 2336;
-pub const CLIENT_POOL_CALLS_COST_OFFSET: usize = 2716;
-pub const CLIENT_POOL_CALLS_RESULT_OFFSET: usize = 3476;
-pub const CLIENT_POOL_PUTS_OFFSET: usize = 4236;
-pub const CLIENT_POOL_PUTS_COST_OFFSET: usize = 4616;
-pub const CLIENT_POOL_PUTS_RESULT_OFFSET: usize = 5376;
-pub const CLIENT_POOL_LAST_COST_OFFSET: usize = 6136;
-pub const CLIENT_POOL_LAST_FEES_OFFSET: usize = 6144;
-pub const CLIENT_POOL_LAST_TRADE_OFFSET: usize = 6152;
-pub const CLIENT_POOL_VANILLA_MEMO_OFFSET: usize = 6532;
-pub const CLIENT_POOL_VANILLA_COST_OFFSET: usize = 6581;
-pub const CLIENT_POOL_LAST_PX_OFFSET: usize = 6613;
-pub const CLIENT_POOL_STRIKES_OFFSET: usize = 7373;
-pub const CLIENT_POOL_BOUNDS_OFFSET: usize = 8133;
-pub const CLIENT_POOL_SIZE: usize = 8885;
-
 #[derive(Copy, Clone, PartialEq, Debug)]
 #[repr(C)]
 pub struct ClientPool {
@@ -62,6 +35,44 @@ pub struct ClientPool {
     bounds: [u8; 8*BOUNDS_COUNT],               // 752 bytes, CLIENT_POOL_BOUNDS_OFFSET
 }
 
+devol_layout! {
+    ClientPool, CLIENT_POOL_SIZE = 8885, test_client_pool_layout;
+    id: u32 => CLIENT_POOL_ID_OFFSET = 0,
+    worker_id: u32 => CLIENT_POOL_WORKER_ID_OFFSET = 4,
+    instr_id: u32 => CLIENT_POOL_INSTR_ID_OFFSET = 8,
+    start: [u8; 8] => CLIENT_POOL_START_OFFSET = 12,
+    expiration: [u8; 8] => CLIENT_POOL_EXPIRATION_OFFSET = 20,
+    fractions: u32 => CLIENT_POOL_FRACTIONS_OFFSET = 28,
+    counter: i64 => CLIENT_POOL_COUNTER_OFFSET = 32,
+    orig_counter: i64 => CLIENT_POOL_ORIG_COUNTER_OFFSET = 40,
+    time: i64 => CLIENT_POOL_TIME_OFFSET = 48,
+    depo: [i64; BUCKETS_COUNT] => CLIENT_POOL_DEPO_OFFSET = 56,
+    cost: [i64; BUCKETS_COUNT] => CLIENT_POOL_COST_OFFSET = 816,
+    result: [i64; BUCKETS_COUNT] => CLIENT_POOL_RESULT_OFFSET = 1576,
+    calls: [u32; BUCKETS_COUNT] => CLIENT_POOL_CALLS_OFFSET = 2336,
+    #[parse = LeParser::ne_words::<_, 8>]
+    calls_cost: [u8; 8*BUCKETS_COUNT] => CLIENT_POOL_CALLS_COST_OFFSET = 2716,
+    #[parse = LeParser::ne_words::<_, 8>]
+    calls_result: [u8; 8*BUCKETS_COUNT] => CLIENT_POOL_CALLS_RESULT_OFFSET = 3476,
+    puts: [u32; BUCKETS_COUNT] => CLIENT_POOL_PUTS_OFFSET = 4236,
+    puts_cost: [i64; BUCKETS_COUNT] => CLIENT_POOL_PUTS_COST_OFFSET = 4616,
+    puts_result: [i64; BUCKETS_COUNT] => CLIENT_POOL_PUTS_RESULT_OFFSET = 5376,
+    last_cost: i64 => CLIENT_POOL_LAST_COST_OFFSET = 6136,
+    last_fees: i64 => CLIENT_POOL_LAST_FEES_OFFSET = 6144,
+    last_trade_quantity: [i32; BUCKETS_COUNT] => CLIENT_POOL_LAST_TRADE_OFFSET = 6152,
+    #[parse = LeParser::u8]
+    basket_length_memo: u8 => CLIENT_POOL_VANILLA_MEMO_OFFSET = 6532,
+    basket_memo: [BasketData; CLIENT_POOL_MAX_BASKET_LENGTH] = 6533,
+    #[parse = LeParser::ne_words::<_, 8>]
+    vanilla_cost: [u8; 8*VANILLA_COST_SIZE] => CLIENT_POOL_VANILLA_COST_OFFSET = 6581,
+    #[parse = LeParser::ne_words::<_, 8>]
+    last_px: [u8; 8*BUCKETS_COUNT] => CLIENT_POOL_LAST_PX_OFFSET = 6613,
+    #[parse = LeParser::ne_words::<_, 8>]
+    strikes: [u8; 8*BUCKETS_COUNT] => CLIENT_POOL_STRIKES_OFFSET = 7373,
+    #[parse = LeParser::ne_words::<_, 8>]
+    bounds: [u8; 8*BOUNDS_COUNT] => CLIENT_POOL_BOUNDS_OFFSET = 8133,
+}
+
 impl ClientPool {
     #[inline(always)]
     pub fn get_start(&self) -> i64 { i64::from_ne_bytes(self.start) }
@@ -170,47 +181,3 @@ impl Default for ClientPool {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use std::mem;
-    use crate::utils::type_size_helper::align_size;
-    use super::*;
-
-    #[test]
-    fn test_client_pool_offsets() {
-        let account = ClientPool::default();
-
-        let base_ptr = &account as *const _ as usize;
-
-        assert_eq!(&account.id as *const _ as usize - base_ptr, CLIENT_POOL_ID_OFFSET);
-        assert_eq!(&account.worker_id as *const _ as usize - base_ptr, CLIENT_POOL_WORKER_ID_OFFSET);
-        assert_eq!(&account.instr_id as *const _ as usize - base_ptr, CLIENT_POOL_INSTR_ID_OFFSET);
-        assert_eq!(&account.start as *const _ as usize - base_ptr, CLIENT_POOL_START_OFFSET);
-        assert_eq!(&account.expiration as *const _ as usize - base_ptr, CLIENT_POOL_EXPIRATION_OFFSET);
-        assert_eq!(&account.fractions as *const _ as usize - base_ptr, CLIENT_POOL_FRACTIONS_OFFSET);
-        assert_eq!(&account.counter as *const _ as usize - base_ptr, CLIENT_POOL_COUNTER_OFFSET);
-        assert_eq!(&account.orig_counter as *const _ as usize - base_ptr, CLIENT_POOL_ORIG_COUNTER_OFFSET);
-        assert_eq!(&account.time as *const _ as usize - base_ptr, CLIENT_POOL_TIME_OFFSET);
-        assert_eq!(&account.depo as *const _ as usize - base_ptr, CLIENT_POOL_DEPO_OFFSET);
-        assert_eq!(&account.cost as *const _ as usize - base_ptr, CLIENT_POOL_COST_OFFSET);
-        assert_eq!(&account.result as *const _ as usize - base_ptr, CLIENT_POOL_RESULT_OFFSET);
-        assert_eq!(&account.calls as *const _ as usize - base_ptr, CLIENT_POOL_CALLS_OFFSET);
-        assert_eq!(&account.calls_cost as *const _ as usize - base_ptr, CLIENT_POOL_CALLS_COST_OFFSET);
-        assert_eq!(&account.calls_result as *const _ as usize - base_ptr, CLIENT_POOL_CALLS_RESULT_OFFSET);
-        assert_eq!(&account.puts as *const _ as usize - base_ptr, CLIENT_POOL_PUTS_OFFSET);
-        assert_eq!(&account.puts_cost as *const _ as usize - base_ptr, CLIENT_POOL_PUTS_COST_OFFSET);
-        assert_eq!(&account.puts_result as *const _ as usize - base_ptr, CLIENT_POOL_PUTS_RESULT_OFFSET);
-        assert_eq!(&account.last_cost as *const _ as usize - base_ptr, CLIENT_POOL_LAST_COST_OFFSET);
-        assert_eq!(&account.last_fees as *const _ as usize - base_ptr, CLIENT_POOL_LAST_FEES_OFFSET);
-        assert_eq!(&account.last_trade_quantity as *const _ as usize - base_ptr, CLIENT_POOL_LAST_TRADE_OFFSET);
-        assert_eq!(&account.basket_length_memo as *const _ as usize - base_ptr, CLIENT_POOL_VANILLA_MEMO_OFFSET);
-        assert_eq!(&account.basket_memo as *const _ as usize - base_ptr, CLIENT_POOL_VANILLA_MEMO_OFFSET + 1);
-        assert_eq!(&account.vanilla_cost as *const _ as usize - base_ptr, CLIENT_POOL_VANILLA_COST_OFFSET);
-        assert_eq!(&account.last_px as *const _ as usize - base_ptr, CLIENT_POOL_LAST_PX_OFFSET);
-        assert_eq!(&account.strikes as *const _ as usize - base_ptr, CLIENT_POOL_STRIKES_OFFSET);
-        assert_eq!(&account.bounds as *const _ as usize - base_ptr, CLIENT_POOL_BOUNDS_OFFSET);
-
-        assert_eq!(mem::size_of::<ClientPool>(), align_size(CLIENT_POOL_SIZE, 8));
-    }
-}
@@ -1,6 +1,12 @@
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::constants::VANILLA_MEMO_SIZE;
+use crate::dvl_error::DvlError;
+use crate::utils::put_or_call::PutOrCall;
 
 pub const CLIENT_POOL_MAX_BASKET_LENGTH: usize = 4;
+/// Size of a `BasketData` element packed in a vanilla memo after the basket length byte.
+pub const VANILLA_MEMO_BASKET_DATA_SIZE: usize = 12;
 
 #[derive(Copy, Clone, PartialOrd, PartialEq, Debug, Serialize, Deserialize)]
 #[repr(C)]
@@ -10,6 +16,24 @@ pub struct BasketData {
     pub amount: [u8; CLIENT_POOL_MAX_BASKET_LENGTH],
 }
 
+impl DevolParse for BasketData {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        Ok(Self {
+            strike: parser.ne_bytes(offset)?,
+            pc: parser.ne_bytes(offset + 4)?,
+            amount: parser.ne_bytes(offset + 8)?,
+        })
+    }
+}
+
+impl DevolWrite for BasketData {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.ne_bytes(offset, self.strike)?;
+        writer.ne_bytes(offset + 4, self.pc)?;
+        writer.ne_bytes(offset + 8, self.amount)
+    }
+}
+
 impl Default for BasketData {
     fn default() -> Self {
         Self {
@@ -33,4 +57,21 @@ impl BasketData {
     pub fn get_amount(&self) -> i32 { i32::from_ne_bytes(self.amount) }
     #[inline(always)]
     pub fn set_amount(&mut self, value: i32) { self.amount = value.to_ne_bytes() }
+    #[inline(always)]
+    pub fn get_put_or_call(&self) -> PutOrCall {
+        if self.get_pc() == PutOrCall::PUT as u32 { PutOrCall::PUT } else { PutOrCall::CALL }
+    }
+
+    /// Decodes the vanilla memo of the trade and pools logs: the basket length byte followed by
+    /// packed `BasketData` elements.
+    pub fn from_vanilla_memo(memo: &[u8; VANILLA_MEMO_SIZE]) -> impl Iterator<Item = BasketData> + '_ {
+        let length = (memo[0] as usize).min(CLIENT_POOL_MAX_BASKET_LENGTH);
+        memo[1..].chunks_exact(VANILLA_MEMO_BASKET_DATA_SIZE)
+            .take(length)
+            .map(|element| BasketData {
+                strike: [element[0], element[1], element[2], element[3]],
+                pc: [element[4], element[5], element[6], element[7]],
+                amount: [element[8], element[9], element[10], element[11]],
+            })
+    }
 }
\ No newline at end of file
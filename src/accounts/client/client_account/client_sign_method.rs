@@ -1,6 +1,26 @@
-#[derive(Clone, Copy, PartialEq, Debug)]
+use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum ClientSignMethod {
     Wallet = 0,
     SignerAccount = 1,
-}
\ No newline at end of file
+}
+
+impl DevolParse for ClientSignMethod {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u32(offset)? {
+            0 => Ok(ClientSignMethod::Wallet),
+            1 => Ok(ClientSignMethod::SignerAccount),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for ClientSignMethod {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u32(offset, *self as u32)
+    }
+}
@@ -1,8 +1,30 @@
-#[derive(Clone, Copy, PartialEq, Debug)]
+use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
+
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 #[repr(i64)]
 pub enum KYCStatus {
     Blocked = -1,
     Light = 0,
     Power = 1000000,
     PowerLp = 1000001,
-}
\ No newline at end of file
+}
+
+impl DevolParse for KYCStatus {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.i64(offset)? {
+            -1 => Ok(KYCStatus::Blocked),
+            0 => Ok(KYCStatus::Light),
+            1000000 => Ok(KYCStatus::Power),
+            1000001 => Ok(KYCStatus::PowerLp),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for KYCStatus {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.i64(offset, *self as i64)
+    }
+}
@@ -1,4 +1,5 @@
 pub mod client_account;
+pub mod client_account_snapshot;
 pub mod client_sign_method;
 pub mod kyc_status;
 pub mod client_mint;
@@ -1,13 +1,7 @@
-pub const LP_TRADE_LOG_ID_OFFSET: usize = 0;
-pub const LP_TRADE_LOG_TIME_OFFSET: usize = 8;
-pub const LP_TRADE_LOG_WORKER_ID_OFFSET: usize = 16;
-pub const LP_TRADE_LOG_TASK_ID_OFFSET: usize = 20;
-pub const LP_TRADE_LOG_COUNTER_OFFSET: usize = 24;
-pub const LP_TRADE_LOG_COST_OFFSET: usize = 32;
-pub const LP_TRADE_LOG_PS_OFFSET: usize = 40;
-pub const LP_TRADE_LOG_PS_PX_OFFSET: usize = 44;
-pub const LP_TRADE_LOG_SIZE: usize = 52;
+use crate::accounts::devol_layout::devol_layout;
 
+/// Record of an LP trade. The program has no account holding these records yet, its tag, size,
+/// capacity and seeds are unknown, so there is no `LpTradeLogAccount` or reader for it.
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct LpTradeLog {
@@ -29,6 +23,18 @@ pub struct LpTradeLog {
     pub ps_px: [u8; 8],
 }
 
+devol_layout! {
+    LpTradeLog, LP_TRADE_LOG_SIZE = 52, test_lp_trade_log_layout;
+    id: [u8; 8] => LP_TRADE_LOG_ID_OFFSET = 0,
+    time: [u8; 8] => LP_TRADE_LOG_TIME_OFFSET = 8,
+    worker_id: u32 => LP_TRADE_LOG_WORKER_ID_OFFSET = 16,
+    task_id: u32 => LP_TRADE_LOG_TASK_ID_OFFSET = 20,
+    counter: [u8; 8] => LP_TRADE_LOG_COUNTER_OFFSET = 24,
+    cost: [u8; 8] => LP_TRADE_LOG_COST_OFFSET = 32,
+    ps: i32 => LP_TRADE_LOG_PS_OFFSET = 40,
+    ps_px: [u8; 8] => LP_TRADE_LOG_PS_PX_OFFSET = 44,
+}
+
 impl LpTradeLog {
     #[inline(always)]
     pub fn get_id(&self) -> i64 {
@@ -95,53 +101,4 @@ impl Default for LpTradeLog {
             ps_px: [0; 8],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::mem;
-    use super::*;
-
-    #[test]
-    fn test_lp_trade_log_offsets() {
-        let log = LpTradeLog::default();
-        let base_ptr = &log as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &log.id as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_ID_OFFSET
-        );
-        assert_eq!(
-            &log.time as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_TIME_OFFSET
-        );
-        assert_eq!(
-            &log.worker_id as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_WORKER_ID_OFFSET
-        );
-        assert_eq!(
-            &log.task_id as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_TASK_ID_OFFSET
-        );
-        assert_eq!(
-            &log.counter as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_COUNTER_OFFSET
-        );
-        assert_eq!(
-            &log.cost as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_COST_OFFSET
-        );
-        assert_eq!(
-            &log.ps as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_PS_OFFSET
-        );
-        assert_eq!(
-            &log.ps_px as *const _ as usize - base_ptr,
-            LP_TRADE_LOG_PS_PX_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(mem::size_of::<LpTradeLog>(), LP_TRADE_LOG_SIZE);
-    }
 }
\ No newline at end of file
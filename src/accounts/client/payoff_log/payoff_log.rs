@@ -1,14 +1,4 @@
-pub const PAYOFF_LOG_ID_OFFSET: usize = 0;
-pub const PAYOFF_LOG_WORKER_ID_OFFSET: usize = 8;
-pub const PAYOFF_LOG_POOL_ID_OFFSET: usize = 12;
-pub const PAYOFF_LOG_INSTR_ID_OFFSET: usize = 16;
-pub const PAYOFF_LOG_TRADE_TIME_OFFSET: usize = 20;
-pub const PAYOFF_LOG_UPDATE_TIME_OFFSET: usize = 28;
-pub const PAYOFF_LOG_SETTLEMENT_PX_OFFSET: usize = 36;
-pub const PAYOFF_LOG_PAYOFF_OFFSET: usize = 44;
-pub const PAYOFF_LOG_UNBLOCKED_COLLATERAL_OFFSET: usize = 52;
-pub const PAYOFF_LOG_RESULT_OFFSET: usize = 60;
-pub const PAYOFF_LOG_SIZE: usize = 68;
+use crate::accounts::devol_layout::devol_layout;
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -34,6 +24,20 @@ pub struct PayoffLog {
     pub result: [u8; 8],
 }
 
+devol_layout! {
+    PayoffLog, PAYOFF_LOG_SIZE = 68, test_payoff_log_layout;
+    id: [u8; 8] => PAYOFF_LOG_ID_OFFSET = 0,
+    worker_id: u32 => PAYOFF_LOG_WORKER_ID_OFFSET = 8,
+    pool_id: u32 => PAYOFF_LOG_POOL_ID_OFFSET = 12,
+    instr_id: u32 => PAYOFF_LOG_INSTR_ID_OFFSET = 16,
+    trade_time: [u8; 8] => PAYOFF_LOG_TRADE_TIME_OFFSET = 20,
+    update_time: [u8; 8] => PAYOFF_LOG_UPDATE_TIME_OFFSET = 28,
+    settlement_px: [u8; 8] => PAYOFF_LOG_SETTLEMENT_PX_OFFSET = 36,
+    payoff: [u8; 8] => PAYOFF_LOG_PAYOFF_OFFSET = 44,
+    unblocked_collateral: [u8; 8] => PAYOFF_LOG_UNBLOCKED_COLLATERAL_OFFSET = 52,
+    result: [u8; 8] => PAYOFF_LOG_RESULT_OFFSET = 60,
+}
+
 impl PayoffLog {
     #[inline(always)]
     pub fn get_id(&self) -> i64 { i64::from_ne_bytes(self.id) }
@@ -86,33 +90,4 @@ impl Default for PayoffLog {
             result: [0; 8],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-    use crate::utils::type_size_helper::align_size;
-
-    #[test]
-    fn test_payoff_log_offsets_and_sizes() {
-        let log = PayoffLog::default();
-
-        let base_ptr = &log as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(&log.id as *const _ as usize - base_ptr, PAYOFF_LOG_ID_OFFSET);
-        assert_eq!(&log.worker_id as *const _ as usize - base_ptr, PAYOFF_LOG_WORKER_ID_OFFSET);
-        assert_eq!(&log.pool_id as *const _ as usize - base_ptr, PAYOFF_LOG_POOL_ID_OFFSET);
-        assert_eq!(&log.instr_id as *const _ as usize - base_ptr, PAYOFF_LOG_INSTR_ID_OFFSET);
-        assert_eq!(&log.trade_time as *const _ as usize - base_ptr, PAYOFF_LOG_TRADE_TIME_OFFSET);
-        assert_eq!(&log.update_time as *const _ as usize - base_ptr, PAYOFF_LOG_UPDATE_TIME_OFFSET);
-        assert_eq!(&log.settlement_px as *const _ as usize - base_ptr, PAYOFF_LOG_SETTLEMENT_PX_OFFSET);
-        assert_eq!(&log.payoff as *const _ as usize - base_ptr, PAYOFF_LOG_PAYOFF_OFFSET);
-        assert_eq!(&log.unblocked_collateral as *const _ as usize - base_ptr, PAYOFF_LOG_UNBLOCKED_COLLATERAL_OFFSET);
-        assert_eq!(&log.result as *const _ as usize - base_ptr, PAYOFF_LOG_RESULT_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<PayoffLog>(), align_size(PAYOFF_LOG_SIZE, 4));
-    }
 }
\ No newline at end of file
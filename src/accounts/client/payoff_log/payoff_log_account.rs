@@ -2,14 +2,10 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::client::payoff_log::payoff_log::PayoffLog;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::ring_log::RingLog;
 
-pub const PAYOFF_LOG_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const PAYOFF_LOG_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const PAYOFF_LOG_ACCOUNT_ID_OFFSET: usize = 40;
-pub const PAYOFF_LOG_ACCOUNT_LAST_OFFSET: usize = 44;
-pub const PAYOFF_LOG_ACCOUNT_COUNT_OFFSET: usize = 48;
-pub const PAYOFF_LOG_ACCOUNT_DATA_OFFSET: usize = 52;
-pub const PAYOFF_LOG_ACCOUNT_SIZE: usize = 8756;
 pub const PAYOFF_LOG_ACCOUNT_TAG: u8 = 9;
 pub const PAYOFF_LOG_ACCOUNT_VERSION: u32 = 11;
 pub const PAYOFF_LOG_BUFFER_CAPACITY: usize = 128;
@@ -23,8 +19,40 @@ pub struct PayoffLogAccount {
     pub count: u32, // 4 bytes
     pub data: [PayoffLog; PAYOFF_LOG_BUFFER_CAPACITY],
 }
+
+devol_layout! {
+    PayoffLogAccount, PAYOFF_LOG_ACCOUNT_SIZE = 8756, test_payoff_log_account_layout;
+    header: AccountHeader => PAYOFF_LOG_ACCOUNT_VERSION_OFFSET = 0,
+    id: u32 => PAYOFF_LOG_ACCOUNT_ID_OFFSET = 40,
+    last: u32 => PAYOFF_LOG_ACCOUNT_LAST_OFFSET = 44,
+    count: u32 => PAYOFF_LOG_ACCOUNT_COUNT_OFFSET = 48,
+    data: [PayoffLog; PAYOFF_LOG_BUFFER_CAPACITY] => PAYOFF_LOG_ACCOUNT_DATA_OFFSET = 52,
+}
+
 impl DevolIndexedAccount for PayoffLogAccount {}
 
+impl RingLog for PayoffLogAccount {
+    type Record = PayoffLog;
+
+    const CAPACITY: usize = PAYOFF_LOG_BUFFER_CAPACITY;
+
+    fn buffer(&self) -> &[PayoffLog] {
+        &self.data
+    }
+
+    fn written_count(&self) -> u64 {
+        self.count as u64
+    }
+
+    fn newest_index(&self) -> Option<usize> {
+        (self.count > 0).then_some(self.last as usize % PAYOFF_LOG_BUFFER_CAPACITY)
+    }
+
+    fn record_id(record: &PayoffLog) -> i64 {
+        record.get_id()
+    }
+}
+
 impl DevolAccount for PayoffLogAccount {
     #[inline(always)]
     fn expected_size() -> usize { PAYOFF_LOG_ACCOUNT_SIZE }
@@ -48,32 +76,3 @@ impl Default for PayoffLogAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_root_account_offsets() {
-        let account = PayoffLogAccount::default();
-
-        for i in 0..PAYOFF_LOG_BUFFER_CAPACITY {
-            let log = &account.data[i];
-            let log_ptr = log as *const _;
-            let expected_ptr = account.data.as_ptr() as usize + i * mem::size_of::<PayoffLog>();
-            assert_eq!(log_ptr as usize, expected_ptr, "PayoffLog offset is incorrect for index {}", i);
-        }
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size and offset
-        assert_eq!(&account.header as *const _ as usize - base_ptr, PAYOFF_LOG_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.id as *const _ as usize - base_ptr, PAYOFF_LOG_ACCOUNT_ID_OFFSET);
-        assert_eq!(&account.last as *const _ as usize - base_ptr, PAYOFF_LOG_ACCOUNT_LAST_OFFSET);
-        assert_eq!(&account.count as *const _ as usize - base_ptr, PAYOFF_LOG_ACCOUNT_COUNT_OFFSET);
-        assert_eq!(&account.data as *const _ as usize - base_ptr, PAYOFF_LOG_ACCOUNT_DATA_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<PayoffLogAccount>(), PAYOFF_LOG_ACCOUNT_SIZE);
-    }
-}
@@ -1,19 +1,5 @@
 use crate::constants::{BUCKETS_COUNT, VANILLA_COST_SIZE, VANILLA_MEMO_SIZE};
-
-pub const TRADE_LOG_ID_OFFSET: usize = 0;
-pub const TRADE_LOG_TIME_OFFSET: usize = 8;
-pub const TRADE_LOG_FRACTIONS_OFFSET: usize = 16;
-pub const TRADE_LOG_WORKER_ID_OFFSET: usize = 20;
-pub const TRADE_LOG_TASK_ID_OFFSET: usize = 24;
-pub const TRADE_LOG_POOL_ID_OFFSET: usize = 28;
-pub const TRADE_LOG_INSTR_ID_OFFSET: usize = 32;
-pub const TRADE_LOG_COUNTER_OFFSET: usize = 36;
-pub const TRADE_LOG_COST_OFFSET: usize = 44;
-pub const TRADE_LOG_PX_OFFSET: usize = 52;
-pub const TRADE_LOG_QTY_OFFSET: usize = 812;
-pub const TRADE_LOG_VANILLA_MEMO_OFFSET: usize = 1192;
-pub const TRADE_LOG_VANILLA_COST_OFFSET: usize = 1241;
-pub const TRADE_LOG_SIZE: usize = 1273;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -46,6 +32,24 @@ pub struct TradeLog {
     pub vanilla_cost: [[u8; 8]; VANILLA_COST_SIZE],
 }
 
+devol_layout! {
+    TradeLog, TRADE_LOG_SIZE = 1273, test_trade_log_layout;
+    id: [u8; 8] => TRADE_LOG_ID_OFFSET = 0,
+    time: [u8; 8] => TRADE_LOG_TIME_OFFSET = 8,
+    fractions: [u8; 4] => TRADE_LOG_FRACTIONS_OFFSET = 16,
+    worker_id: [u8; 4] => TRADE_LOG_WORKER_ID_OFFSET = 20,
+    task_id: [u8; 4] => TRADE_LOG_TASK_ID_OFFSET = 24,
+    pool_id: [u8; 4] => TRADE_LOG_POOL_ID_OFFSET = 28,
+    instr_id: [u8; 4] => TRADE_LOG_INSTR_ID_OFFSET = 32,
+    counter: [u8; 8] => TRADE_LOG_COUNTER_OFFSET = 36,
+    cost: [u8; 8] => TRADE_LOG_COST_OFFSET = 44,
+    px: [[u8; 8]; BUCKETS_COUNT] => TRADE_LOG_PX_OFFSET = 52,
+    qty: [[u8; 4]; BUCKETS_COUNT] => TRADE_LOG_QTY_OFFSET = 812,
+    #[parse = LeParser::bytes]
+    vanilla_memo: [u8; VANILLA_MEMO_SIZE] => TRADE_LOG_VANILLA_MEMO_OFFSET = 1192,
+    vanilla_cost: [[u8; 8]; VANILLA_COST_SIZE] => TRADE_LOG_VANILLA_COST_OFFSET = 1241,
+}
+
 impl TradeLog {
     #[inline(always)]
     pub fn get_id(&self) -> i64 {
@@ -187,71 +191,3 @@ impl Default for TradeLog {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_trade_log_offsets() {
-        let log = TradeLog::default();
-        let base_ptr = &log as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &log.id as *const _ as usize - base_ptr,
-            TRADE_LOG_ID_OFFSET
-        );
-        assert_eq!(
-            &log.time as *const _ as usize - base_ptr,
-            TRADE_LOG_TIME_OFFSET
-        );
-        assert_eq!(
-            &log.fractions as *const _ as usize - base_ptr,
-            TRADE_LOG_FRACTIONS_OFFSET
-        );
-        assert_eq!(
-            &log.worker_id as *const _ as usize - base_ptr,
-            TRADE_LOG_WORKER_ID_OFFSET
-        );
-        assert_eq!(
-            &log.task_id as *const _ as usize - base_ptr,
-            TRADE_LOG_TASK_ID_OFFSET
-        );
-        assert_eq!(
-            &log.pool_id as *const _ as usize - base_ptr,
-            TRADE_LOG_POOL_ID_OFFSET
-        );
-        assert_eq!(
-            &log.instr_id as *const _ as usize - base_ptr,
-            TRADE_LOG_INSTR_ID_OFFSET
-        );
-        assert_eq!(
-            &log.counter as *const _ as usize - base_ptr,
-            TRADE_LOG_COUNTER_OFFSET
-        );
-        assert_eq!(
-            &log.cost as *const _ as usize - base_ptr,
-            TRADE_LOG_COST_OFFSET
-        );
-        assert_eq!(
-            &log.px as *const _ as usize - base_ptr,
-            TRADE_LOG_PX_OFFSET
-        );
-        assert_eq!(
-            &log.qty as *const _ as usize - base_ptr,
-            TRADE_LOG_QTY_OFFSET
-        );
-        assert_eq!(
-            &log.vanilla_memo as *const _ as usize - base_ptr,
-            TRADE_LOG_VANILLA_MEMO_OFFSET
-        );
-        assert_eq!(
-            &log.vanilla_cost as *const _ as usize - base_ptr,
-            TRADE_LOG_VANILLA_COST_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<TradeLog>(), TRADE_LOG_SIZE);
-    }
-}
@@ -3,14 +3,10 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::client::trade_log::trade_log::TradeLog;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::ring_log::RingLog;
 
-pub const TRADE_LOG_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const TRADE_LOG_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const TRADE_LOG_ACCOUNT_MAIN_ADDRESS_OFFSET: usize = 40;
-pub const TRADE_LOG_ACCOUNT_LAST_OFFSET: usize = 72;
-pub const TRADE_LOG_ACCOUNT_COUNT_OFFSET: usize = 76;
-pub const TRADE_LOG_ACCOUNT_DATA_OFFSET: usize = 80;
-pub const TRADE_LOG_ACCOUNT_SIZE: usize = 40816;
 pub const TRADE_LOG_ACCOUNT_TAG: u8 = 12;
 pub const TRADE_LOG_ACCOUNT_VERSION: u32 = 14;
 pub const TRADE_LOG_BUFFER_CAPACITY: usize = 32;
@@ -29,8 +25,40 @@ pub struct TradeLogAccount {
     // 40736 bytes, TRADE_LOG_ACCOUNT_DATA_OFFSET
     pub data: [TradeLog; TRADE_LOG_BUFFER_CAPACITY],
 }
+
+devol_layout! {
+    TradeLogAccount, TRADE_LOG_ACCOUNT_SIZE = 40816, test_trade_log_account_layout;
+    header: AccountHeader => TRADE_LOG_ACCOUNT_VERSION_OFFSET = 0,
+    main_address: Pubkey => TRADE_LOG_ACCOUNT_MAIN_ADDRESS_OFFSET = 40,
+    last: u32 => TRADE_LOG_ACCOUNT_LAST_OFFSET = 72,
+    count: u32 => TRADE_LOG_ACCOUNT_COUNT_OFFSET = 76,
+    data: [TradeLog; TRADE_LOG_BUFFER_CAPACITY] => TRADE_LOG_ACCOUNT_DATA_OFFSET = 80,
+}
+
 impl DevolIndexedAccount for TradeLogAccount {}
 
+impl RingLog for TradeLogAccount {
+    type Record = TradeLog;
+
+    const CAPACITY: usize = TRADE_LOG_BUFFER_CAPACITY;
+
+    fn buffer(&self) -> &[TradeLog] {
+        &self.data
+    }
+
+    fn written_count(&self) -> u64 {
+        self.count as u64
+    }
+
+    fn newest_index(&self) -> Option<usize> {
+        (self.count > 0).then_some(self.last as usize % TRADE_LOG_BUFFER_CAPACITY)
+    }
+
+    fn record_id(record: &TradeLog) -> i64 {
+        record.get_id()
+    }
+}
+
 impl DevolAccount for TradeLogAccount {
     #[inline(always)]
     fn expected_size() -> usize { TRADE_LOG_ACCOUNT_SIZE }
@@ -58,39 +86,3 @@ impl Default for TradeLogAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_trade_log_account_offsets() {
-        let account = TradeLogAccount::default();
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &account.header as *const _ as usize - base_ptr,
-            0
-        );
-        assert_eq!(
-            &account.main_address as *const _ as usize - base_ptr,
-            TRADE_LOG_ACCOUNT_MAIN_ADDRESS_OFFSET
-        );
-        assert_eq!(
-            &account.last as *const _ as usize - base_ptr,
-            TRADE_LOG_ACCOUNT_LAST_OFFSET
-        );
-        assert_eq!(
-            &account.count as *const _ as usize - base_ptr,
-            TRADE_LOG_ACCOUNT_COUNT_OFFSET
-        );
-        assert_eq!(
-            &account.data as *const _ as usize - base_ptr,
-            TRADE_LOG_ACCOUNT_DATA_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<TradeLogAccount>(), TRADE_LOG_ACCOUNT_SIZE);
-    }
-}
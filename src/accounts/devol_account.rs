@@ -1,31 +1,81 @@
-use std::cell::Ref;
+use std::cell::{Ref, RefMut};
 use std::error::Error;
+use std::mem::size_of;
 use solana_program::account_info::{Account, IntoAccountInfo};
 use solana_program::account_info::AccountInfo;
 use solana_program::pubkey::Pubkey;
 use crate::accounts::account_header::AccountHeader;
+use crate::accounts::account_view::{borrow_account, borrow_account_mut, AccountView, AccountViewMut};
+use crate::accounts::devol_legacy_account::DevolLegacyLayout;
+use crate::accounts::devol_parse::DevolParse;
 use crate::dvl_error::DvlError;
 use crate::errors::*;
 
-pub trait DevolAccount {
+pub trait DevolAccount: DevolParse {
     fn expected_size() -> usize;
 
     fn expected_tag() -> u8;
 
     fn expected_version() -> u32;
 
+    /// Checked view over the account data, see `AccountView`.
     #[inline(always)]
-    fn account_header<'a>(data: Ref<&mut [u8]>) -> &'a AccountHeader {
-        unsafe { &*(data.as_ptr() as *const AccountHeader) }
+    fn view(data: &[u8]) -> Result<AccountView<'_, Self>, DvlError>
+        where
+            Self: Sized,
+    {
+        AccountView::new(AccountTag::from_u8(Self::expected_tag()), data, Self::expected_size())
+    }
+
+    /// Checked mutable view over the account data, see `AccountViewMut`.
+    #[inline(always)]
+    fn view_mut(data: &mut [u8]) -> Result<AccountViewMut<'_, Self>, DvlError>
+        where
+            Self: Sized,
+    {
+        AccountViewMut::new(AccountTag::from_u8(Self::expected_tag()), data, Self::expected_size())
+    }
+
+    /// Older layouts of the account which are still readable off-chain, see `DevolLegacyLayout`.
+    #[inline(always)]
+    fn legacy_layouts() -> &'static [DevolLegacyLayout<Self>]
+        where
+            Self: Sized + 'static,
+    {
+        &[]
+    }
+
+    #[inline(always)]
+    fn account_header(data: Ref<&mut [u8]>) -> Result<AccountHeader, DvlError> {
+        Self::read_header(&data)
+    }
+
+    #[inline(always)]
+    fn read_header(data: &[u8]) -> Result<AccountHeader, DvlError> {
+        let tag = AccountTag::from_u8(Self::expected_tag());
+        AccountView::<AccountHeader>::new(tag, data, size_of::<AccountHeader>())?.read_at(0)
     }
 
     #[inline(always)]
     fn check_basic(account_info: &AccountInfo, root_addr: &Pubkey, program_id: &Pubkey) -> Result<(), DvlError> {
         let tag = AccountTag::from_u8(Self::expected_tag());
         Self::check_size(tag, account_info.data.borrow())?;
-        let header = Self::account_header(account_info.data.borrow());
-        Self::check_tag_and_version(tag, header)?;
-        Self::check_root(tag, header, root_addr)?;
+        let header = Self::account_header(account_info.data.borrow())?;
+        Self::check_tag_and_version(tag, &header)?;
+        Self::check_root(tag, &header, root_addr)?;
+        Self::check_program_id(tag, account_info, program_id)?;
+        Ok(())
+    }
+
+    /// Same as `check_basic`, but leaves the size and the version to `from_versioned_data`.
+    #[inline(always)]
+    fn check_versioned_basic(account_info: &AccountInfo, root_addr: &Pubkey, program_id: &Pubkey) -> Result<(), DvlError> {
+        let tag = AccountTag::from_u8(Self::expected_tag());
+        let header = Self::account_header(account_info.data.borrow())?;
+        if header.tag != Self::expected_tag() as u32 {
+            return Err(DvlError::new_with_account(tag, ContractError::WrongAccountTag));
+        }
+        Self::check_root(tag, &header, root_addr)?;
         Self::check_program_id(tag, account_info, program_id)?;
         Ok(())
     }
@@ -72,28 +122,28 @@ pub trait DevolAccount {
     }
 
     /// Transforms `AccountInfo` into a reference of `Self` for on-chain use without the intent to modify the data.
+    /// The data stays borrowed while the returned guard lives.
     #[inline(always)]
     fn from_account_info_basic<'a>(
         account_info: &'a AccountInfo,
         root_addr: &Pubkey,
         program_id: &Pubkey,
-    ) -> Result<&'a Self, DvlError>
+    ) -> Result<Ref<'a, Self>, DvlError>
         where
             Self: Sized,
     {
         Self::check_basic(account_info, root_addr, program_id)?;
-        let account = unsafe { &*(account_info.data.borrow().as_ptr() as *const Self) };
-        Ok(account)
+        borrow_account(AccountTag::from_u8(Self::expected_tag()), account_info, Self::expected_size())
     }
 
     /// Transforms `AccountInfo` into a mutable reference of `Self` for on-chain use with the intent to modify the data.
-    /// Ensures the account is marked as writable.
+    /// Ensures the account is marked as writable. The data stays mutably borrowed while the returned guard lives.
     #[inline(always)]
     fn from_account_info_mut_basic<'a>(
         account_info: &'a AccountInfo,
         root_addr: &Pubkey,
         program_id: &Pubkey,
-    ) -> Result<&'a mut Self, DvlError>
+    ) -> Result<RefMut<'a, Self>, DvlError>
         where
             Self: Sized,
     {
@@ -101,11 +151,29 @@ pub trait DevolAccount {
         if !account_info.is_writable {
             return Err(DvlError::new_with_account(AccountTag::from_u8(Self::expected_tag()), ContractError::AccountWritableAttribute));
         }
-        let account = unsafe { &mut *(account_info.data.borrow_mut().as_ptr() as *mut Self) };
-        Ok(account)
+        borrow_account_mut(AccountTag::from_u8(Self::expected_tag()), account_info, Self::expected_size())
+    }
+
+    /// Copies the account out of data written with the current layout or any of the legacy ones,
+    /// a legacy layout is upgraded to `Self`.
+    fn from_versioned_data(data: &[u8]) -> Result<Box<Self>, DvlError>
+        where
+            Self: Sized + Copy + 'static
+    {
+        let header = Self::read_header(data)?;
+        if header.version != Self::expected_version() {
+            let legacy_layout = Self::legacy_layouts().iter()
+                .find(|layout| (layout.version)() == header.version);
+            if let Some(legacy_layout) = legacy_layout {
+                return (legacy_layout.decode)(data);
+            }
+        }
+        Self::check_tag_and_version(AccountTag::from_u8(Self::expected_tag()), &header)?;
+        Self::view(data)?.read_boxed()
     }
 
     /// Used off-chain to convert raw account data from RPC to a blockchain-utilized account structure.
+    /// The account is copied out, so RPC data does not have to be aligned, and legacy layouts are upgraded.
     #[inline(always)]
     fn from_account_basic(
         key: &Pubkey,
@@ -114,11 +182,12 @@ pub trait DevolAccount {
         program_id: &Pubkey,
     ) -> Result<Box<Self>, Box<dyn Error>>
         where
-            Self: Sized + Copy
+            Self: Sized + Copy + 'static
     {
         let account_info = (key, account).into_account_info();
-        let account_ref = Self::from_account_info_basic(&account_info, root_addr, program_id)?;
-        Ok(Box::new(*account_ref))
+        Self::check_versioned_basic(&account_info, root_addr, program_id)?;
+        let account = Self::from_versioned_data(&account_info.data.borrow())?;
+        Ok(account)
     }
 }
 
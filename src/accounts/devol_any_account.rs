@@ -0,0 +1,252 @@
+use std::mem::size_of;
+use crate::accounts::account_header::AccountHeader;
+use crate::accounts::account_view::AccountView;
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
+use crate::accounts::client::payoff_log::payoff_log_account::PayoffLogAccount;
+use crate::accounts::client::trade_log::trade_log_account::TradeLogAccount;
+use crate::accounts::devol_account::DevolAccount;
+use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+use crate::accounts::mints::mint_log::mint_log_account::MintLogAccount;
+use crate::accounts::mints::mints_account::MintsAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
+use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
+use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+use crate::accounts::worker::tasks_log::tasks_log_account::TasksLogAccount;
+use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
+use crate::accounts::worker::worker_account::WorkerAccount;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Any DeVol account decoded by the tag and version of its header.
+#[derive(Clone)]
+pub enum DevolAnyAccount {
+    Root(Box<RootAccount>),
+    Mints(Box<MintsAccount>),
+    Instruments(Box<InstrumentsAccount>),
+    AllWorkers(Box<AllWorkersAccount>),
+    PoolsTrace(Box<PoolsTraceAccount>),
+    TasksTrace(Box<TasksTraceAccount>),
+    PoolsLog(Box<PoolLogsAccount>),
+    PoolsLogV8(Box<PoolLogsAccountV8>),
+    Worker(Box<WorkerAccount>),
+    Client(Box<ClientAccountSnapshot>),
+    PayoffLog(Box<PayoffLogAccount>),
+    MintLog(Box<MintLogAccount>),
+    TasksLog(Box<TasksLogAccount>),
+    TradeLog(Box<TradeLogAccount>),
+    Oracle(Box<OraclesAccount>),
+}
+
+impl DevolAnyAccount {
+    pub fn tag(&self) -> AccountTag {
+        match self {
+            DevolAnyAccount::Root(_) => AccountTag::Root,
+            DevolAnyAccount::Mints(_) => AccountTag::Mints,
+            DevolAnyAccount::Instruments(_) => AccountTag::Instruments,
+            DevolAnyAccount::AllWorkers(_) => AccountTag::AllWorkers,
+            DevolAnyAccount::PoolsTrace(_) => AccountTag::PoolsTrace,
+            DevolAnyAccount::TasksTrace(_) => AccountTag::TasksTrace,
+            DevolAnyAccount::PoolsLog(_) | DevolAnyAccount::PoolsLogV8(_) => AccountTag::PoolsLog,
+            DevolAnyAccount::Worker(_) => AccountTag::Worker,
+            DevolAnyAccount::Client(_) => AccountTag::Client,
+            DevolAnyAccount::PayoffLog(_) => AccountTag::PayoffLog,
+            DevolAnyAccount::MintLog(_) => AccountTag::MintLog,
+            DevolAnyAccount::TasksLog(_) => AccountTag::TasksLog,
+            DevolAnyAccount::TradeLog(_) => AccountTag::TradeLog,
+            DevolAnyAccount::Oracle(_) => AccountTag::Oracle,
+        }
+    }
+
+    pub fn header(&self) -> &AccountHeader {
+        match self {
+            DevolAnyAccount::Root(account) => &account.header,
+            DevolAnyAccount::Mints(account) => &account.header,
+            DevolAnyAccount::Instruments(account) => &account.header,
+            DevolAnyAccount::AllWorkers(account) => &account.header,
+            DevolAnyAccount::PoolsTrace(account) => &account.header,
+            DevolAnyAccount::TasksTrace(account) => &account.header,
+            DevolAnyAccount::PoolsLog(account) => &account.header,
+            DevolAnyAccount::PoolsLogV8(account) => &account.header,
+            DevolAnyAccount::Worker(account) => &account.header,
+            DevolAnyAccount::Client(account) => &account.header,
+            DevolAnyAccount::PayoffLog(account) => &account.header,
+            DevolAnyAccount::MintLog(account) => &account.header,
+            DevolAnyAccount::TasksLog(account) => &account.header,
+            DevolAnyAccount::TradeLog(account) => &account.header,
+            DevolAnyAccount::Oracle(account) => &account.header,
+        }
+    }
+}
+
+impl DevolAnyAccount {
+    /// The account if it is a `T`, e.g. `into_account::<WorkerAccount>()`.
+    pub fn into_account<T: DevolAnyVariant>(self) -> Result<Box<T>, DvlError> {
+        let tag = self.tag();
+        T::from_any(self).ok_or_else(|| DvlError::new_with_account(tag, ContractError::WrongAccountTag))
+    }
+}
+
+/// Account type held by a `DevolAnyAccount` variant.
+pub trait DevolAnyVariant: Sized {
+    fn from_any(account: DevolAnyAccount) -> Option<Box<Self>>;
+}
+
+macro_rules! impl_devol_any_variant {
+    ($($struct_name:ty => $variant:ident),+) => {
+        $(
+            impl DevolAnyVariant for $struct_name {
+                fn from_any(account: DevolAnyAccount) -> Option<Box<Self>> {
+                    match account {
+                        DevolAnyAccount::$variant(account) => Some(account),
+                        _ => None,
+                    }
+                }
+            }
+        )+
+    };
+}
+
+impl_devol_any_variant!(
+    RootAccount => Root,
+    MintsAccount => Mints,
+    InstrumentsAccount => Instruments,
+    AllWorkersAccount => AllWorkers,
+    PoolsTraceAccount => PoolsTrace,
+    TasksTraceAccount => TasksTrace,
+    PoolLogsAccount => PoolsLog,
+    PoolLogsAccountV8 => PoolsLogV8,
+    WorkerAccount => Worker,
+    ClientAccountSnapshot => Client,
+    PayoffLogAccount => PayoffLog,
+    MintLogAccount => MintLog,
+    TasksLogAccount => TasksLog,
+    TradeLogAccount => TradeLog,
+    OraclesAccount => Oracle
+);
+
+/// Decodes raw data of any program-owned account by `AccountHeader.tag` and `version`.
+/// Only the layout is checked, the root and the owner of the account are up to the caller.
+pub fn decode_any(data: &[u8]) -> Result<DevolAnyAccount, DvlError> {
+    let header: AccountHeader = AccountView::<AccountHeader>::new(AccountTag::AccountDecodeError, data, size_of::<AccountHeader>())?
+        .read_at(0)?;
+    let tag = u8::try_from(header.tag).map_or(AccountTag::AccountDecodeError, AccountTag::from_u8);
+    let account = match tag {
+        AccountTag::Root => DevolAnyAccount::Root(decode(data, &header)?),
+        AccountTag::Mints => DevolAnyAccount::Mints(decode(data, &header)?),
+        AccountTag::Instruments => DevolAnyAccount::Instruments(decode(data, &header)?),
+        AccountTag::AllWorkers => DevolAnyAccount::AllWorkers(decode(data, &header)?),
+        AccountTag::PoolsTrace => DevolAnyAccount::PoolsTrace(decode(data, &header)?),
+        AccountTag::TasksTrace => DevolAnyAccount::TasksTrace(decode(data, &header)?),
+        AccountTag::PoolsLog if header.version == PoolLogsAccountV8::expected_version() => {
+            DevolAnyAccount::PoolsLogV8(decode(data, &header)?)
+        }
+        AccountTag::PoolsLog => DevolAnyAccount::PoolsLog(decode(data, &header)?),
+        AccountTag::Worker => DevolAnyAccount::Worker(decode(data, &header)?),
+        AccountTag::Client => {
+            ClientAccount::check_tag_and_version(tag, &header)?;
+            DevolAnyAccount::Client(Box::new(ClientAccountSnapshot::from_account_data(data)?))
+        }
+        AccountTag::PayoffLog => DevolAnyAccount::PayoffLog(decode(data, &header)?),
+        AccountTag::MintLog => DevolAnyAccount::MintLog(decode(data, &header)?),
+        AccountTag::TasksLog => DevolAnyAccount::TasksLog(decode(data, &header)?),
+        AccountTag::TradeLog => DevolAnyAccount::TradeLog(decode(data, &header)?),
+        AccountTag::Oracle => DevolAnyAccount::Oracle(decode(data, &header)?),
+        _ => return Err(DvlError::new_with_account(tag, ContractError::UnknownAccountTag)),
+    };
+    Ok(account)
+}
+
+fn decode<T: DevolAccount + Copy>(data: &[u8], header: &AccountHeader) -> Result<Box<T>, DvlError> {
+    T::check_tag_and_version(AccountTag::from_u8(T::expected_tag()), header)?;
+    T::view(data)?.read_boxed()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::account_view::AccountViewMut;
+    use crate::accounts::devol_parse::DevolWrite;
+    use crate::accounts::client::client_account::client_account::CLIENT_ACCOUNT_SIZE;
+    use crate::accounts::root::root_account::{ROOT_ACCOUNT_SIZE, ROOT_ACCOUNT_TAG, ROOT_ACCOUNT_VERSION};
+    use crate::accounts::worker::pool_logs::v8::pools_log_account_v8;
+
+    fn account_data<T: DevolWrite>(account: &T, size: usize) -> Vec<u8> {
+        let mut data = vec![0u8; size];
+        AccountViewMut::<T>::new(AccountTag::AccountDecodeError, &mut data, size).unwrap()
+            .write_record(0, size, 0, account).unwrap();
+        data
+    }
+
+    #[test]
+    fn test_decode_any_root() {
+        let mut account = RootAccount::default();
+        account.header.tag = ROOT_ACCOUNT_TAG as u32;
+        account.header.version = ROOT_ACCOUNT_VERSION;
+        account.clients_count = 5;
+        let decoded = decode_any(&account_data(&account, ROOT_ACCOUNT_SIZE)).unwrap();
+        assert_eq!(decoded.tag(), AccountTag::Root);
+        match decoded {
+            DevolAnyAccount::Root(root) => assert_eq!(root.clients_count, 5),
+            _ => panic!("Root account expected"),
+        }
+    }
+
+    #[test]
+    fn test_decode_any_pools_log_v8() {
+        let mut account = PoolLogsAccountV8::default();
+        account.header.tag = pools_log_account_v8::POOLS_LOG_ACCOUNT_TAG as u32;
+        account.header.version = pools_log_account_v8::POOLS_LOG_ACCOUNT_VERSION;
+        account.worker_id = 3;
+        let decoded = decode_any(&account_data(&account, pools_log_account_v8::POOLS_LOG_ACCOUNT_SIZE)).unwrap();
+        assert_eq!(decoded.tag(), AccountTag::PoolsLog);
+        match decoded {
+            DevolAnyAccount::PoolsLogV8(pools_log) => assert_eq!(pools_log.worker_id, 3),
+            _ => panic!("Pools log v8 account expected"),
+        }
+    }
+
+    #[test]
+    fn test_decode_any_client() {
+        let mut account = ClientAccount::default();
+        account.set_ops_counter(3);
+        account.id = 11;
+        let decoded = decode_any(&account_data(&account, CLIENT_ACCOUNT_SIZE)).unwrap();
+        assert_eq!(decoded.header().tag, AccountTag::Client as u32);
+        match decoded {
+            DevolAnyAccount::Client(client) => {
+                assert_eq!(client.id, 11);
+                assert_eq!(client.ops_counter, 3);
+                assert_eq!(client.get_pools_count(), 0);
+            }
+            _ => panic!("Client account expected"),
+        }
+    }
+
+    #[test]
+    fn test_decode_any_errors() {
+        let mut account = RootAccount::default();
+        account.header.tag = ROOT_ACCOUNT_TAG as u32;
+        account.header.version = ROOT_ACCOUNT_VERSION + 1;
+        assert!(decode_any(&account_data(&account, ROOT_ACCOUNT_SIZE)).is_err());
+        account.header.version = ROOT_ACCOUNT_VERSION;
+        assert!(decode_any(&account_data(&account, ROOT_ACCOUNT_SIZE)[..ROOT_ACCOUNT_SIZE - 1]).is_err());
+        account.header.tag = AccountTag::Buffer as u32;
+        assert!(decode_any(&account_data(&account, ROOT_ACCOUNT_SIZE)).is_err());
+        assert!(decode_any(&[0u8; 8]).is_err());
+    }
+
+    #[test]
+    fn test_into_account() {
+        let mut account = RootAccount::default();
+        account.header.tag = ROOT_ACCOUNT_TAG as u32;
+        account.header.version = ROOT_ACCOUNT_VERSION;
+        account.clients_count = 2;
+        let decoded = decode_any(&account_data(&account, ROOT_ACCOUNT_SIZE)).unwrap();
+        assert!(decoded.clone().into_account::<MintsAccount>().is_err());
+        assert_eq!(decoded.into_account::<RootAccount>().unwrap().clients_count, 2);
+    }
+}
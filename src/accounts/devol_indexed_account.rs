@@ -1,6 +1,9 @@
+use std::cell::{Ref, RefMut};
 use std::error::Error;
+use std::mem::size_of;
 use solana_program::account_info::{Account, AccountInfo, IntoAccountInfo};
 use solana_program::pubkey::Pubkey;
+use crate::accounts::account_view::AccountView;
 use crate::accounts::devol_account::DevolAccount;
 use crate::dvl_error::DvlError;
 use crate::errors::{AccountTag, ContractError};
@@ -13,12 +16,19 @@ pub trait DevolIndexedAccount : DevolAccount {
     #[inline(always)]
     fn id_offset() -> usize { 40 }
 
+    /// Only the ID is bounds-checked, not the whole account, so the ID of a legacy layout shorter
+    /// than `Self` is checked before `from_versioned_data` upgrades it.
     #[inline(always)]
-    fn check_id(account_info: &AccountInfo, id: Option<u32>) -> Result<(), DvlError> {
+    fn check_id(account_info: &AccountInfo, id: Option<u32>) -> Result<(), DvlError>
+        where
+            Self: Sized,
+    {
         if let Some(id) = id {
             let tag = AccountTag::from_u8(Self::expected_tag());
-            let read_id = unsafe { *(account_info.data.borrow().as_ptr().add(Self::id_offset()) as *const u32) };
-            if read_id != id as u32 {
+            let data = account_info.data.borrow();
+            let read_id: u32 = AccountView::<u32>::new(tag, &data, Self::id_offset() + size_of::<u32>())?
+                .read_at(Self::id_offset())?;
+            if read_id != id {
                 return Err(DvlError::new_with_account(tag, ContractError::InvalidAccountId));
             }
         }
@@ -26,7 +36,10 @@ pub trait DevolIndexedAccount : DevolAccount {
     }
 
     #[inline(always)]
-    fn check_all(account_info: &AccountInfo, root_addr: &Pubkey, program_id: &Pubkey, id: Option<u32>) -> Result<(), DvlError> {
+    fn check_all(account_info: &AccountInfo, root_addr: &Pubkey, program_id: &Pubkey, id: Option<u32>) -> Result<(), DvlError>
+        where
+            Self: Sized,
+    {
         Self::check_basic(account_info, root_addr, program_id)?;
         Self::check_id(account_info, id)?;
         Ok(())
@@ -39,7 +52,7 @@ pub trait DevolIndexedAccount : DevolAccount {
         root_addr: &Pubkey,
         program_id: &Pubkey,
         id: Option<u32>,
-    ) -> Result<&'a Self, DvlError>
+    ) -> Result<Ref<'a, Self>, DvlError>
         where
             Self: Sized,
     {
@@ -56,16 +69,17 @@ pub trait DevolIndexedAccount : DevolAccount {
         root_addr: &Pubkey,
         program_id: &Pubkey,
         id: Option<u32>,
-    ) -> Result<&'a mut Self, DvlError>
+    ) -> Result<RefMut<'a, Self>, DvlError>
         where
             Self: Sized,
     {
-        let account = Self::from_account_info_mut_basic(account_info,root_addr,program_id)?;
+        // The id is read before the data is borrowed mutably.
         Self::check_id(account_info, id)?;
-        Ok(account)
+        Self::from_account_info_mut_basic(account_info, root_addr, program_id)
     }
 
     /// Used off-chain to convert raw account data from RPC to a blockchain-utilized account structure.
+    /// The account is copied out, so RPC data does not have to be aligned, and legacy layouts are upgraded.
     #[inline(always)]
     fn from_account(
         key: &Pubkey,
@@ -75,10 +89,12 @@ pub trait DevolIndexedAccount : DevolAccount {
         id: Option<u32>,
     ) -> Result<Box<Self>, Box<dyn Error>>
         where
-            Self: Sized + Copy
+            Self: Sized + Copy + 'static
     {
         let account_info = (key, account).into_account_info();
-        let account_ref = Self::from_account_info(&account_info, root_addr, program_id, id)?;
-        Ok(Box::new(*account_ref))
+        Self::check_versioned_basic(&account_info, root_addr, program_id)?;
+        Self::check_id(&account_info, id)?;
+        let account = Self::from_versioned_data(&account_info.data.borrow())?;
+        Ok(account)
     }
 }
\ No newline at end of file
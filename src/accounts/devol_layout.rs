@@ -0,0 +1,314 @@
+use std::fmt;
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::payoff_log::payoff_log_account::PayoffLogAccount;
+use crate::accounts::client::trade_log::trade_log_account::TradeLogAccount;
+use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+use crate::accounts::mints::mint_log::mint_log_account::MintLogAccount;
+use crate::accounts::mints::mints_account::MintsAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
+use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
+use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+use crate::accounts::worker::tasks_log::tasks_log_account::TasksLogAccount;
+use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
+use crate::accounts::worker::worker_account::WorkerAccount;
+
+/// Field of a `#[repr(C)]` structure as it is laid out in the account data.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct FieldLayout {
+    pub name: &'static str,
+    pub offset: usize,
+    pub size: usize,
+    pub type_name: &'static str,
+}
+
+/// Field table of a `#[repr(C)]` structure generated by `devol_layout!`. `size` is the size of
+/// the data the program reads and writes, it may be less than `size_of` by the trailing padding.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub struct StructLayout {
+    pub name: &'static str,
+    pub size: usize,
+    pub fields: &'static [FieldLayout],
+}
+
+/// Difference between two versions of a layout, see `StructLayout::diff`.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LayoutChange {
+    Size { old: usize, new: usize },
+    Added(FieldLayout),
+    Removed(FieldLayout),
+    Changed { old: FieldLayout, new: FieldLayout },
+}
+
+pub trait DevolLayout {
+    const LAYOUT: StructLayout;
+}
+
+/// Layouts of all program accounts, including the legacy ones still readable off-chain.
+pub const ACCOUNT_LAYOUTS: &[StructLayout] = &[
+    RootAccount::LAYOUT,
+    MintsAccount::LAYOUT,
+    InstrumentsAccount::LAYOUT,
+    AllWorkersAccount::LAYOUT,
+    PoolsTraceAccount::LAYOUT,
+    TasksTraceAccount::LAYOUT,
+    PoolLogsAccount::LAYOUT,
+    PoolLogsAccountV8::LAYOUT,
+    WorkerAccount::LAYOUT,
+    ClientAccount::LAYOUT,
+    PayoffLogAccount::LAYOUT,
+    MintLogAccount::LAYOUT,
+    TasksLogAccount::LAYOUT,
+    TradeLogAccount::LAYOUT,
+    OraclesAccount::LAYOUT,
+];
+
+impl StructLayout {
+    pub fn field(&self, name: &str) -> Option<&FieldLayout> {
+        self.fields.iter().find(|field| field.name == name)
+    }
+
+    /// Changes from `self` to `new`, fields are matched by name.
+    pub fn diff(&self, new: &StructLayout) -> Vec<LayoutChange> {
+        let mut changes = Vec::new();
+        if self.size != new.size {
+            changes.push(LayoutChange::Size { old: self.size, new: new.size });
+        }
+        for old_field in self.fields {
+            match new.field(old_field.name) {
+                Some(new_field) if new_field != old_field => {
+                    changes.push(LayoutChange::Changed { old: *old_field, new: *new_field })
+                }
+                Some(_) => {}
+                None => changes.push(LayoutChange::Removed(*old_field)),
+            }
+        }
+        for new_field in new.fields {
+            if self.field(new_field.name).is_none() {
+                changes.push(LayoutChange::Added(*new_field));
+            }
+        }
+        changes
+    }
+}
+
+impl fmt::Display for FieldLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        write!(f, "{:>6} {:>6}  {}: {}", self.offset, self.size, self.name, self.type_name)
+    }
+}
+
+impl fmt::Display for StructLayout {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        writeln!(f, "{} ({} bytes)", self.name, self.size)?;
+        for field in self.fields {
+            writeln!(f, "{}", field)?;
+        }
+        Ok(())
+    }
+}
+
+/// Checks the generated field table against the compiled structure. The declared offsets are the
+/// source of truth, so the check fails when the structure changes without the table.
+#[cfg(test)]
+pub(crate) fn check_layout<T: DevolLayout>(offsets: &[usize]) {
+    use crate::utils::type_size_helper::align_size;
+    let layout = T::LAYOUT;
+    assert_eq!(layout.fields.len(), offsets.len());
+    let mut end = 0;
+    for (field, offset) in layout.fields.iter().zip(offsets) {
+        assert_eq!(field.offset, *offset, "{}.{} offset", layout.name, field.name);
+        assert!(field.offset >= end, "{}.{} overlaps the previous field", layout.name, field.name);
+        end = field.offset + field.size;
+    }
+    assert!(end <= layout.size, "{} fields exceed the size", layout.name);
+    assert_eq!(std::mem::size_of::<T>(), align_size(layout.size, std::mem::align_of::<T>()), "{} size", layout.name);
+}
+
+/// Declares the field table of a `#[repr(C)]` structure: generates the size constant, an
+/// `_OFFSET` constant for every field which names one, the `DevolLayout`, `DevolParse` and
+/// `DevolWrite` implementations and a test module, named by `$test`, checking the table against the
+/// compiled structure.
+///
+/// Fields are parsed by their type, `[u8; N]` as a native-endian integer. A field with another
+/// encoding names the `LeParser` method reading it, e.g. `#[parse = LeParser::bytes]` for raw bytes,
+/// and is written by the `LeWriter` method of the same name. Such fields are plain values which
+/// `DevolParse::check_at` skips.
+///
+/// ```ignore
+/// devol_layout! {
+///     Mint, MINT_SIZE = 128, test_mint_layout;
+///     address: Pubkey => MINT_ADDRESS_OFFSET = 0,
+///     #[parse = LeParser::bytes]
+///     ticker: [u8; 8] => MINT_TICKER_OFFSET = 36,
+///     ...
+/// }
+/// ```
+macro_rules! devol_layout {
+    (
+        $struct_type:ident, $size_const:ident = $size:expr, $test:ident;
+        $( $( #[parse = LeParser::$parse:ident $( ::<$( $parse_arg:tt ),*> )?] )? $field:ident : $field_type:ty $( => $offset_const:ident )? = $offset:expr ),* $(,)?
+    ) => {
+        pub const $size_const: usize = $size;
+        $( $( pub const $offset_const: usize = $offset; )? )*
+
+        impl $crate::accounts::devol_layout::DevolLayout for $struct_type {
+            const LAYOUT: $crate::accounts::devol_layout::StructLayout = $crate::accounts::devol_layout::StructLayout {
+                name: stringify!($struct_type),
+                size: $size_const,
+                fields: &[
+                    $( $crate::accounts::devol_layout::FieldLayout {
+                        name: stringify!($field),
+                        offset: $offset,
+                        size: std::mem::size_of::<$field_type>(),
+                        type_name: stringify!($field_type),
+                    } ),*
+                ],
+            };
+        }
+
+        impl $crate::accounts::devol_parse::DevolParse for $struct_type {
+            unsafe fn parse_into(
+                parser: &$crate::accounts::devol_parse::LeParser,
+                offset: usize,
+                place: *mut Self,
+            ) -> Result<(), $crate::dvl_error::DvlError> {
+                $( $crate::accounts::devol_layout::devol_parse_field!(
+                    parser, offset + $offset, std::ptr::addr_of_mut!((*place).$field), $field_type,
+                    [$( $parse $( ::<$( $parse_arg ),*> )? )?]
+                )?; )*
+                Ok(())
+            }
+
+            fn check_at(
+                parser: &$crate::accounts::devol_parse::LeParser,
+                offset: usize,
+            ) -> Result<(), $crate::dvl_error::DvlError> {
+                $( $crate::accounts::devol_layout::devol_check_field!(
+                    parser, offset + $offset, $field_type, [$( $parse $( ::<$( $parse_arg ),*> )? )?]
+                )?; )*
+                Ok(())
+            }
+        }
+
+        impl $crate::accounts::devol_parse::DevolWrite for $struct_type {
+            fn write_at(
+                &self,
+                writer: &mut $crate::accounts::devol_parse::LeWriter,
+                offset: usize,
+            ) -> Result<(), $crate::dvl_error::DvlError> {
+                $( $crate::accounts::devol_layout::devol_write_field!(
+                    writer, offset + $offset, &self.$field, $field_type, [$( $parse $( ::<$( $parse_arg ),*> )? )?]
+                )?; )*
+                Ok(())
+            }
+        }
+
+        #[cfg(test)]
+        mod $test {
+            use super::*;
+
+            #[test]
+            fn layout() {
+                $( let _: fn(&$struct_type) -> &$field_type = |value| &value.$field; )*
+                $crate::accounts::devol_layout::check_layout::<$struct_type>(&[
+                    $( std::mem::offset_of!($struct_type, $field) ),*
+                ]);
+            }
+        }
+    };
+}
+
+/// Parses one field of a `devol_layout!` table into `place`, by its type or with the named method.
+macro_rules! devol_parse_field {
+    ($parser:expr, $offset:expr, $place:expr, $field_type:ty, []) => {
+        <$field_type as $crate::accounts::devol_parse::DevolParse>::parse_into($parser, $offset, $place)
+    };
+    ($parser:expr, $offset:expr, $place:expr, $field_type:ty, [$( $parse:tt )+]) => {
+        $crate::accounts::devol_parse::LeParser::$( $parse )+($parser, $offset).map(|value| $place.write(value))
+    };
+}
+
+/// Checks one field of a `devol_layout!` table, fields with a named method hold plain values.
+macro_rules! devol_check_field {
+    ($parser:expr, $offset:expr, $field_type:ty, []) => {
+        <$field_type as $crate::accounts::devol_parse::DevolParse>::check_at($parser, $offset)
+    };
+    ($parser:expr, $offset:expr, $field_type:ty, [$( $parse:tt )+]) => {
+        Ok::<(), $crate::dvl_error::DvlError>(())
+    };
+}
+
+/// Writes one field of a `devol_layout!` table, by its type or with the `LeWriter` counterpart of
+/// the named method.
+macro_rules! devol_write_field {
+    ($writer:expr, $offset:expr, $value:expr, $field_type:ty, []) => {
+        <$field_type as $crate::accounts::devol_parse::DevolWrite>::write_at($value, $writer, $offset)
+    };
+    ($writer:expr, $offset:expr, $value:expr, $field_type:ty, [$( $parse:tt )+]) => {
+        $crate::accounts::devol_parse::LeWriter::$( $parse )+($writer, $offset, *$value)
+    };
+}
+
+pub(crate) use devol_layout;
+pub(crate) use devol_parse_field;
+pub(crate) use devol_check_field;
+pub(crate) use devol_write_field;
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::devol_account::DevolAccount;
+
+    const OLD_FIELDS: [FieldLayout; 2] = [
+        FieldLayout { name: "id", offset: 0, size: 4, type_name: "u32" },
+        FieldLayout { name: "time", offset: 4, size: 8, type_name: "[u8; 8]" },
+    ];
+    const NEW_FIELDS: [FieldLayout; 2] = [
+        FieldLayout { name: "id", offset: 0, size: 4, type_name: "u32" },
+        FieldLayout { name: "time", offset: 8, size: 8, type_name: "i64" },
+    ];
+
+    #[test]
+    fn test_struct_layout_diff() {
+        let old = StructLayout { name: "Log", size: 12, fields: &OLD_FIELDS };
+        let new = StructLayout { name: "Log", size: 16, fields: &NEW_FIELDS };
+        assert!(old.diff(&old).is_empty());
+        assert_eq!(old.diff(&new), vec![
+            LayoutChange::Size { old: 12, new: 16 },
+            LayoutChange::Changed { old: OLD_FIELDS[1], new: NEW_FIELDS[1] },
+        ]);
+        assert_eq!(new.diff(&StructLayout { name: "Log", size: 16, fields: &NEW_FIELDS[..1] }),
+                   vec![LayoutChange::Removed(NEW_FIELDS[1])]);
+        assert_eq!(old.to_string().lines().count(), 3);
+    }
+
+    fn check_account_size<T: DevolAccount + DevolLayout>() {
+        assert_eq!(T::expected_size(), T::LAYOUT.size, "{} size", T::LAYOUT.name);
+        assert!(ACCOUNT_LAYOUTS.contains(&T::LAYOUT));
+    }
+
+    #[test]
+    fn test_account_layouts() {
+        check_account_size::<RootAccount>();
+        check_account_size::<MintsAccount>();
+        check_account_size::<InstrumentsAccount>();
+        check_account_size::<AllWorkersAccount>();
+        check_account_size::<PoolsTraceAccount>();
+        check_account_size::<TasksTraceAccount>();
+        check_account_size::<PoolLogsAccount>();
+        check_account_size::<PoolLogsAccountV8>();
+        check_account_size::<WorkerAccount>();
+        check_account_size::<ClientAccount>();
+        check_account_size::<PayoffLogAccount>();
+        check_account_size::<MintLogAccount>();
+        check_account_size::<TasksLogAccount>();
+        check_account_size::<TradeLogAccount>();
+        check_account_size::<OraclesAccount>();
+        assert_eq!(ACCOUNT_LAYOUTS.len(), 15);
+        assert!(PoolLogsAccountV8::LAYOUT.diff(&PoolLogsAccount::LAYOUT).iter()
+            .any(|change| matches!(change, LayoutChange::Removed(field) if field.name == "last")));
+    }
+}
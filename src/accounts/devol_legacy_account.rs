@@ -0,0 +1,29 @@
+use crate::accounts::devol_account::DevolAccount;
+use crate::dvl_error::DvlError;
+use crate::errors::AccountTag;
+
+/// Older layout of the `T` account which can be upgraded to the current one.
+pub trait DevolLegacyAccount<T>: DevolAccount + Copy {
+    fn upgrade(&self) -> Box<T>;
+}
+
+/// Entry of `DevolAccount::legacy_layouts`: the version of a legacy layout and its decoder into `T`.
+pub struct DevolLegacyLayout<T> {
+    pub version: fn() -> u32,
+    pub decode: fn(&[u8]) -> Result<Box<T>, DvlError>,
+}
+
+impl<T> DevolLegacyLayout<T> {
+    pub const fn of<L: DevolLegacyAccount<T>>() -> Self {
+        Self {
+            version: L::expected_version,
+            decode: decode_legacy::<L, T>,
+        }
+    }
+}
+
+fn decode_legacy<L: DevolLegacyAccount<T>, T>(data: &[u8]) -> Result<Box<T>, DvlError> {
+    let header = L::read_header(data)?;
+    L::check_tag_and_version(AccountTag::from_u8(L::expected_tag()), &header)?;
+    Ok(L::view(data)?.read_boxed()?.upgrade())
+}
@@ -0,0 +1,494 @@
+use std::alloc::{alloc_zeroed, dealloc, handle_alloc_error, Layout};
+use std::mem::{size_of, MaybeUninit};
+use solana_program::pubkey::Pubkey;
+use crate::accounts::account_header::AccountHeader;
+use crate::accounts::devol_account::DevolAccount;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Little-endian reader over raw account data. Every value is read at an explicit offset and
+/// rebuilt field by field, so neither the alignment nor the host layout and endianness matter.
+pub struct LeParser<'a> {
+    tag: AccountTag,
+    data: &'a [u8],
+}
+
+/// Little-endian writer over raw account data, the counterpart of `LeParser`. Values are written
+/// field by field, so the padding of a structure never reaches the data.
+pub struct LeWriter<'a> {
+    tag: AccountTag,
+    data: &'a mut [u8],
+}
+
+/// Structure which can be written by `LeWriter`, `devol_layout!` generates it from the field table
+/// with the encoding of `DevolParse`. The bytes between the fields are left as they are.
+pub trait DevolWrite {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError>;
+}
+
+/// Structure which can be parsed by `LeParser` without transmuting the data. Implementations
+/// provide either `parse_at` or `parse_into`, `devol_layout!` generates the latter from the field
+/// table.
+pub trait DevolParse: Sized {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        let mut value = MaybeUninit::<Self>::zeroed();
+        unsafe {
+            Self::parse_into(parser, offset, value.as_mut_ptr())?;
+            Ok(value.assume_init())
+        }
+    }
+
+    /// Parses the value straight into `place`, so large accounts are never moved through the stack.
+    ///
+    /// # Safety
+    /// `place` must be valid for writes and aligned. An implementation writing the value field by
+    /// field must initialize every field which is invalid when zeroed.
+    unsafe fn parse_into(parser: &LeParser, offset: usize, place: *mut Self) -> Result<(), DvlError> {
+        place.write(Self::parse_at(parser, offset)?);
+        Ok(())
+    }
+
+    /// Checks that the data at `offset` is a valid `Self`, i.e. every enum and `bool` in it holds
+    /// a known value, so `AccountView` may copy or reference it as `Self`. Types without invalid
+    /// bit patterns override it with a no-op.
+    fn check_at(parser: &LeParser, offset: usize) -> Result<(), DvlError> {
+        Self::parse_at(parser, offset).map(drop)
+    }
+
+    /// Parses the whole account after checking its size, tag and version. Unlike `view`, this is
+    /// safe for untrusted data: invalid enum values fail with `ContractError::AccountFieldValue`.
+    /// The account is parsed in place into a zeroed heap allocation.
+    fn parse(data: &[u8]) -> Result<Box<Self>, DvlError>
+        where
+            Self: DevolAccount,
+    {
+        let tag = AccountTag::from_u8(Self::expected_tag());
+        let parser = LeParser::new(tag, data, Self::expected_size())?;
+        let header: AccountHeader = parser.parse(0)?;
+        Self::check_tag_and_version(tag, &header)?;
+        let layout = Layout::new::<Self>();
+        unsafe {
+            let place = alloc_zeroed(layout) as *mut Self;
+            if place.is_null() {
+                handle_alloc_error(layout);
+            }
+            if let Err(error) = Self::parse_into(&parser, 0, place) {
+                dealloc(place as *mut u8, layout);
+                return Err(error);
+            }
+            Ok(Box::from_raw(place))
+        }
+    }
+}
+
+impl<'a> LeParser<'a> {
+    pub fn new(tag: AccountTag, data: &'a [u8], size: usize) -> Result<Self, DvlError> {
+        if data.len() < size {
+            return Err(DvlError::new_with_account(tag, ContractError::AccountSize));
+        }
+        Ok(Self { tag, data })
+    }
+
+    pub fn bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], DvlError> {
+        offset.checked_add(N)
+            .and_then(|end| self.data.get(offset..end))
+            .map(|bytes| bytes.try_into().unwrap())
+            .ok_or_else(|| DvlError::new_with_account(self.tag, ContractError::AccountSize))
+    }
+
+    /// Little-endian integer of `N` bytes in the native byte order, for the `[u8; N]` fields with
+    /// `from_ne_bytes` accessors.
+    pub fn ne_bytes<const N: usize>(&self, offset: usize) -> Result<[u8; N], DvlError> {
+        let mut bytes = self.bytes::<N>(offset)?;
+        if cfg!(target_endian = "big") {
+            bytes.reverse();
+        }
+        Ok(bytes)
+    }
+
+    /// Packed `WORD`-byte little-endian integers in the native byte order, e.g. the `[u8; 8 * N]`
+    /// arrays of `ClientPool`.
+    pub fn ne_words<const N: usize, const WORD: usize>(&self, offset: usize) -> Result<[u8; N], DvlError> {
+        let mut bytes = self.bytes::<N>(offset)?;
+        if cfg!(target_endian = "big") {
+            bytes.chunks_mut(WORD).for_each(|word| word.reverse());
+        }
+        Ok(bytes)
+    }
+
+    pub fn u8(&self, offset: usize) -> Result<u8, DvlError> {
+        Ok(self.bytes::<1>(offset)?[0])
+    }
+
+    pub fn u16(&self, offset: usize) -> Result<u16, DvlError> {
+        Ok(u16::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn i16(&self, offset: usize) -> Result<i16, DvlError> {
+        Ok(i16::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn u32(&self, offset: usize) -> Result<u32, DvlError> {
+        Ok(u32::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn i32(&self, offset: usize) -> Result<i32, DvlError> {
+        Ok(i32::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn u64(&self, offset: usize) -> Result<u64, DvlError> {
+        Ok(u64::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn i64(&self, offset: usize) -> Result<i64, DvlError> {
+        Ok(i64::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn f64(&self, offset: usize) -> Result<f64, DvlError> {
+        Ok(f64::from_le_bytes(self.bytes(offset)?))
+    }
+
+    pub fn bool(&self, offset: usize) -> Result<bool, DvlError> {
+        match self.u8(offset)? {
+            0 => Ok(false),
+            1 => Ok(true),
+            _ => Err(self.field_error()),
+        }
+    }
+
+    pub fn pubkey(&self, offset: usize) -> Result<Pubkey, DvlError> {
+        Ok(Pubkey::new_from_array(self.bytes(offset)?))
+    }
+
+    pub fn parse<T: DevolParse>(&self, offset: usize) -> Result<T, DvlError> {
+        T::parse_at(self, offset)
+    }
+
+    /// Error for a value out of the range of its field, e.g. an unknown enum variant.
+    pub fn field_error(&self) -> DvlError {
+        DvlError::new_with_account(self.tag, ContractError::AccountFieldValue)
+    }
+}
+
+impl<'a> LeWriter<'a> {
+    pub fn new(tag: AccountTag, data: &'a mut [u8], size: usize) -> Result<Self, DvlError> {
+        if data.len() < size {
+            return Err(DvlError::new_with_account(tag, ContractError::AccountSize));
+        }
+        Ok(Self { tag, data })
+    }
+
+    pub fn bytes<const N: usize>(&mut self, offset: usize, value: [u8; N]) -> Result<(), DvlError> {
+        offset.checked_add(N)
+            .and_then(|end| self.data.get_mut(offset..end))
+            .map(|bytes| bytes.copy_from_slice(&value))
+            .ok_or_else(|| DvlError::new_with_account(self.tag, ContractError::AccountSize))
+    }
+
+    /// See `LeParser::ne_bytes`.
+    pub fn ne_bytes<const N: usize>(&mut self, offset: usize, mut value: [u8; N]) -> Result<(), DvlError> {
+        if cfg!(target_endian = "big") {
+            value.reverse();
+        }
+        self.bytes(offset, value)
+    }
+
+    /// See `LeParser::ne_words`.
+    pub fn ne_words<const N: usize, const WORD: usize>(&mut self, offset: usize, mut value: [u8; N]) -> Result<(), DvlError> {
+        if cfg!(target_endian = "big") {
+            value.chunks_mut(WORD).for_each(|word| word.reverse());
+        }
+        self.bytes(offset, value)
+    }
+
+    pub fn u8(&mut self, offset: usize, value: u8) -> Result<(), DvlError> {
+        self.bytes(offset, [value])
+    }
+
+    pub fn u16(&mut self, offset: usize, value: u16) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn i16(&mut self, offset: usize, value: i16) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn u32(&mut self, offset: usize, value: u32) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn i32(&mut self, offset: usize, value: i32) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn u64(&mut self, offset: usize, value: u64) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn i64(&mut self, offset: usize, value: i64) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn f64(&mut self, offset: usize, value: f64) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_le_bytes())
+    }
+
+    pub fn bool(&mut self, offset: usize, value: bool) -> Result<(), DvlError> {
+        self.u8(offset, value as u8)
+    }
+
+    pub fn pubkey(&mut self, offset: usize, value: Pubkey) -> Result<(), DvlError> {
+        self.bytes(offset, value.to_bytes())
+    }
+
+    pub fn write<T: DevolWrite>(&mut self, offset: usize, value: &T) -> Result<(), DvlError> {
+        value.write_at(self, offset)
+    }
+}
+
+impl DevolParse for AccountHeader {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        Ok(Self {
+            tag: parser.u32(offset)?,
+            version: parser.u32(offset + 4)?,
+            root: parser.pubkey(offset + 8)?,
+        })
+    }
+
+    fn check_at(_parser: &LeParser, _offset: usize) -> Result<(), DvlError> {
+        Ok(())
+    }
+}
+
+impl DevolWrite for AccountHeader {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u32(offset, self.tag)?;
+        writer.u32(offset + 4, self.version)?;
+        writer.pubkey(offset + 8, self.root)
+    }
+}
+
+macro_rules! le_parse {
+    ($( $type:ident ),*) => {
+        $( impl DevolParse for $type {
+            fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+                parser.$type(offset)
+            }
+
+            fn check_at(_parser: &LeParser, _offset: usize) -> Result<(), DvlError> {
+                Ok(())
+            }
+        }
+
+        impl DevolWrite for $type {
+            fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+                writer.$type(offset, *self)
+            }
+        } )*
+    };
+}
+
+le_parse!(u16, i16, u32, i32, u64, i64, f64);
+
+impl DevolParse for bool {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        parser.bool(offset)
+    }
+}
+
+impl DevolWrite for bool {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.bool(offset, *self)
+    }
+}
+
+impl DevolParse for Pubkey {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        parser.pubkey(offset)
+    }
+
+    fn check_at(_parser: &LeParser, _offset: usize) -> Result<(), DvlError> {
+        Ok(())
+    }
+}
+
+/// `[u8; N]` fields hold integers accessed with `from_ne_bytes`, raw bytes are parsed with an
+/// explicit `#[parse = LeParser::bytes]` in the field table.
+impl<const N: usize> DevolParse for [u8; N] {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        parser.ne_bytes(offset)
+    }
+
+    fn check_at(_parser: &LeParser, _offset: usize) -> Result<(), DvlError> {
+        Ok(())
+    }
+}
+
+impl DevolWrite for Pubkey {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.pubkey(offset, *self)
+    }
+}
+
+impl<const N: usize> DevolWrite for [u8; N] {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.ne_bytes(offset, *self)
+    }
+}
+
+impl<T: DevolParse, const N: usize> DevolParse for [T; N] {
+    unsafe fn parse_into(parser: &LeParser, offset: usize, place: *mut Self) -> Result<(), DvlError> {
+        let place = place as *mut T;
+        for index in 0..N {
+            T::parse_into(parser, offset + index * size_of::<T>(), place.add(index))?;
+        }
+        Ok(())
+    }
+
+    fn check_at(parser: &LeParser, offset: usize) -> Result<(), DvlError> {
+        for index in 0..N {
+            T::check_at(parser, offset + index * size_of::<T>())?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: DevolWrite, const N: usize> DevolWrite for [T; N] {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        for (index, value) in self.iter().enumerate() {
+            value.write_at(writer, offset + index * size_of::<T>())?;
+        }
+        Ok(())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+    use crate::accounts::client::client_account::client_account::ClientAccount;
+    use crate::accounts::client::client_account::kyc_status::KYCStatus;
+    use crate::accounts::client::payoff_log::payoff_log_account::PayoffLogAccount;
+    use crate::accounts::client::trade_log::trade_log_account::TradeLogAccount;
+    use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+    use crate::accounts::mints::mint_log::mint_log_account::MintLogAccount;
+    use crate::accounts::mints::mints_account::MintsAccount;
+    use crate::accounts::oracles::oracles_account::OraclesAccount;
+    use crate::accounts::root::root_account::RootAccount;
+    use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
+    use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
+    use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+    use crate::accounts::worker::tasks_log::tasks_log_account::TasksLogAccount;
+    use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
+    use crate::accounts::worker::worker_account::{WORKER_ACCOUNT_STATE_OFFSET, WorkerAccount};
+    use crate::accounts::worker::worker_state::WorkerState;
+    use crate::fixtures::devol_account_builder::{DevolAccountBuilder, DevolAccountFixture};
+
+    const HEADER_SIZE: usize = 40;
+
+    /// Parses `data` and checks that the `repr(C)` bytes of the result are the same, and that
+    /// writing the result back gives the same data.
+    fn check_parse<T: DevolAccountFixture + DevolParse + DevolWrite>(data: &[u8]) {
+        let account = T::parse(data).unwrap();
+        let bytes = unsafe { std::slice::from_raw_parts(&*account as *const T as *const u8, T::expected_size()) };
+        assert_eq!(bytes, &data[..T::expected_size()]);
+        let mut written = vec![0u8; T::expected_size()];
+        let mut writer = LeWriter::new(AccountTag::from_u8(T::expected_tag()), &mut written, T::expected_size()).unwrap();
+        writer.write(0, &*account).unwrap();
+        assert_eq!(written, &data[..T::expected_size()]);
+    }
+
+    /// Fills the data after the header with pseudo-random bytes.
+    fn fill_random(data: &mut [u8]) {
+        let mut state = 0x2545_f491_4f6c_dd1du64;
+        for byte in data[HEADER_SIZE..].iter_mut() {
+            state ^= state << 13;
+            state ^= state >> 7;
+            state ^= state << 17;
+            *byte = state as u8;
+        }
+    }
+
+    fn check_parse_random<T: DevolAccountFixture + DevolParse + DevolWrite>() {
+        let mut fixture = DevolAccountBuilder::<T>::new().build();
+        fill_random(fixture.data_mut());
+        check_parse::<T>(fixture.data());
+    }
+
+    #[test]
+    fn test_parse_random_data() {
+        check_parse_random::<MintsAccount>();
+        check_parse_random::<InstrumentsAccount>();
+        check_parse_random::<AllWorkersAccount>();
+        check_parse_random::<PoolsTraceAccount>();
+        check_parse_random::<TasksTraceAccount>();
+        check_parse_random::<PayoffLogAccount>();
+        check_parse_random::<MintLogAccount>();
+        check_parse_random::<TasksLogAccount>();
+        check_parse_random::<TradeLogAccount>();
+    }
+
+    #[test]
+    fn test_parse_modified_accounts() {
+        check_parse::<RootAccount>(DevolAccountBuilder::<RootAccount>::new()
+            .with(|account| {
+                account.clients_count = 17;
+                account.light_trader_daily_trade_limit = 1_000_000;
+            })
+            .data());
+        check_parse::<WorkerAccount>(DevolAccountBuilder::<WorkerAccount>::new()
+            .with(|account| {
+                account.id = 3;
+                account.state = WorkerState::Active;
+                account.ops_counter = -5;
+                account.pool_counter = u64::MAX;
+            })
+            .data());
+        check_parse::<ClientAccount>(DevolAccountBuilder::<ClientAccount>::new()
+            .with(|account| {
+                account.id = 11;
+                account.kyc_status = KYCStatus::PowerLp;
+                account.lp_count = 2;
+            })
+            .with_pools(1)
+            .data());
+        check_parse::<OraclesAccount>(DevolAccountBuilder::<OraclesAccount>::new()
+            .with(|account| account.count = 4)
+            .data());
+    }
+
+    #[test]
+    fn test_parse_large_accounts() {
+        check_parse::<PoolLogsAccount>(DevolAccountBuilder::<PoolLogsAccount>::new().data());
+        check_parse::<PoolLogsAccountV8>(DevolAccountBuilder::<PoolLogsAccountV8>::new().data());
+    }
+
+    #[test]
+    fn test_parse_invalid_enum() {
+        let mut fixture = DevolAccountBuilder::<WorkerAccount>::new().build();
+        fixture.data_mut()[WORKER_ACCOUNT_STATE_OFFSET..WORKER_ACCOUNT_STATE_OFFSET + 4].copy_from_slice(&7u32.to_le_bytes());
+        let error = WorkerAccount::parse(fixture.data()).err().unwrap();
+        let expected = DvlError::new_with_account(AccountTag::Worker, ContractError::AccountFieldValue);
+        assert_eq!(error.encode(), expected.encode());
+    }
+
+    #[test]
+    fn test_parse_checks() {
+        let fixture = DevolAccountBuilder::<MintsAccount>::new().build();
+        let data = fixture.data();
+        assert!(MintsAccount::parse(&data[..data.len() - 1]).is_err());
+        assert!(WorkerAccount::parse(data).is_err());
+        let fixture = DevolAccountBuilder::<MintsAccount>::new().version(MintsAccount::expected_version() - 1).build();
+        assert!(MintsAccount::parse(fixture.data()).is_err());
+    }
+
+    #[test]
+    fn test_parse_misaligned_data() {
+        let mut fixture = DevolAccountBuilder::<MintsAccount>::new().build();
+        fill_random(fixture.data_mut());
+        let mut data = vec![0u8; 1];
+        data.extend_from_slice(fixture.data());
+        let account = MintsAccount::parse(&data[1..]).unwrap();
+        assert_eq!(account.count, MintsAccount::view(fixture.data()).unwrap().read_boxed().unwrap().count);
+    }
+}
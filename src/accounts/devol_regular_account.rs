@@ -1,3 +1,4 @@
+use std::cell::{Ref, RefMut};
 use std::error::Error;
 use solana_program::account_info::{Account, AccountInfo, IntoAccountInfo};
 use solana_program::pubkey::Pubkey;
@@ -16,7 +17,7 @@ pub trait DevolRegularAccount: DevolAccount {
         account_info: &'a AccountInfo,
         root_addr: &Pubkey,
         program_id: &Pubkey,
-    ) -> Result<&'a Self, DvlError>
+    ) -> Result<Ref<'a, Self>, DvlError>
         where
             Self: Sized,
     {
@@ -30,7 +31,7 @@ pub trait DevolRegularAccount: DevolAccount {
         account_info: &'a AccountInfo,
         root_addr: &Pubkey,
         program_id: &Pubkey,
-    ) -> Result<&'a mut Self, DvlError>
+    ) -> Result<RefMut<'a, Self>, DvlError>
         where
             Self: Sized,
     {
@@ -38,6 +39,7 @@ pub trait DevolRegularAccount: DevolAccount {
     }
 
     /// Used off-chain to convert raw account data from RPC to a blockchain-utilized account structure.
+    /// The account is copied out, so RPC data does not have to be aligned, and legacy layouts are upgraded.
     #[inline(always)]
     fn from_account(
         key: &Pubkey,
@@ -46,10 +48,11 @@ pub trait DevolRegularAccount: DevolAccount {
         program_id: &Pubkey,
     ) -> Result<Box<Self>, Box<dyn Error>>
         where
-            Self: Sized + Copy
+            Self: Sized + Copy + 'static
     {
         let account_info = (key, account).into_account_info();
-        let account_ref = Self::from_account_info(&account_info, root_addr, program_id)?;
-        Ok(Box::new(*account_ref))
+        Self::check_versioned_basic(&account_info, root_addr, program_id)?;
+        let account = Self::from_versioned_data(&account_info.data.borrow())?;
+        Ok(account)
     }
 }
\ No newline at end of file
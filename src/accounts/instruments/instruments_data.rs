@@ -1,16 +1,5 @@
 use solana_program::pubkey::Pubkey;
-
-pub const INSTR_SPOT_ADDRESS_OFFSET: usize = 0;
-pub const INSTR_MINT_ID_OFFSET: usize = 32;
-pub const INSTR_PX_DECIMALS_OFFSET: usize = 36;
-pub const INSTR_STRIKE_DECIMALS_OFFSET: usize = 40;
-pub const INSTR_ASSET_TICKER_OFFSET: usize = 44;
-pub const INSTR_ASSIGNED_ORACLE_NUM_OFFSET: usize = 52;
-pub const INSTR_RESERVED_OFFSET: usize = 56;
-pub const INSTR_ORACLE_TIME_OFFSET: usize = 60;
-pub const INSTR_WORKERS_COUNT_OFFSET: usize = 64;
-pub const INSTR_WORKERS_DATA_OFFSET: usize = 68;
-pub const INSTR_SIZE: usize = 196;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Copy, Clone)]
@@ -27,45 +16,19 @@ pub struct InstrumentsData {
     pub workers_data: [u8; 128],             //128 bytes, INSTR_WORKERS_DATA_OFFSET
 }
 
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_instruments_account_offsets() {
-        assert_eq!(mem::size_of::<u64>(), 8);
-        assert_eq!(mem::size_of::<u32>(), 4);
-        assert_eq!(mem::size_of::<u8>(), 1);
-        assert_eq!(mem::size_of::<Pubkey>(), 32);
-
-        let account = InstrumentsData {
-            spot_address: Pubkey::from([1; 32]),
-            mint_id: 0,
-            px_decimals: 0,
-            strike_decimals: 0,
-            asset_ticker: [0; 8],
-            assigned_oracle_num: 0,
-            reserved: 0,
-            oracle_time: 0,
-            workers_count: 0,
-            workers_data: [0; 128],
-        };
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size and offset
-        assert_eq!(&account.spot_address as *const _ as usize - base_ptr, INSTR_SPOT_ADDRESS_OFFSET);
-        assert_eq!(&account.mint_id as *const _ as usize - base_ptr, INSTR_MINT_ID_OFFSET);
-        assert_eq!(&account.px_decimals as *const _ as usize - base_ptr, INSTR_PX_DECIMALS_OFFSET);
-        assert_eq!(&account.strike_decimals as *const _ as usize - base_ptr, INSTR_STRIKE_DECIMALS_OFFSET);
-        assert_eq!(&account.asset_ticker as *const _ as usize - base_ptr, INSTR_ASSET_TICKER_OFFSET);
-        assert_eq!(&account.assigned_oracle_num as *const _ as usize - base_ptr, INSTR_ASSIGNED_ORACLE_NUM_OFFSET);
-        assert_eq!(&account.reserved as *const _ as usize - base_ptr, INSTR_RESERVED_OFFSET);
-        assert_eq!(&account.oracle_time as *const _ as usize - base_ptr, INSTR_ORACLE_TIME_OFFSET);
-        assert_eq!(&account.workers_count as *const _ as usize - base_ptr, INSTR_WORKERS_COUNT_OFFSET);
-        assert_eq!(&account.workers_data as *const _ as usize - base_ptr, INSTR_WORKERS_DATA_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<InstrumentsData>(), INSTR_SIZE);
-    }
+devol_layout! {
+    InstrumentsData, INSTR_SIZE = 196, test_instruments_data_layout;
+    spot_address: Pubkey => INSTR_SPOT_ADDRESS_OFFSET = 0,
+    mint_id: u32 => INSTR_MINT_ID_OFFSET = 32,
+    px_decimals: u32 => INSTR_PX_DECIMALS_OFFSET = 36,
+    strike_decimals: u32 => INSTR_STRIKE_DECIMALS_OFFSET = 40,
+    #[parse = LeParser::bytes]
+    asset_ticker: [u8; 8] => INSTR_ASSET_TICKER_OFFSET = 44,
+    assigned_oracle_num: i32 => INSTR_ASSIGNED_ORACLE_NUM_OFFSET = 52,
+    reserved: u32 => INSTR_RESERVED_OFFSET = 56,
+    oracle_time: i32 => INSTR_ORACLE_TIME_OFFSET = 60,
+    workers_count: u32 => INSTR_WORKERS_COUNT_OFFSET = 64,
+    #[parse = LeParser::bytes]
+    workers_data: [u8; 128] => INSTR_WORKERS_DATA_OFFSET = 68,
 }
+
@@ -1,13 +1,5 @@
 use solana_program::pubkey::Pubkey;
-
-pub const MINT_ADDRESS_OFFSET: usize = 0;
-pub const MINT_PROGRAM_ADDRESS_OFFSET: usize = 32;
-pub const MINT_LOG_ADDRESS_OFFSET: usize = 64;
-pub const MINT_OPS_COUNTER_OFFSET: usize = 96;
-pub const MINT_TICKER_OFFSET: usize = 104;
-pub const MINT_OWN_OFFSET: usize = 112;
-pub const MINT_CLIENT_OFFSET: usize = 120;
-pub const MINT_SIZE: usize = 128;
+use crate::accounts::devol_layout::devol_layout;
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -28,6 +20,18 @@ pub struct Mint {
     pub client: [u8; 8],
 }
 
+devol_layout! {
+    Mint, MINT_SIZE = 128, test_mint_layout;
+    address: Pubkey => MINT_ADDRESS_OFFSET = 0,
+    program_address: Pubkey => MINT_PROGRAM_ADDRESS_OFFSET = 32,
+    log_address: Pubkey => MINT_LOG_ADDRESS_OFFSET = 64,
+    ops_counter: [u8; 8] => MINT_OPS_COUNTER_OFFSET = 96,
+    #[parse = LeParser::bytes]
+    ticker: [u8; 8] => MINT_TICKER_OFFSET = 104,
+    own: [u8; 8] => MINT_OWN_OFFSET = 112,
+    client: [u8; 8] => MINT_CLIENT_OFFSET = 120,
+}
+
 impl Mint {
     #[inline(always)]
     pub fn get_ops_counter(&self) -> i64 { i64::from_ne_bytes(self.ops_counter) }
@@ -62,28 +66,3 @@ impl Default for Mint {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_root_account_offsets() {
-        let account = Mint::default();
-
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(&account.address as *const _ as usize - base_ptr, MINT_ADDRESS_OFFSET);
-        assert_eq!(&account.program_address as *const _ as usize - base_ptr, MINT_PROGRAM_ADDRESS_OFFSET);
-        assert_eq!(&account.log_address as *const _ as usize - base_ptr, MINT_LOG_ADDRESS_OFFSET);
-        assert_eq!(&account.ops_counter as *const _ as usize - base_ptr, MINT_OPS_COUNTER_OFFSET);
-        assert_eq!(&account.ticker as *const _ as usize - base_ptr, MINT_TICKER_OFFSET);
-        assert_eq!(&account.own as *const _ as usize - base_ptr, MINT_OWN_OFFSET);
-        assert_eq!(&account.client as *const _ as usize - base_ptr, MINT_CLIENT_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<Mint>(), MINT_SIZE);
-    }
-}
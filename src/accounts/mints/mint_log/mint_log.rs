@@ -1,13 +1,5 @@
 use solana_program::pubkey::Pubkey;
-
-pub const MINT_LOG_ID_OFFSET: usize = 0;
-pub const MINT_LOG_TIME_OFFSET: usize = 8;
-pub const MINT_LOG_EVENT_TYPE_OFFSET: usize = 16;
-pub const MINT_LOG_PUBKEY_OFFSET: usize = 20;
-pub const MINT_LOG_OWN_OFFSET: usize = 52;
-pub const MINT_LOG_CLIENT_OFFSET: usize = 60;
-pub const MINT_LOG_SUM_OFFSET: usize = 68;
-pub const MINT_LOG_SIZE: usize = 76;
+use crate::accounts::devol_layout::devol_layout;
 
 #[derive(Clone, Copy)]
 #[repr(C)]
@@ -21,6 +13,17 @@ pub struct MintLog {
     pub sum: [u8; 8],
 }
 
+devol_layout! {
+    MintLog, MINT_LOG_SIZE = 76, test_mint_log_layout;
+    id: [u8; 8] => MINT_LOG_ID_OFFSET = 0,
+    time: [u8; 8] => MINT_LOG_TIME_OFFSET = 8,
+    event_type: u32 => MINT_LOG_EVENT_TYPE_OFFSET = 16,
+    pubkey: Pubkey => MINT_LOG_PUBKEY_OFFSET = 20,
+    own: [u8; 8] => MINT_LOG_OWN_OFFSET = 52,
+    client: [u8; 8] => MINT_LOG_CLIENT_OFFSET = 60,
+    sum: [u8; 8] => MINT_LOG_SUM_OFFSET = 68,
+}
+
 impl MintLog {
     #[inline(always)]
     pub fn get_id(&self) -> i64 { i64::from_ne_bytes(self.id) }
@@ -57,29 +60,4 @@ impl Default for MintLog {
             sum: [0; 8],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_root_account_offsets() {
-        let account = MintLog::default();
-
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(&account.id as *const _ as usize - base_ptr, MINT_LOG_ID_OFFSET);
-        assert_eq!(&account.time as *const _ as usize - base_ptr, MINT_LOG_TIME_OFFSET);
-        assert_eq!(&account.event_type as *const _ as usize - base_ptr, MINT_LOG_EVENT_TYPE_OFFSET);
-        assert_eq!(&account.pubkey as *const _ as usize - base_ptr, MINT_LOG_PUBKEY_OFFSET);
-        assert_eq!(&account.own as *const _ as usize - base_ptr, MINT_LOG_OWN_OFFSET);
-        assert_eq!(&account.client as *const _ as usize - base_ptr, MINT_LOG_CLIENT_OFFSET);
-        assert_eq!(&account.sum as *const _ as usize - base_ptr, MINT_LOG_SUM_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<MintLog>(), MINT_LOG_SIZE);
-    }
 }
\ No newline at end of file
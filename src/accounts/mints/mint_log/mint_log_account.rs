@@ -3,15 +3,10 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 use crate::accounts::mints::mint_log::mint_log::MintLog;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::ring_log::RingLog;
 
-pub const MINT_LOG_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const MINT_LOG_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const MINT_LOG_ACCOUNT_MINTS_ADDRESS_OFFSET: usize = 40;
-pub const MINT_LOG_ACCOUNT_MINT_ID_OFFSET: usize = 72;
-pub const MINT_LOG_ACCOUNT_LAST_OFFSET: usize = 76;
-pub const MINT_LOG_ACCOUNT_COUNT_OFFSET: usize = 80;
-pub const MINT_LOG_ACCOUNT_DATA_OFFSET: usize = 84;
-pub const MINT_LOG_ACCOUNT_SIZE: usize = 19540;
 pub const MINT_LOG_ACCOUNT_TAG: u8 = 10;
 pub const MINT_LOG_ACCOUNT_VERSION: u32 = 12;
 pub const MINT_LOG_BUFFER_CAPACITY: usize = 256;
@@ -28,11 +23,44 @@ pub struct MintLogAccount {
     pub data: [MintLog; MINT_LOG_BUFFER_CAPACITY],
 }
 
+devol_layout! {
+    MintLogAccount, MINT_LOG_ACCOUNT_SIZE = 19540, test_mint_log_account_layout;
+    header: AccountHeader => MINT_LOG_ACCOUNT_VERSION_OFFSET = 0,
+    mints_address: Pubkey => MINT_LOG_ACCOUNT_MINTS_ADDRESS_OFFSET = 40,
+    mint_id: u32 => MINT_LOG_ACCOUNT_MINT_ID_OFFSET = 72,
+    last: u32 => MINT_LOG_ACCOUNT_LAST_OFFSET = 76,
+    count: u32 => MINT_LOG_ACCOUNT_COUNT_OFFSET = 80,
+    data: [MintLog; MINT_LOG_BUFFER_CAPACITY] => MINT_LOG_ACCOUNT_DATA_OFFSET = 84,
+}
+
 impl DevolIndexedAccount for MintLogAccount {
     fn id_offset() -> usize {
         MINT_LOG_ACCOUNT_MINT_ID_OFFSET
     }
 }
+
+impl RingLog for MintLogAccount {
+    type Record = MintLog;
+
+    const CAPACITY: usize = MINT_LOG_BUFFER_CAPACITY;
+
+    fn buffer(&self) -> &[MintLog] {
+        &self.data
+    }
+
+    fn written_count(&self) -> u64 {
+        self.count as u64
+    }
+
+    fn newest_index(&self) -> Option<usize> {
+        (self.count > 0).then_some(self.last as usize % MINT_LOG_BUFFER_CAPACITY)
+    }
+
+    fn record_id(record: &MintLog) -> i64 {
+        record.get_id()
+    }
+}
+
 impl DevolAccount for MintLogAccount {
 
     #[inline(always)]
@@ -57,34 +85,3 @@ impl Default for MintLogAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-    use crate::accounts::mints::mint_log::mint_log::MINT_LOG_SIZE;
-
-    #[test]
-    fn test_root_account_offsets() {
-        let account = MintLogAccount::default();
-
-        for i in 0..MINT_LOG_BUFFER_CAPACITY {
-            let mint = &account.data[i];
-            let mint_ptr = mint as *const _;
-            let expected_ptr = account.data.as_ptr() as usize + i * MINT_LOG_SIZE;
-            assert_eq!(mint_ptr as usize, expected_ptr, "Mint offset is incorrect for index {}", i);
-        }
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size and offset
-        assert_eq!(&account.header as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.mints_address as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_MINTS_ADDRESS_OFFSET);
-        assert_eq!(&account.mint_id as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_MINT_ID_OFFSET);
-        assert_eq!(&account.last as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_LAST_OFFSET);
-        assert_eq!(&account.count as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_COUNT_OFFSET);
-        assert_eq!(&account.data as *const _ as usize - base_ptr, MINT_LOG_ACCOUNT_DATA_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<MintLogAccount>(), MINT_LOG_ACCOUNT_SIZE);
-    }
-}
@@ -2,12 +2,9 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_regular_account::DevolRegularAccount;
 use crate::accounts::mints::mint::Mint;
+use crate::accounts::devol_layout::devol_layout;
 
-pub const MINTS_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const MINTS_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const MINTS_ACCOUNT_COUNT_OFFSET: usize = 40;
-pub const MINTS_ACCOUNT_DATA_OFFSET: usize = 44;
-pub const MINTS_ACCOUNT_SIZE: usize = 4140;
 pub const MINTS_ACCOUNT_TAG: u8 = 1;
 pub const MINTS_ACCOUNT_VERSION: u32 = 3;
 pub const MAX_MINTS_COUNT: usize = 32;
@@ -20,6 +17,13 @@ pub struct MintsAccount {
     pub data: [Mint; MAX_MINTS_COUNT],
 }
 
+devol_layout! {
+    MintsAccount, MINTS_ACCOUNT_SIZE = 4140, test_mints_account_layout;
+    header: AccountHeader => MINTS_ACCOUNT_VERSION_OFFSET = 0,
+    count: u32 => MINTS_ACCOUNT_COUNT_OFFSET = 40,
+    data: [Mint; MAX_MINTS_COUNT] => MINTS_ACCOUNT_DATA_OFFSET = 44,
+}
+
 impl DevolRegularAccount for MintsAccount {}
 impl DevolAccount for MintsAccount {
     #[inline(always)]
@@ -42,31 +46,3 @@ impl Default for MintsAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-    use crate::accounts::mints::mint::{MINT_SIZE};
-
-    #[test]
-    fn test_root_account_offsets() {
-        let account = MintsAccount::default();
-
-        for i in 0..MAX_MINTS_COUNT {
-            let mint = &account.data[i];
-            let mint_ptr = mint as *const _;
-            let expected_ptr = account.data.as_ptr() as usize + i * MINT_SIZE;
-            assert_eq!(mint_ptr as usize, expected_ptr, "Mint offset is incorrect for index {}", i);
-        }
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size and offset
-        assert_eq!(&account.header as *const _ as usize - base_ptr, MINTS_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.count as *const _ as usize - base_ptr, MINTS_ACCOUNT_COUNT_OFFSET);
-        assert_eq!(&account.data as *const _ as usize - base_ptr, MINTS_ACCOUNT_DATA_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<MintsAccount>(), MINTS_ACCOUNT_SIZE);
-    }
-}
@@ -4,9 +4,15 @@ pub mod instruments;
 pub mod oracles;
 pub mod all_workers;
 pub mod account_header;
+pub mod account_view;
+pub mod devol_any_account;
 pub mod mints;
 pub mod client;
 pub mod devol_account;
 pub mod devol_indexed_account;
+pub mod devol_layout;
+pub mod devol_legacy_account;
+pub mod devol_parse;
 pub mod devol_regular_account;
 pub mod devol_expandable_size_account;
+pub mod ring_log;
@@ -1,6 +1,9 @@
 use serde::{Deserialize, Serialize};
 use solana_program::pubkey::Pubkey;
 use crate::accounts::oracles::oracle_provider::OracleProvider;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
 #[derive(PartialEq, PartialOrd, Copy, Clone, Serialize, Deserialize)]
 #[repr(u8)]
@@ -12,6 +15,25 @@ pub enum DataLen {
     U128,  // 128-bit unsigned integer
 } // size: 1 byte
 
+impl DevolParse for DataLen {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u8(offset)? {
+            0 => Ok(DataLen::Undefined),
+            1 => Ok(DataLen::U8),
+            2 => Ok(DataLen::U32),
+            3 => Ok(DataLen::U64),
+            4 => Ok(DataLen::U128),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for DataLen {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u8(offset, *self as u8)
+    }
+}
+
 
 #[derive(PartialEq, PartialOrd, Copy, Clone, Serialize, Deserialize)]
 #[repr(u8)]
@@ -20,6 +42,22 @@ pub enum Endian {
     BE,
 } // size: 1 byte
 
+impl DevolParse for Endian {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u8(offset)? {
+            0 => Ok(Endian::LE),
+            1 => Ok(Endian::BE),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for Endian {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u8(offset, *self as u8)
+    }
+}
+
 #[derive(Copy, Clone, Serialize, Deserialize)]
 #[repr(C)]
 pub struct OracleDataField{
@@ -29,6 +67,26 @@ pub struct OracleDataField{
     pub offset: i32,            // Offset from the start of the data storage
 } // size: 8 byte
 
+impl DevolParse for OracleDataField {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        Ok(Self {
+            data_len: parser.parse(offset)?,
+            endian: parser.parse(offset + 1)?,
+            reserved: parser.bytes(offset + 2)?,
+            offset: parser.i32(offset + 4)?,
+        })
+    }
+}
+
+impl DevolWrite for OracleDataField {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.write(offset, &self.data_len)?;
+        writer.write(offset + 1, &self.endian)?;
+        writer.bytes(offset + 2, self.reserved)?;
+        writer.i32(offset + 4, self.offset)
+    }
+}
+
 #[cfg(test)]
 impl Default for OracleDataField {
     fn default() -> Self {
@@ -42,7 +100,6 @@ impl Default for OracleDataField {
 }
 
 pub const ORACLE_DATA_FIELDS_QUANTITY: usize = 3;
-pub const ORACLE_PARAMS_SIZE: usize = 64;
 
 #[derive(Copy, Clone, Serialize, Deserialize)]
 #[repr(C)]
@@ -58,6 +115,21 @@ pub struct OracleParams {
     pub timestamp: OracleDataField,
 } // size: ORACLE_PARAMS_SIZE bytes
 
+devol_layout! {
+    OracleParams, ORACLE_PARAMS_SIZE = 64, test_oracle_params_layout;
+    #[parse = LeParser::u8]
+    version: u8 = 0,
+    enabled: bool = 1,
+    provider: OracleProvider = 2,
+    #[parse = LeParser::bytes]
+    reserved: [u8; 1] = 3,
+    max_timestamp_diff_sec: i32 = 4,
+    account: Pubkey = 8,
+    mantissa: OracleDataField = 40,
+    exponent: OracleDataField = 48,
+    timestamp: OracleDataField = 56,
+}
+
 #[cfg(test)]
 impl Default for OracleParams {
     fn default() -> Self {
@@ -74,14 +146,3 @@ impl Default for OracleParams {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use std::mem;
-    use crate::accounts::oracles::oracle_params::{ORACLE_PARAMS_SIZE, OracleParams};
-
-    #[test]
-    fn test_oracle_params() {
-        assert_eq!(mem::size_of::<OracleParams>(), ORACLE_PARAMS_SIZE, "Total size of OracleParams structure mismatch");
-    }
-}
\ No newline at end of file
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
 #[derive(Clone, Copy, PartialEq, Serialize, Deserialize)]
 #[repr(u8)]
@@ -6,4 +8,21 @@ pub enum OracleProvider {
     Custom = 0,
     Switchboard = 1,
     Pyth = 2,
-}
\ No newline at end of file
+}
+
+impl DevolParse for OracleProvider {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u8(offset)? {
+            0 => Ok(OracleProvider::Custom),
+            1 => Ok(OracleProvider::Switchboard),
+            2 => Ok(OracleProvider::Pyth),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for OracleProvider {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u8(offset, *self as u8)
+    }
+}
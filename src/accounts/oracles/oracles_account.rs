@@ -2,12 +2,8 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_regular_account::DevolRegularAccount;
 use crate::accounts::oracles::oracles_data::{OracleData, ORACLES_DATA_COUNT};
+use crate::accounts::devol_layout::devol_layout;
 
-pub const ORACLES_ACCOUNT_HEADER_OFFSET: usize = 0;
-pub const ORACLES_ACCOUNT_COUNT_OFFSET: usize = 40;
-pub const ORACLES_ACCOUNT_RESERVED_OFFSET: usize = 44;
-pub const ORACLES_ACCOUNT_DATA_OFFSET: usize = 48;
-pub const ORACLES_ACCOUNT_SIZE: usize = 1776;
 pub const ORACLES_ACCOUNT_TAG: u8 = 13;
 pub const ORACLES_ACCOUNT_VERSION: u32 = 1;
 
@@ -20,6 +16,14 @@ pub struct OraclesAccount {
     pub data: [OracleData; ORACLES_DATA_COUNT],     // ORACLES_ACCOUNT_DATA_OFFSET
 }
 
+devol_layout! {
+    OraclesAccount, ORACLES_ACCOUNT_SIZE = 1776, test_oracles_account_layout;
+    header: AccountHeader => ORACLES_ACCOUNT_HEADER_OFFSET = 0,
+    count: u32 => ORACLES_ACCOUNT_COUNT_OFFSET = 40,
+    reserved: u32 => ORACLES_ACCOUNT_RESERVED_OFFSET = 44,
+    data: [OracleData; ORACLES_DATA_COUNT] => ORACLES_ACCOUNT_DATA_OFFSET = 48,
+}
+
 impl DevolRegularAccount for OraclesAccount {}
 impl DevolAccount for OraclesAccount {
     #[inline(always)]
@@ -35,32 +39,3 @@ impl DevolAccount for OraclesAccount {
         ORACLES_ACCOUNT_VERSION
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_oracles_account_offsets_and_size() {
-
-        let account = OraclesAccount {
-            header: AccountHeader::default(),
-            count: 0,
-            reserved: 0,
-            data: [OracleData::default(); ORACLES_DATA_COUNT],
-        };
-
-        let base_ptr = &account as *const _ as usize;
-
-        // Check offsets
-        // Assuming we have constants for offsets
-        assert_eq!(&account.header as *const _ as usize - base_ptr, ORACLES_ACCOUNT_HEADER_OFFSET, "Header offset mismatch");
-        assert_eq!(&account.count as *const _ as usize - base_ptr, ORACLES_ACCOUNT_COUNT_OFFSET, "Count offset mismatch");
-        assert_eq!(&account.reserved as *const _ as usize - base_ptr, ORACLES_ACCOUNT_RESERVED_OFFSET, "Reserved offset mismatch");
-        assert_eq!(&account.data as *const _ as usize - base_ptr, ORACLES_ACCOUNT_DATA_OFFSET, "Data array offset mismatch");
-
-        // Check total size of the OraclesAccount structure
-        assert_eq!(mem::size_of::<OraclesAccount>(), ORACLES_ACCOUNT_SIZE, "Total size of OraclesAccount structure mismatch");
-    }
-}
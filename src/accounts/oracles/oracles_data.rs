@@ -7,9 +7,9 @@ use crate::accounts::oracles::oracle_params::{DataLen, Endian, OracleParams};
 use crate::accounts::oracles::oracle_provider::OracleProvider;
 use crate::dvl_error::DvlError;
 use crate::errors::*;
+use crate::accounts::devol_layout::devol_layout;
 
 pub const ORACLE_PARAMS_QUANTITY: usize = 3;
-pub const ORACLES_DATA_SIZE: usize = 216;
 pub const ORACLES_DATA_COUNT: usize = 8;
 
 #[repr(C)]
@@ -25,6 +25,20 @@ pub struct OracleData {
     pub params: [OracleParams; ORACLE_PARAMS_QUANTITY], // Oracle parameters, 192 bytes, offset=24
 }
 
+devol_layout! {
+    OracleData, ORACLES_DATA_SIZE = 216, test_oracle_data_layout;
+    base_ticker: [u8; 8] = 0,
+    relative_ticker: [u8; 8] = 8,
+    configured: bool = 16,
+    use_relative_oracle: bool = 17,
+    #[parse = LeParser::u8]
+    relative_oracle_num: u8 = 18,
+    #[parse = LeParser::u8]
+    reserved: u8 = 19,
+    max_price_deviation: u32 = 20,
+    params: [OracleParams; ORACLE_PARAMS_QUANTITY] = 24,
+}
+
 impl OracleData {
     /// Calculates the asset price based on oracle data
     /// Returns Result<(f64, DvlError), ProgramError> where f64 is the average price
@@ -142,15 +156,3 @@ impl Default for OracleData {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_instruments_account_offsets() {
-        // checking total size
-        assert_eq!(mem::size_of::<OracleData>(), ORACLES_DATA_SIZE);
-    }
-}
@@ -0,0 +1,199 @@
+use std::iter::FusedIterator;
+
+/// Log account which stores its records in a fixed-capacity ring buffer and overwrites the
+/// oldest record once the buffer is full.
+pub trait RingLog {
+    type Record;
+
+    const CAPACITY: usize;
+
+    /// Whole buffer in storage order, including the records which were never written. The
+    /// account views keep the filled records, in the same order, as `records`.
+    fn buffer(&self) -> &[Self::Record];
+
+    /// Number of records written as stored by the program, the `count` field which stops growing
+    /// at the capacity. It exceeds `CAPACITY` for the logs which count every record once they
+    /// have overwritten the oldest ones.
+    fn written_count(&self) -> u64;
+
+    /// Storage index of the newest record, the `last` field unless the log derives it from the
+    /// count, `None` for an empty log.
+    fn newest_index(&self) -> Option<usize>;
+
+    /// Id of the record, growing with every record written to the log.
+    fn record_id(record: &Self::Record) -> i64;
+
+    fn capacity(&self) -> usize {
+        Self::CAPACITY
+    }
+
+    /// Number of the records held by the buffer.
+    fn len(&self) -> usize {
+        self.written_count().min(Self::CAPACITY as u64) as usize
+    }
+
+    fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    /// The next record overwrites the oldest one.
+    fn is_full(&self) -> bool {
+        self.len() == Self::CAPACITY
+    }
+
+    /// Number of the records which were overwritten and are no longer in the buffer.
+    fn overwritten_count(&self) -> u64 {
+        self.written_count().saturating_sub(Self::CAPACITY as u64)
+    }
+
+    /// Records from the oldest to the newest, handling the wrap-around.
+    fn iter_chronological(&self) -> RingLogIter<'_, Self::Record> {
+        let len = self.newest_index().map_or(0, |_| self.len());
+        let newest = self.newest_index().unwrap_or(0) % Self::CAPACITY;
+        let start = (newest + 1 + Self::CAPACITY - len) % Self::CAPACITY;
+        RingLogIter { buffer: &self.buffer()[..Self::CAPACITY], start, front: 0, back: len }
+    }
+
+    /// Newest `n` records, from the oldest to the newest.
+    fn latest(&self, n: usize) -> RingLogIter<'_, Self::Record> {
+        let mut iter = self.iter_chronological();
+        iter.front = iter.back.saturating_sub(n);
+        iter
+    }
+
+    /// Records written after the record `id`, from the oldest to the newest. All the records are
+    /// returned if `id` is older than the oldest record in the buffer.
+    fn since_id(&self, id: i64) -> RingLogIter<'_, Self::Record> {
+        let mut iter = self.iter_chronological();
+        let newer = iter.clone().rev().take_while(|record| Self::record_id(record) > id).count();
+        iter.front = iter.back - newer;
+        iter
+    }
+}
+
+/// Chronological iterator over the records of a `RingLog`.
+pub struct RingLogIter<'a, R> {
+    buffer: &'a [R],
+    start: usize,
+    front: usize,
+    back: usize,
+}
+
+impl<R> Clone for RingLogIter<'_, R> {
+    fn clone(&self) -> Self {
+        Self { ..*self }
+    }
+}
+
+impl<'a, R> Iterator for RingLogIter<'a, R> {
+    type Item = &'a R;
+
+    fn next(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        let record = &self.buffer[(self.start + self.front) % self.buffer.len()];
+        self.front += 1;
+        Some(record)
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        let len = self.back - self.front;
+        (len, Some(len))
+    }
+}
+
+impl<R> DoubleEndedIterator for RingLogIter<'_, R> {
+    fn next_back(&mut self) -> Option<Self::Item> {
+        if self.front == self.back {
+            return None;
+        }
+        self.back -= 1;
+        Some(&self.buffer[(self.start + self.back) % self.buffer.len()])
+    }
+}
+
+impl<R> ExactSizeIterator for RingLogIter<'_, R> {}
+
+impl<R> FusedIterator for RingLogIter<'_, R> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
+    use crate::accounts::worker::pool_logs::pool_logs_account::POOLS_LOG_BUFFER_CAPACITY;
+    use crate::accounts::worker::tasks_log::task_log::TasksLog;
+    use crate::accounts::worker::tasks_log::tasks_log_account::{TASKS_LOG_BUFFER_CAPACITY, TasksLogAccount};
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+    use crate::fixtures::pool_logs_account_builder::PoolLogsAccountBuilder;
+
+    /// Tasks log with `count` records written the way the program does, ids start from 1.
+    fn tasks_log(count: usize) -> Box<TasksLogAccount> {
+        DevolAccountBuilder::<TasksLogAccount>::new()
+            .with(|account| {
+                for id in 1..=count {
+                    let index = (id - 1) % TASKS_LOG_BUFFER_CAPACITY;
+                    let mut record = TasksLog::default();
+                    record.set_id(id as i64);
+                    account.data[index] = record;
+                    account.last = index as u32;
+                    account.count = id.min(TASKS_LOG_BUFFER_CAPACITY) as u32;
+                }
+            })
+            .account()
+    }
+
+    fn ids<'a>(iter: impl Iterator<Item = &'a TasksLog>) -> Vec<i64> {
+        iter.map(TasksLog::get_id).collect()
+    }
+
+    #[test]
+    fn test_ring_log_empty() {
+        let account = tasks_log(0);
+        assert!(account.is_empty());
+        assert_eq!(account.iter_chronological().count(), 0);
+        assert_eq!(account.latest(3).count(), 0);
+        assert_eq!(account.since_id(0).count(), 0);
+    }
+
+    #[test]
+    fn test_ring_log_partially_filled() {
+        let account = tasks_log(5);
+        assert_eq!(account.len(), 5);
+        assert!(!account.is_full());
+        assert_eq!(ids(account.iter_chronological()), vec![1, 2, 3, 4, 5]);
+        assert_eq!(ids(account.latest(2)), vec![4, 5]);
+        assert_eq!(ids(account.latest(10)), vec![1, 2, 3, 4, 5]);
+        assert_eq!(ids(account.since_id(3)), vec![4, 5]);
+        assert_eq!(ids(account.since_id(5)), Vec::<i64>::new());
+        assert_eq!(ids(account.iter_chronological().rev()), vec![5, 4, 3, 2, 1]);
+    }
+
+    #[test]
+    fn test_ring_log_wrapped() {
+        let count = TASKS_LOG_BUFFER_CAPACITY + 3;
+        let account = tasks_log(count);
+        assert!(account.is_full());
+        assert_eq!(account.capacity(), TASKS_LOG_BUFFER_CAPACITY);
+        let all = ids(account.iter_chronological());
+        assert_eq!(all.len(), TASKS_LOG_BUFFER_CAPACITY);
+        assert_eq!(all, (4..=count as i64).collect::<Vec<_>>());
+        assert_eq!(ids(account.latest(3)), vec![count as i64 - 2, count as i64 - 1, count as i64]);
+        assert_eq!(ids(account.since_id(count as i64 - 1)), vec![count as i64]);
+        assert_eq!(account.since_id(0).len(), TASKS_LOG_BUFFER_CAPACITY);
+    }
+
+    #[test]
+    fn test_ring_log_pool_logs_overflow() {
+        let mut builder = PoolLogsAccountBuilder::new();
+        for operation in 1..=POOLS_LOG_BUFFER_CAPACITY as u64 + 5 {
+            builder = builder.push_record(&PoolLogRecord { worker_operations_count: operation, ..PoolLogRecord::default() });
+        }
+        let account = builder.account();
+        assert!(account.is_full());
+        assert_eq!(account.overwritten_count(), 5);
+        let operations: Vec<u64> = account.iter_chronological().map(|record| record.worker_operations_count).collect();
+        assert_eq!(operations, (6..=POOLS_LOG_BUFFER_CAPACITY as u64 + 5).collect::<Vec<_>>());
+        assert_eq!(account.since_id(POOLS_LOG_BUFFER_CAPACITY as i64 + 3).len(), 2);
+    }
+}
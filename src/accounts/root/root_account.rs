@@ -5,28 +5,36 @@ use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_regular_account::DevolRegularAccount;
 use crate::dvl_error::DvlError;
 use crate::errors::AccountTag;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
 
-pub const ROOT_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const ROOT_ACCOUNT_ADMIN_ADDRESS_OFFSET: usize = 8;
-pub const ROOT_ACCOUNT_WALLET_ADDRESS_OFFSET: usize = 40;
-pub const ROOT_ACCOUNT_KYC_PROVIDER_OFFSET: usize = 72;
-pub const ROOT_ACCOUNT_MINTS_ADDRESS_OFFSET: usize = 104;
-pub const ROOT_ACCOUNT_INSTRUMENTS_ADDRESS_OFFSET: usize = 136;
-pub const ROOT_ACCOUNT_WORKERS_ADDRESS_OFFSET: usize = 168;
-pub const ROOT_ACCOUNT_CLIENTS_COUNT_OFFSET: usize = 200;
-pub const ROOT_ACCOUNT_KYC_METHOD_OFFSET: usize = 204;
-pub const ROOT_ACCOUNT_MAX_LIGHT_VOLUME_OFFSET: usize = 208;
-pub const ROOT_ACCOUNT_SIZE: usize = 216;
 pub const ROOT_ACCOUNT_TAG: u8 = 0;
 pub const ROOT_ACCOUNT_VERSION: u32 = 2;
 
-#[derive(PartialEq, PartialOrd, Clone, Copy, Serialize, Deserialize)]
+#[derive(PartialEq, PartialOrd, Clone, Copy, Debug, Serialize, Deserialize)]
 #[repr(u32)]
 pub enum OpenAccountFeePayer {
     Client = 0,
     Devol = 1,
 }
 
+impl DevolParse for OpenAccountFeePayer {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u32(offset)? {
+            0 => Ok(OpenAccountFeePayer::Client),
+            1 => Ok(OpenAccountFeePayer::Devol),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for OpenAccountFeePayer {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u32(offset, *self as u32)
+    }
+}
+
 #[derive(Clone, Copy)]
 #[repr(C)]
 pub struct RootAccount {
@@ -41,6 +49,19 @@ pub struct RootAccount {
     pub light_trader_daily_trade_limit: u64,             //  8 bytes, ROOT_ACCOUNT_MAX_LIGHT_VOLUME_OFFSET
 }
 
+devol_layout! {
+    RootAccount, ROOT_ACCOUNT_SIZE = 216, test_root_account_layout;
+    header: AccountHeader => ROOT_ACCOUNT_VERSION_OFFSET = 0,
+    wallet_address: Pubkey => ROOT_ACCOUNT_WALLET_ADDRESS_OFFSET = 40,
+    kyc_provider: Pubkey => ROOT_ACCOUNT_KYC_PROVIDER_OFFSET = 72,
+    mints_address: Pubkey => ROOT_ACCOUNT_MINTS_ADDRESS_OFFSET = 104,
+    instruments_address: Pubkey => ROOT_ACCOUNT_INSTRUMENTS_ADDRESS_OFFSET = 136,
+    workers_address: Pubkey => ROOT_ACCOUNT_WORKERS_ADDRESS_OFFSET = 168,
+    clients_count: u32 => ROOT_ACCOUNT_CLIENTS_COUNT_OFFSET = 200,
+    fee_payer: OpenAccountFeePayer => ROOT_ACCOUNT_KYC_METHOD_OFFSET = 204,
+    light_trader_daily_trade_limit: u64 => ROOT_ACCOUNT_MAX_LIGHT_VOLUME_OFFSET = 208,
+}
+
 impl DevolRegularAccount for RootAccount {}
 impl DevolAccount for RootAccount {
     #[inline(always)]
@@ -74,30 +95,3 @@ impl Default for RootAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    #[test]
-    fn test_root_account_offsets() {
-
-        let account = RootAccount::default();
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size and offset
-        assert_eq!(&account.header as *const _ as usize  - base_ptr, ROOT_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.wallet_address as *const _ as usize  - base_ptr, ROOT_ACCOUNT_WALLET_ADDRESS_OFFSET);
-        assert_eq!(&account.kyc_provider as *const _ as usize  - base_ptr, ROOT_ACCOUNT_KYC_PROVIDER_OFFSET);
-        assert_eq!(&account.mints_address as *const _ as usize  - base_ptr, ROOT_ACCOUNT_MINTS_ADDRESS_OFFSET);
-        assert_eq!(&account.instruments_address as *const _ as usize  - base_ptr, ROOT_ACCOUNT_INSTRUMENTS_ADDRESS_OFFSET);
-        assert_eq!(&account.workers_address as *const _ as usize  - base_ptr, ROOT_ACCOUNT_WORKERS_ADDRESS_OFFSET);
-        assert_eq!(&account.clients_count as *const _ as usize  - base_ptr, ROOT_ACCOUNT_CLIENTS_COUNT_OFFSET);
-        assert_eq!(&account.fee_payer as *const _ as usize  - base_ptr, ROOT_ACCOUNT_KYC_METHOD_OFFSET);
-        assert_eq!(&account.light_trader_daily_trade_limit as *const _ as usize  - base_ptr, ROOT_ACCOUNT_MAX_LIGHT_VOLUME_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<RootAccount>(), ROOT_ACCOUNT_SIZE);
-    }
-}
@@ -1,10 +1,32 @@
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
-#[derive(Clone, Copy, Serialize, Deserialize)]
+#[derive(Clone, Copy, PartialEq, Debug, Serialize, Deserialize)]
 #[repr(C)]
 pub struct Band {
     pub depo: i64,  // set to 0
     pub px: i64,    // will be setup on the start pool
     pub loan: i64,  // obsolete
     pub prop: i64,  // obsolete
+}
+
+impl DevolParse for Band {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        Ok(Self {
+            depo: parser.i64(offset)?,
+            px: parser.i64(offset + 8)?,
+            loan: parser.i64(offset + 16)?,
+            prop: parser.i64(offset + 24)?,
+        })
+    }
+}
+
+impl DevolWrite for Band {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.i64(offset, self.depo)?;
+        writer.i64(offset + 8, self.px)?;
+        writer.i64(offset + 16, self.loan)?;
+        writer.i64(offset + 24, self.prop)
+    }
 }
\ No newline at end of file
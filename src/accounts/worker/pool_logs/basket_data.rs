@@ -1,7 +1,6 @@
 use serde::{Deserialize, Serialize};
 use crate::utils::put_or_call::PutOrCall;
-
-pub const BASKET_DATA_SIZE: usize = 24;
+use crate::accounts::devol_layout::devol_layout;
 
 // Traded basket element. 64bit alignment. Size - 24 bytes.
 #[derive(Copy, Clone, PartialOrd, PartialEq, Debug, Serialize, Deserialize)]
@@ -14,6 +13,15 @@ pub struct PoolRecordBasketData {
     reserved: i16,              // 2 bytes (8/8 align)
 }
 
+devol_layout! {
+    PoolRecordBasketData, BASKET_DATA_SIZE = 24, test_pool_record_basket_data_layout;
+    strike: i64 = 0,
+    vanilla_cost: i64 = 8,
+    amount: i32 = 16,
+    put_or_call: PutOrCall = 20,
+    reserved: i16 = 22,
+}
+
 impl Default for PoolRecordBasketData {
     fn default() -> Self {
         Self {
@@ -24,17 +32,4 @@ impl Default for PoolRecordBasketData {
             reserved: 0,
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::utils::type_size_helper::align_size;
-    use super::*;
-
-    #[test]
-    fn test_pools_log_offsets() {
-        let real_size = std::mem::size_of::<PoolRecordBasketData>();
-        assert_eq!(real_size, BASKET_DATA_SIZE);
-        assert_eq!(real_size, align_size(real_size, 8));
-    }
 }
\ No newline at end of file
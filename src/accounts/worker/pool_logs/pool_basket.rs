@@ -1,7 +1,7 @@
 use serde::{Deserialize, Serialize};
 use crate::accounts::worker::pool_logs::basket_data::{PoolRecordBasketData};
+use crate::accounts::devol_layout::devol_layout;
 
-pub const POOL_RECORD_BASKET_SIZE: usize = 104;
 pub const POOL_RECORD_BASKET_LENGTH: usize = 4;
 
 #[derive(Copy, Clone, PartialOrd, PartialEq, Debug, Serialize, Deserialize)]
@@ -12,6 +12,12 @@ pub struct PoolRecordBasket {
     pub basket_elements: [PoolRecordBasketData; POOL_RECORD_BASKET_LENGTH], // 24x4=96 bytes
 }
 
+devol_layout! {
+    PoolRecordBasket, POOL_RECORD_BASKET_SIZE = 104, test_pool_record_basket_layout;
+    length: i64 = 0,
+    basket_elements: [PoolRecordBasketData; POOL_RECORD_BASKET_LENGTH] = 8,
+}
+
 impl Default for PoolRecordBasket {
     fn default() -> Self {
         Self {
@@ -19,17 +25,4 @@ impl Default for PoolRecordBasket {
             basket_elements: [PoolRecordBasketData::default(); POOL_RECORD_BASKET_LENGTH],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::utils::type_size_helper::align_size;
-    use super::*;
-
-    #[test]
-    fn test_pools_log_offsets() {
-        let real_size = std::mem::size_of::<PoolRecordBasket>();
-        assert_eq!(real_size, POOL_RECORD_BASKET_SIZE);
-        assert_eq!(real_size, align_size(real_size, 8));
-    }
 }
\ No newline at end of file
@@ -1,8 +1,7 @@
 use solana_program::pubkey::Pubkey;
 use crate::accounts::worker::pool_logs::pool_basket::PoolRecordBasket;
 use crate::constants::{BUCKETS_COUNT};
-
-pub const POOLS_LOG_SIZE: usize = 1328;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -34,6 +33,24 @@ pub struct PoolLogRecord {
     pub traded_basket: PoolRecordBasket, // 104 bytes
 }
 
+devol_layout! {
+    PoolLogRecord, POOLS_LOG_SIZE = 1328, test_pool_log_record_layout;
+    worker_operations_count: u64 = 0,
+    pool_trades_count: u64 = 8,
+    pool_id: u32 = 16,
+    instrument_id: u32 = 20,
+    task_id: u32 = 24,
+    fractions: u32 = 28,
+    trade_time: i64 = 32,
+    client_pubkey: Pubkey = 40,
+    cost: i64 = 72,
+    price_distribution: [i64; BUCKETS_COUNT] = 80,
+    trade_quantity: [i32; BUCKETS_COUNT] = 840,
+    basket_page: u16 = 1220,
+    basket_pages_count: u16 = 1222,
+    traded_basket: PoolRecordBasket = 1224,
+}
+
 impl PoolLogRecord {
 }
 
@@ -56,17 +73,4 @@ impl Default for PoolLogRecord {
             basket_pages_count: 0,
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::utils::type_size_helper::align_size;
-    use super::*;
-
-    #[test]
-    fn test_pools_log_offsets() {
-        let real_size = std::mem::size_of::<PoolLogRecord>();
-        assert_eq!(real_size, POOLS_LOG_SIZE);
-        assert_eq!(real_size, align_size(real_size, 8));
-    }
 }
\ No newline at end of file
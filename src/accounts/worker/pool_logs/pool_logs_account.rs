@@ -1,10 +1,13 @@
 use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
+use crate::accounts::devol_legacy_account::DevolLegacyLayout;
+use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
 use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::ring_log::RingLog;
 
 pub const POOLS_LOG_BUFFER_CAPACITY: usize = 256;
-pub const POOLS_LOG_ACCOUNT_SIZE: usize = 340024;
 pub const POOLS_LOG_ACCOUNT_TAG: u8 = 6;
 pub const POOLS_LOG_ACCOUNT_VERSION: u32 = 9;
 
@@ -20,8 +23,43 @@ pub struct PoolLogsAccount {
     pub pool_log_records: [PoolLogRecord; POOLS_LOG_BUFFER_CAPACITY], // 1328x256=339968 bytes
 }
 
+devol_layout! {
+    PoolLogsAccount, POOLS_LOG_ACCOUNT_SIZE = 340024, test_pool_logs_account_layout;
+    header: AccountHeader = 0,
+    worker_id: u32 = 40,
+    last_pool_id: u32 = 44,
+    pools_count: u32 = 48,
+    reserved: u32 = 52,
+    pool_log_records: [PoolLogRecord; POOLS_LOG_BUFFER_CAPACITY] = 56,
+}
+
 impl DevolIndexedAccount for PoolLogsAccount {}
 
+impl RingLog for PoolLogsAccount {
+    type Record = PoolLogRecord;
+
+    const CAPACITY: usize = POOLS_LOG_BUFFER_CAPACITY;
+
+    fn buffer(&self) -> &[PoolLogRecord] {
+        &self.pool_log_records
+    }
+
+    /// `pools_count` counts every record, the overwritten ones included.
+    fn written_count(&self) -> u64 {
+        self.pools_count as u64
+    }
+
+    /// There is no `last` field, the newest record is the one before `pools_count`.
+    fn newest_index(&self) -> Option<usize> {
+        (self.pools_count > 0).then(|| (self.pools_count as usize - 1) % POOLS_LOG_BUFFER_CAPACITY)
+    }
+
+    /// Several records of a pool share its id, the worker operations count is unique.
+    fn record_id(record: &PoolLogRecord) -> i64 {
+        record.worker_operations_count as i64
+    }
+}
+
 impl DevolAccount for PoolLogsAccount {
     #[inline(always)]
     fn expected_size() -> usize { POOLS_LOG_ACCOUNT_SIZE }
@@ -35,6 +73,13 @@ impl DevolAccount for PoolLogsAccount {
     fn expected_version() -> u32 {
         POOLS_LOG_ACCOUNT_VERSION
     }
+
+    fn legacy_layouts() -> &'static [DevolLegacyLayout<Self>] {
+        const LEGACY_LAYOUTS: [DevolLegacyLayout<PoolLogsAccount>; 1] = [
+            DevolLegacyLayout::of::<PoolLogsAccountV8>(),
+        ];
+        &LEGACY_LAYOUTS
+    }
 }
 
 impl Default for PoolLogsAccount {
@@ -48,27 +93,4 @@ impl Default for PoolLogsAccount {
             pool_log_records: [PoolLogRecord::default(); POOLS_LOG_BUFFER_CAPACITY],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use crate::utils::type_size_helper::align_size;
-    use super::*;
-
-    #[test]
-    fn test_pools_log_account_offsets() {
-        let account = PoolLogsAccount::default();
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &account.header as *const _ as usize - base_ptr,
-            0
-        );
-
-        // checking total size
-        let real_size = std::mem::size_of::<PoolLogsAccount>();
-        assert_eq!(real_size, POOLS_LOG_ACCOUNT_SIZE);
-        assert_eq!(real_size, align_size(real_size, 8));
-    }
 }
\ No newline at end of file
@@ -1,20 +1,8 @@
 use solana_program::pubkey::Pubkey;
+use crate::accounts::client::client_account::client_pool_basket::BasketData;
+use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
 use crate::constants::{BUCKETS_COUNT, VANILLA_COST_SIZE, VANILLA_MEMO_SIZE};
-pub const POOLS_LOG_ID_OFFSET: usize = 0;
-pub const POOLS_LOG_TIME_OFFSET: usize = 8;
-pub const POOLS_LOG_EVENT_TYPE_OFFSET: usize = 16;
-pub const POOLS_LOG_FRACTIONS_OFFSET: usize = 20;
-pub const POOLS_LOG_TASK_ID_OFFSET: usize = 24;
-pub const POOLS_LOG_POOL_ID_OFFSET: usize = 28;
-pub const POOLS_LOG_INSTR_ID_OFFSET: usize = 32;
-pub const POOLS_LOG_COUNTER_OFFSET: usize = 36;
-pub const POOLS_LOG_PUBKEY_OFFSET: usize = 44;
-pub const POOLS_LOG_COST_OFFSET: usize = 76;
-pub const POOLS_LOG_PX_OFFSET: usize = 84;
-pub const POOLS_LOG_QTY_OFFSET: usize = 844;
-pub const POOLS_LOG_VANILLA_MEMO_OFFSET: usize = 1224;
-pub const POOLS_LOG_VANILLA_COST_OFFSET: usize = 1273;
-pub const POOLS_LOG_SIZE: usize = 1305;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -44,6 +32,25 @@ pub struct PoolsLogV8 {
     // 49 + 32 = 83
 }
 
+devol_layout! {
+    PoolsLogV8, POOLS_LOG_SIZE = 1305, test_pools_log_v8_layout;
+    id: [u8; 8] => POOLS_LOG_ID_OFFSET = 0,
+    time: [u8; 8] => POOLS_LOG_TIME_OFFSET = 8,
+    event_type: [u8; 4] => POOLS_LOG_EVENT_TYPE_OFFSET = 16,
+    fractions: [u8; 4] => POOLS_LOG_FRACTIONS_OFFSET = 20,
+    task_id: [u8; 4] => POOLS_LOG_TASK_ID_OFFSET = 24,
+    pool_id: [u8; 4] => POOLS_LOG_POOL_ID_OFFSET = 28,
+    instr_id: [u8; 4] => POOLS_LOG_INSTR_ID_OFFSET = 32,
+    counter: [u8; 8] => POOLS_LOG_COUNTER_OFFSET = 36,
+    pubkey: Pubkey => POOLS_LOG_PUBKEY_OFFSET = 44,
+    cost: [u8; 8] => POOLS_LOG_COST_OFFSET = 76,
+    px: [[u8; 8]; BUCKETS_COUNT] => POOLS_LOG_PX_OFFSET = 84,
+    qty: [[u8; 4]; BUCKETS_COUNT] => POOLS_LOG_QTY_OFFSET = 844,
+    #[parse = LeParser::bytes]
+    vanilla_memo: [u8; VANILLA_MEMO_SIZE] => POOLS_LOG_VANILLA_MEMO_OFFSET = 1224,
+    vanilla_cost: [[u8; 8]; VANILLA_COST_SIZE] => POOLS_LOG_VANILLA_COST_OFFSET = 1273,
+}
+
 impl PoolsLogV8 {
     #[inline(always)]
     pub fn get_id(&self) -> i64 {
@@ -164,6 +171,39 @@ impl PoolsLogV8 {
     pub fn set_vanilla_cost(&mut self, index: usize, value: i64) {
         self.vanilla_cost[index] = value.to_ne_bytes();
     }
+
+    /// Converts the record into the current layout. The vanilla memo holds the basket length
+    /// followed by `BasketData` elements (strike, put or call, amount), the event type is dropped.
+    pub fn to_pool_log_record(&self) -> PoolLogRecord {
+        let mut record = PoolLogRecord {
+            worker_operations_count: self.get_id() as u64,
+            pool_trades_count: self.get_counter() as u64,
+            pool_id: self.get_pool_id(),
+            instrument_id: self.get_instr_id(),
+            task_id: self.get_task_id(),
+            fractions: self.get_fractions(),
+            trade_time: self.get_time(),
+            client_pubkey: self.pubkey,
+            cost: self.get_cost(),
+            basket_page: 0,
+            basket_pages_count: 1,
+            ..PoolLogRecord::default()
+        };
+        for index in 0..BUCKETS_COUNT {
+            record.price_distribution[index] = self.get_px(index);
+            record.trade_quantity[index] = self.get_qty(index);
+        }
+        let basket = BasketData::from_vanilla_memo(&self.vanilla_memo);
+        let elements = record.traded_basket.basket_elements.iter_mut().zip(basket).enumerate();
+        for (index, (element, data)) in elements {
+            element.strike = data.get_strike() as i64;
+            element.put_or_call = data.get_put_or_call();
+            element.amount = data.get_amount();
+            element.vanilla_cost = self.get_vanilla_cost(index);
+            record.traded_basket.length = index as i64 + 1;
+        }
+        record
+    }
 }
 
 #[cfg(test)]
@@ -186,77 +226,4 @@ impl Default for PoolsLogV8 {
             vanilla_cost: [[0; 8]; VANILLA_COST_SIZE],
         }
     }
-}
-
-#[cfg(test)]
-mod tests {
-    use std::mem;
-    use super::*;
-
-    #[test]
-    fn test_pools_log_offsets() {
-        let log = PoolsLogV8::default();
-        let base_ptr = &log as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &log.id as *const _ as usize - base_ptr,
-            POOLS_LOG_ID_OFFSET
-        );
-        assert_eq!(
-            &log.time as *const _ as usize - base_ptr,
-            POOLS_LOG_TIME_OFFSET
-        );
-        assert_eq!(
-            &log.event_type as *const _ as usize - base_ptr,
-            POOLS_LOG_EVENT_TYPE_OFFSET
-        );
-        assert_eq!(
-            &log.fractions as *const _ as usize - base_ptr,
-            POOLS_LOG_FRACTIONS_OFFSET
-        );
-        assert_eq!(
-            &log.task_id as *const _ as usize - base_ptr,
-            POOLS_LOG_TASK_ID_OFFSET
-        );
-        assert_eq!(
-            &log.pool_id as *const _ as usize - base_ptr,
-            POOLS_LOG_POOL_ID_OFFSET
-        );
-        assert_eq!(
-            &log.instr_id as *const _ as usize - base_ptr,
-            POOLS_LOG_INSTR_ID_OFFSET
-        );
-        assert_eq!(
-            &log.counter as *const _ as usize - base_ptr,
-            POOLS_LOG_COUNTER_OFFSET
-        );
-        assert_eq!(
-            &log.pubkey as *const _ as usize - base_ptr,
-            POOLS_LOG_PUBKEY_OFFSET
-        );
-        assert_eq!(
-            &log.cost as *const _ as usize - base_ptr,
-            POOLS_LOG_COST_OFFSET
-        );
-        assert_eq!(
-            &log.px as *const _ as usize - base_ptr,
-            POOLS_LOG_PX_OFFSET
-        );
-        assert_eq!(
-            &log.qty as *const _ as usize - base_ptr,
-            POOLS_LOG_QTY_OFFSET
-        );
-        assert_eq!(
-            &log.vanilla_memo as *const _ as usize - base_ptr,
-            POOLS_LOG_VANILLA_MEMO_OFFSET
-        );
-        assert_eq!(
-            &log.vanilla_cost as *const _ as usize - base_ptr,
-            POOLS_LOG_VANILLA_COST_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(mem::size_of::<PoolsLogV8>(), POOLS_LOG_SIZE);
-    }
 }
\ No newline at end of file
@@ -1,16 +1,14 @@
+use std::alloc::{alloc_zeroed, handle_alloc_error, Layout};
 use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
+use crate::accounts::devol_legacy_account::DevolLegacyAccount;
+use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
 use crate::accounts::worker::pool_logs::v8::pool_log_v8::PoolsLogV8;
+use crate::accounts::devol_layout::devol_layout;
 
 pub const POOLS_LOG_BUFFER_CAPACITY: usize = 256;
-pub const POOLS_LOG_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const POOLS_LOG_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const POOLS_LOG_ACCOUNT_WORKER_ID_OFFSET: usize = 40;
-pub const POOLS_LOG_ACCOUNT_LAST_OFFSET: usize = 44;
-pub const POOLS_LOG_ACCOUNT_COUNT_OFFSET: usize = 48;
-pub const POOLS_LOG_ACCOUNT_DATA_OFFSET: usize = 52;
-pub const POOLS_LOG_ACCOUNT_SIZE: usize = 334132;
 pub const POOLS_LOG_ACCOUNT_TAG: u8 = 6;
 pub const POOLS_LOG_ACCOUNT_VERSION: u32 = 8;
 
@@ -29,6 +27,15 @@ pub struct PoolLogsAccountV8 {
     pub data: [PoolsLogV8; POOLS_LOG_BUFFER_CAPACITY],
 }
 
+devol_layout! {
+    PoolLogsAccountV8, POOLS_LOG_ACCOUNT_SIZE = 334132, test_pool_logs_account_v8_layout;
+    header: AccountHeader => POOLS_LOG_ACCOUNT_VERSION_OFFSET = 0,
+    worker_id: u32 => POOLS_LOG_ACCOUNT_WORKER_ID_OFFSET = 40,
+    last: u32 => POOLS_LOG_ACCOUNT_LAST_OFFSET = 44,
+    count: u32 => POOLS_LOG_ACCOUNT_COUNT_OFFSET = 48,
+    data: [PoolsLogV8; POOLS_LOG_BUFFER_CAPACITY] => POOLS_LOG_ACCOUNT_DATA_OFFSET = 52,
+}
+
 impl DevolIndexedAccount for PoolLogsAccountV8 {}
 
 impl DevolAccount for PoolLogsAccountV8 {
@@ -46,6 +53,34 @@ impl DevolAccount for PoolLogsAccountV8 {
     }
 }
 
+impl DevolLegacyAccount<PoolLogsAccount> for PoolLogsAccountV8 {
+    /// Records keep their ring buffer positions, `last` and `count` map to `last_pool_id` and
+    /// `pools_count`. The header gets the current version.
+    fn upgrade(&self) -> Box<PoolLogsAccount> {
+        // Allocated zeroed on the heap, the account would not fit the stack. Zero is a valid value
+        // of every field and the same as `PoolLogsAccount::default`.
+        let layout = Layout::new::<PoolLogsAccount>();
+        let mut account = unsafe {
+            let ptr = alloc_zeroed(layout) as *mut PoolLogsAccount;
+            if ptr.is_null() {
+                handle_alloc_error(layout);
+            }
+            Box::from_raw(ptr)
+        };
+        account.header = AccountHeader {
+            version: PoolLogsAccount::expected_version(),
+            ..self.header
+        };
+        account.worker_id = self.worker_id;
+        account.last_pool_id = self.last;
+        account.pools_count = self.count;
+        for (record, record_v8) in account.pool_log_records.iter_mut().zip(self.data.iter()) {
+            *record = record_v8.to_pool_log_record();
+        }
+        account
+    }
+}
+
 #[cfg(test)]
 impl Default for PoolLogsAccountV8 {
     fn default() -> Self {
@@ -62,35 +97,39 @@ impl Default for PoolLogsAccountV8 {
 #[cfg(test)]
 mod tests {
     use super::*;
+    use crate::utils::put_or_call::PutOrCall;
 
     #[test]
-    fn test_pools_log_account_offsets() {
-        let account = PoolLogsAccountV8::default();
-        let base_ptr = &account as *const _ as usize;
+    fn test_pools_log_account_v8_upgrade() {
+        let mut account = Box::new(PoolLogsAccountV8::default());
+        account.header.tag = POOLS_LOG_ACCOUNT_TAG as u32;
+        account.header.version = POOLS_LOG_ACCOUNT_VERSION;
+        account.worker_id = 2;
+        account.count = 1;
+        account.data[0].set_pool_id(5);
+        account.data[0].set_px(10, 777);
+        account.data[0].vanilla_memo[0] = 1;
+        account.data[0].vanilla_memo[1..5].copy_from_slice(&40u32.to_ne_bytes());
+        account.data[0].vanilla_memo[5..9].copy_from_slice(&(PutOrCall::PUT as u32).to_ne_bytes());
+        account.data[0].vanilla_memo[9..13].copy_from_slice(&(-3i32).to_ne_bytes());
+        account.data[0].set_vanilla_cost(0, 99);
+        let mut data = vec![0u8; POOLS_LOG_ACCOUNT_SIZE];
+        PoolLogsAccountV8::view_mut(&mut data).unwrap().write_at(0, &*account).unwrap();
 
-        // checking fields size and offset
-        assert_eq!(
-            &account.header as *const _ as usize - base_ptr,
-            0
-        );
-        assert_eq!(
-            &account.worker_id as *const _ as usize - base_ptr,
-            POOLS_LOG_ACCOUNT_WORKER_ID_OFFSET
-        );
-        assert_eq!(
-            &account.last as *const _ as usize - base_ptr,
-            POOLS_LOG_ACCOUNT_LAST_OFFSET
-        );
-        assert_eq!(
-            &account.count as *const _ as usize - base_ptr,
-            POOLS_LOG_ACCOUNT_COUNT_OFFSET
-        );
-        assert_eq!(
-            &account.data as *const _ as usize - base_ptr,
-            POOLS_LOG_ACCOUNT_DATA_OFFSET
-        );
+        let upgraded = PoolLogsAccount::from_versioned_data(&data).unwrap();
+        assert_eq!(upgraded.header.version, PoolLogsAccount::expected_version());
+        assert_eq!(upgraded.worker_id, 2);
+        assert_eq!(upgraded.pools_count, 1);
+        let record = &upgraded.pool_log_records[0];
+        assert_eq!(record.pool_id, 5);
+        assert_eq!(record.price_distribution[10], 777);
+        assert_eq!(record.traded_basket.length, 1);
+        assert_eq!(record.traded_basket.basket_elements[0].strike, 40);
+        assert_eq!(record.traded_basket.basket_elements[0].put_or_call, PutOrCall::PUT);
+        assert_eq!(record.traded_basket.basket_elements[0].amount, -3);
+        assert_eq!(record.traded_basket.basket_elements[0].vanilla_cost, 99);
 
-        // checking total size
-        assert_eq!(std::mem::size_of::<PoolLogsAccountV8>(), POOLS_LOG_ACCOUNT_SIZE);
+        PoolLogsAccountV8::view_mut(&mut data).unwrap().write_at(4, &(POOLS_LOG_ACCOUNT_VERSION - 1)).unwrap();
+        assert!(PoolLogsAccount::from_versioned_data(&data).is_err());
     }
 }
\ No newline at end of file
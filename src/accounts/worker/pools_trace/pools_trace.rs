@@ -1,17 +1,4 @@
-pub const POOLS_TRACE_TASK_ID_OFFSET: usize = 0;
-pub const POOLS_TRACE_EXPIRATION_OFFSET: usize = 4;
-pub const POOLS_TRACE_UPDATE_TIME_OFFSET: usize = 12;
-pub const POOLS_TRACE_SETTLEMENT_PX_OFFSET: usize = 20;
-pub const POOLS_TRACE_CPS_PX_OFFSET: usize = 28;
-pub const POOLS_TRACE_PS_OFFSET: usize = 36;
-pub const POOLS_TRACE_PS_PX_OFFSET: usize = 40;
-pub const POOLS_TRACE_TASK_FEES_OFFSET: usize = 48;
-pub const POOLS_TRACE_PAYOFF_LONG_OFFSET: usize = 56;
-pub const POOLS_TRACE_PAYOFF_SHORT_OFFSET: usize = 64;
-pub const POOLS_TRACE_REST_OF_PAYOFF_LONG_OFFSET: usize = 72;
-pub const POOLS_TRACE_REST_OF_PAYOFF_SHORT_OFFSET: usize = 80;
-pub const POOLS_TRACE_SETTLEMENT_STRIKE_OFFSET: usize = 88;
-pub const POOLS_TRACE_SIZE: usize = 96;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -44,6 +31,23 @@ pub struct PoolsTrace {
     pub payoff_time: [u8; 8],
 }
 
+devol_layout! {
+    PoolsTrace, POOLS_TRACE_SIZE = 96, test_pools_trace_layout;
+    task_id: u32 => POOLS_TRACE_TASK_ID_OFFSET = 0,
+    expiration: [u8; 8] => POOLS_TRACE_EXPIRATION_OFFSET = 4,
+    update_time: [u8; 8] => POOLS_TRACE_UPDATE_TIME_OFFSET = 12,
+    settlement_px: [u8; 8] => POOLS_TRACE_SETTLEMENT_PX_OFFSET = 20,
+    cps_px: [u8; 8] => POOLS_TRACE_CPS_PX_OFFSET = 28,
+    ps: u32 => POOLS_TRACE_PS_OFFSET = 36,
+    ps_px: [u8; 8] => POOLS_TRACE_PS_PX_OFFSET = 40,
+    task_fees: [u8; 8] => POOLS_TRACE_TASK_FEES_OFFSET = 48,
+    payoff_long: [u8; 8] => POOLS_TRACE_PAYOFF_LONG_OFFSET = 56,
+    payoff_short: [u8; 8] => POOLS_TRACE_PAYOFF_SHORT_OFFSET = 64,
+    rest_of_payoff_long: [u8; 8] => POOLS_TRACE_REST_OF_PAYOFF_LONG_OFFSET = 72,
+    rest_of_payoff_short: [u8; 8] => POOLS_TRACE_REST_OF_PAYOFF_SHORT_OFFSET = 80,
+    payoff_time: [u8; 8] => POOLS_TRACE_SETTLEMENT_STRIKE_OFFSET = 88,
+}
+
 impl PoolsTrace {
     #[inline(always)]
     pub fn get_expiration(&self) -> i64 {
@@ -176,71 +180,3 @@ impl Default for PoolsTrace {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pools_trace_offsets() {
-        let trace = PoolsTrace::default();
-        let base_ptr = &trace as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &trace.task_id as *const _ as usize - base_ptr,
-            POOLS_TRACE_TASK_ID_OFFSET
-        );
-        assert_eq!(
-            &trace.expiration as *const _ as usize - base_ptr,
-            POOLS_TRACE_EXPIRATION_OFFSET
-        );
-        assert_eq!(
-            &trace.update_time as *const _ as usize - base_ptr,
-            POOLS_TRACE_UPDATE_TIME_OFFSET
-        );
-        assert_eq!(
-            &trace.settlement_px as *const _ as usize - base_ptr,
-            POOLS_TRACE_SETTLEMENT_PX_OFFSET
-        );
-        assert_eq!(
-            &trace.cps_px as *const _ as usize - base_ptr,
-            POOLS_TRACE_CPS_PX_OFFSET
-        );
-        assert_eq!(
-            &trace.ps as *const _ as usize - base_ptr,
-            POOLS_TRACE_PS_OFFSET
-        );
-        assert_eq!(
-            &trace.ps_px as *const _ as usize - base_ptr,
-            POOLS_TRACE_PS_PX_OFFSET
-        );
-        assert_eq!(
-            &trace.task_fees as *const _ as usize - base_ptr,
-            POOLS_TRACE_TASK_FEES_OFFSET
-        );
-        assert_eq!(
-            &trace.payoff_long as *const _ as usize - base_ptr,
-            POOLS_TRACE_PAYOFF_LONG_OFFSET
-        );
-        assert_eq!(
-            &trace.payoff_short as *const _ as usize - base_ptr,
-            POOLS_TRACE_PAYOFF_SHORT_OFFSET
-        );
-        assert_eq!(
-            &trace.rest_of_payoff_long as *const _ as usize - base_ptr,
-            POOLS_TRACE_REST_OF_PAYOFF_LONG_OFFSET
-        );
-        assert_eq!(
-            &trace.rest_of_payoff_short as *const _ as usize - base_ptr,
-            POOLS_TRACE_REST_OF_PAYOFF_SHORT_OFFSET
-        );
-        assert_eq!(
-            &trace.payoff_time as *const _ as usize - base_ptr,
-            POOLS_TRACE_SETTLEMENT_STRIKE_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<PoolsTrace>(), POOLS_TRACE_SIZE);
-    }
-}
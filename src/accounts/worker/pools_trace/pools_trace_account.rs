@@ -2,12 +2,9 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 use crate::accounts::worker::pools_trace::pools_trace::PoolsTrace;
+use crate::accounts::devol_layout::devol_layout;
 
-pub const POOLS_TRACE_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const POOLS_TRACE_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const POOLS_TRACE_ACCOUNT_WORKER_ID_OFFSET: usize = 40;
-pub const POOLS_TRACE_ACCOUNT_DATA_OFFSET: usize = 44;
-pub const POOLS_TRACE_ACCOUNT_SIZE: usize = 24620;
 pub const POOLS_TRACE_ACCOUNT_TAG: u8 = 4;
 pub const POOLS_TRACE_ACCOUNT_VERSION: u32 = 6;
 pub const MAX_POOLS_TRACE_COUNT: usize = 256;
@@ -22,6 +19,14 @@ pub struct PoolsTraceAccount {
     // 24576 bytes, POOLS_TRACE_ACCOUNT_DATA_OFFSET
     pub data: [PoolsTrace; MAX_POOLS_TRACE_COUNT],
 }
+
+devol_layout! {
+    PoolsTraceAccount, POOLS_TRACE_ACCOUNT_SIZE = 24620, test_pools_trace_account_layout;
+    header: AccountHeader => POOLS_TRACE_ACCOUNT_VERSION_OFFSET = 0,
+    worker_id: u32 => POOLS_TRACE_ACCOUNT_WORKER_ID_OFFSET = 40,
+    data: [PoolsTrace; MAX_POOLS_TRACE_COUNT] => POOLS_TRACE_ACCOUNT_DATA_OFFSET = 44,
+}
+
 impl DevolIndexedAccount for PoolsTraceAccount{}
 
 impl DevolAccount for PoolsTraceAccount {
@@ -49,35 +54,3 @@ impl Default for PoolsTraceAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_pools_trace_account_offsets() {
-        let account = PoolsTraceAccount::default();
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &account.header.tag as *const _ as usize - base_ptr,
-            POOLS_TRACE_ACCOUNT_VERSION_OFFSET
-        );
-        assert_eq!(
-            &account.header.root as *const _ as usize - base_ptr,
-            POOLS_TRACE_ACCOUNT_ROOT_ADDRESS_OFFSET
-        );
-        assert_eq!(
-            &account.worker_id as *const _ as usize - base_ptr,
-            POOLS_TRACE_ACCOUNT_WORKER_ID_OFFSET
-        );
-        assert_eq!(
-            &account.data as *const _ as usize - base_ptr,
-            POOLS_TRACE_ACCOUNT_DATA_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<PoolsTraceAccount>(), POOLS_TRACE_ACCOUNT_SIZE);
-    }
-}
@@ -1,11 +1,35 @@
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
 #[repr(C)]
-#[derive(Copy, Clone, Serialize, Deserialize)]
+#[derive(Copy, Clone, PartialEq, Debug, Serialize, Deserialize)]
 pub struct SvmParams {
     pub v: f64,
     pub psi: f64,
     pub p: f64,
     pub c: f64,
     pub vt: f64,
-} // size: 40 bytes
\ No newline at end of file
+} // size: 40 bytes
+
+impl DevolParse for SvmParams {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        Ok(Self {
+            v: parser.f64(offset)?,
+            psi: parser.f64(offset + 8)?,
+            p: parser.f64(offset + 16)?,
+            c: parser.f64(offset + 24)?,
+            vt: parser.f64(offset + 32)?,
+        })
+    }
+}
+
+impl DevolWrite for SvmParams {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.f64(offset, self.v)?;
+        writer.f64(offset + 8, self.psi)?;
+        writer.f64(offset + 16, self.p)?;
+        writer.f64(offset + 24, self.c)?;
+        writer.f64(offset + 32, self.vt)
+    }
+}
\ No newline at end of file
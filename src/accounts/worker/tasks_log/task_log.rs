@@ -1,19 +1,5 @@
 use solana_program::pubkey::Pubkey;
-
-pub const TASKS_LOG_ID_OFFSET: usize = 0;
-pub const TASKS_LOG_TIME_OFFSET: usize = 8;
-pub const TASKS_LOG_EVENT_TYPE_OFFSET: usize = 16;
-pub const TASKS_LOG_TASK_ID_OFFSET: usize = 20;
-pub const TASKS_LOG_POOL_ID_OFFSET: usize = 24;
-pub const TASKS_LOG_INSTR_ID_OFFSET: usize = 28;
-pub const TASKS_LOG_COUNTER_OFFSET: usize = 32;
-pub const TASKS_LOG_PUBKEY_OFFSET: usize = 40;
-pub const TASKS_LOG_COST_OFFSET: usize = 72;
-pub const TASKS_LOG_TRADE_QTY_OFFSET: usize = 80;
-pub const TASKS_LOG_TRADE_PX_OFFSET: usize = 84;
-pub const TASKS_LOG_PS_OFFSET: usize = 92;
-pub const TASKS_LOG_PS_PX_OFFSET: usize = 96;
-pub const TASKS_LOG_SIZE: usize = 104;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -46,6 +32,23 @@ pub struct TasksLog {
     pub ps_px: [u8; 8],
 }
 
+devol_layout! {
+    TasksLog, TASKS_LOG_SIZE = 104, test_tasks_log_layout;
+    id: [u8; 8] => TASKS_LOG_ID_OFFSET = 0,
+    time: [u8; 8] => TASKS_LOG_TIME_OFFSET = 8,
+    event_type: u32 => TASKS_LOG_EVENT_TYPE_OFFSET = 16,
+    task_id: u32 => TASKS_LOG_TASK_ID_OFFSET = 20,
+    pool_id: u32 => TASKS_LOG_POOL_ID_OFFSET = 24,
+    instr_id: u32 => TASKS_LOG_INSTR_ID_OFFSET = 28,
+    counter: [u8; 8] => TASKS_LOG_COUNTER_OFFSET = 32,
+    pubkey: Pubkey => TASKS_LOG_PUBKEY_OFFSET = 40,
+    cost: [u8; 8] => TASKS_LOG_COST_OFFSET = 72,
+    trade_qty: i32 => TASKS_LOG_TRADE_QTY_OFFSET = 80,
+    trade_px: [u8; 8] => TASKS_LOG_TRADE_PX_OFFSET = 84,
+    ps: u32 => TASKS_LOG_PS_OFFSET = 92,
+    ps_px: [u8; 8] => TASKS_LOG_PS_PX_OFFSET = 96,
+}
+
 impl TasksLog {
     #[inline(always)]
     pub fn get_id(&self) -> i64 {
@@ -128,71 +131,3 @@ impl Default for TasksLog {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_tasks_log_offsets() {
-        let log = TasksLog::default();
-        let base_ptr = &log as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &log.id as *const _ as usize - base_ptr,
-            TASKS_LOG_ID_OFFSET
-        );
-        assert_eq!(
-            &log.time as *const _ as usize - base_ptr,
-            TASKS_LOG_TIME_OFFSET
-        );
-        assert_eq!(
-            &log.event_type as *const _ as usize - base_ptr,
-            TASKS_LOG_EVENT_TYPE_OFFSET
-        );
-        assert_eq!(
-            &log.task_id as *const _ as usize - base_ptr,
-            TASKS_LOG_TASK_ID_OFFSET
-        );
-        assert_eq!(
-            &log.pool_id as *const _ as usize - base_ptr,
-            TASKS_LOG_POOL_ID_OFFSET
-        );
-        assert_eq!(
-            &log.instr_id as *const _ as usize - base_ptr,
-            TASKS_LOG_INSTR_ID_OFFSET
-        );
-        assert_eq!(
-            &log.counter as *const _ as usize - base_ptr,
-            TASKS_LOG_COUNTER_OFFSET
-        );
-        assert_eq!(
-            &log.pubkey as *const _ as usize - base_ptr,
-            TASKS_LOG_PUBKEY_OFFSET
-        );
-        assert_eq!(
-            &log.cost as *const _ as usize - base_ptr,
-            TASKS_LOG_COST_OFFSET
-        );
-        assert_eq!(
-            &log.trade_qty as *const _ as usize - base_ptr,
-            TASKS_LOG_TRADE_QTY_OFFSET
-        );
-        assert_eq!(
-            &log.trade_px as *const _ as usize - base_ptr,
-            TASKS_LOG_TRADE_PX_OFFSET
-        );
-        assert_eq!(
-            &log.ps as *const _ as usize - base_ptr,
-            TASKS_LOG_PS_OFFSET
-        );
-        assert_eq!(
-            &log.ps_px as *const _ as usize - base_ptr,
-            TASKS_LOG_PS_PX_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<TasksLog>(), TASKS_LOG_SIZE);
-    }
-}
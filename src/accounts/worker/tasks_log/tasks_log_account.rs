@@ -2,15 +2,11 @@ use crate::accounts::account_header::AccountHeader;
 use crate::accounts::devol_account::DevolAccount;
 use crate::accounts::devol_indexed_account::DevolIndexedAccount;
 use crate::accounts::worker::tasks_log::task_log::TasksLog;
+use crate::accounts::devol_layout::devol_layout;
+use crate::accounts::ring_log::RingLog;
 
 pub const TASKS_LOG_BUFFER_CAPACITY: usize = 256;
-pub const TASKS_LOG_ACCOUNT_VERSION_OFFSET: usize = 0;
 pub const TASKS_LOG_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const TASKS_LOG_ACCOUNT_WORKER_ID_OFFSET: usize = 40;
-pub const TASKS_LOG_ACCOUNT_LAST_OFFSET: usize = 44;
-pub const TASKS_LOG_ACCOUNT_COUNT_OFFSET: usize = 48;
-pub const TASKS_LOG_ACCOUNT_DATA_OFFSET: usize = 52;
-pub const TASKS_LOG_ACCOUNT_SIZE: usize = 26676;
 pub const TASKS_LOG_ACCOUNT_TAG: u8 = 11;
 pub const TASKS_LOG_ACCOUNT_VERSION: u32 = 13;
 
@@ -28,8 +24,40 @@ pub struct TasksLogAccount {
     // 26624 bytes, TASKS_LOG_ACCOUNT_DATA_OFFSET
     pub data: [TasksLog; TASKS_LOG_BUFFER_CAPACITY],
 }
+
+devol_layout! {
+    TasksLogAccount, TASKS_LOG_ACCOUNT_SIZE = 26676, test_tasks_log_account_layout;
+    header: AccountHeader => TASKS_LOG_ACCOUNT_VERSION_OFFSET = 0,
+    worker_id: u32 => TASKS_LOG_ACCOUNT_WORKER_ID_OFFSET = 40,
+    last: u32 => TASKS_LOG_ACCOUNT_LAST_OFFSET = 44,
+    count: u32 => TASKS_LOG_ACCOUNT_COUNT_OFFSET = 48,
+    data: [TasksLog; TASKS_LOG_BUFFER_CAPACITY] => TASKS_LOG_ACCOUNT_DATA_OFFSET = 52,
+}
+
 impl DevolIndexedAccount for TasksLogAccount{}
 
+impl RingLog for TasksLogAccount {
+    type Record = TasksLog;
+
+    const CAPACITY: usize = TASKS_LOG_BUFFER_CAPACITY;
+
+    fn buffer(&self) -> &[TasksLog] {
+        &self.data
+    }
+
+    fn written_count(&self) -> u64 {
+        self.count as u64
+    }
+
+    fn newest_index(&self) -> Option<usize> {
+        (self.count > 0).then_some(self.last as usize % TASKS_LOG_BUFFER_CAPACITY)
+    }
+
+    fn record_id(record: &TasksLog) -> i64 {
+        record.get_id()
+    }
+}
+
 impl DevolAccount for TasksLogAccount {
     #[inline(always)]
     fn expected_size() -> usize { TASKS_LOG_ACCOUNT_SIZE }
@@ -57,39 +85,3 @@ impl Default for TasksLogAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_tasks_log_account_offsets() {
-        let account = TasksLogAccount::default();
-        let base_ptr = &account as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &account.header as *const _ as usize - base_ptr,
-            0
-        );
-        assert_eq!(
-            &account.worker_id as *const _ as usize - base_ptr,
-            TASKS_LOG_ACCOUNT_WORKER_ID_OFFSET
-        );
-        assert_eq!(
-            &account.last as *const _ as usize - base_ptr,
-            TASKS_LOG_ACCOUNT_LAST_OFFSET
-        );
-        assert_eq!(
-            &account.count as *const _ as usize - base_ptr,
-            TASKS_LOG_ACCOUNT_COUNT_OFFSET
-        );
-        assert_eq!(
-            &account.data as *const _ as usize - base_ptr,
-            TASKS_LOG_ACCOUNT_DATA_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<TasksLogAccount>(), TASKS_LOG_ACCOUNT_SIZE);
-    }
-}
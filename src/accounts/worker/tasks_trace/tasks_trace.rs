@@ -1,13 +1,4 @@
-pub const TASKS_TRACE_INSTR_ID_OFFSET: usize = 0;
-pub const TASKS_TRACE_FIRST_TIME_OFFSET: usize = 4;
-pub const TASKS_TRACE_DURATION_OFFSET: usize = 12;
-pub const TASKS_TRACE_INIT_PX_OFFSET: usize = 16;
-pub const TASKS_TRACE_UPDATE_TIME_OFFSET: usize = 24;
-pub const TASKS_TRACE_PS_OFFSET: usize = 32;
-pub const TASKS_TRACE_PS_PX_OFFSET: usize = 36;
-pub const TASKS_TRACE_CANCEL_TIME_OFFSET: usize = 44;
-pub const TASKS_TRACE_PAYOFF_TIME_OFFSET: usize = 52;
-pub const TASKS_TRACE_SIZE: usize = 60;
+use crate::accounts::devol_layout::devol_layout;
 
 #[repr(C)]
 #[derive(Clone, Copy)]
@@ -32,6 +23,19 @@ pub struct TasksTrace {
     pub payoff_time: [u8; 8],
 }
 
+devol_layout! {
+    TasksTrace, TASKS_TRACE_SIZE = 60, test_tasks_trace_layout;
+    instr_id: u32 => TASKS_TRACE_INSTR_ID_OFFSET = 0,
+    first_time: [u8; 8] => TASKS_TRACE_FIRST_TIME_OFFSET = 4,
+    duration: u32 => TASKS_TRACE_DURATION_OFFSET = 12,
+    init_px: [u8; 8] => TASKS_TRACE_INIT_PX_OFFSET = 16,
+    update_time: [u8; 8] => TASKS_TRACE_UPDATE_TIME_OFFSET = 24,
+    ps: u32 => TASKS_TRACE_PS_OFFSET = 32,
+    ps_px: [u8; 8] => TASKS_TRACE_PS_PX_OFFSET = 36,
+    cancel_time: [u8; 8] => TASKS_TRACE_CANCEL_TIME_OFFSET = 44,
+    payoff_time: [u8; 8] => TASKS_TRACE_PAYOFF_TIME_OFFSET = 52,
+}
+
 impl TasksTrace {
     #[inline(always)]
     pub fn get_first_time(&self) -> i64 {
@@ -110,55 +114,3 @@ impl Default for TasksTrace {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-
-    #[test]
-    fn test_tasks_trace_offsets() {
-        let trace = TasksTrace::default();
-        let base_ptr = &trace as *const _ as usize;
-
-        // checking fields size and offset
-        assert_eq!(
-            &trace.instr_id as *const _ as usize - base_ptr,
-            TASKS_TRACE_INSTR_ID_OFFSET
-        );
-        assert_eq!(
-            &trace.first_time as *const _ as usize - base_ptr,
-            TASKS_TRACE_FIRST_TIME_OFFSET
-        );
-        assert_eq!(
-            &trace.duration as *const _ as usize - base_ptr,
-            TASKS_TRACE_DURATION_OFFSET
-        );
-        assert_eq!(
-            &trace.init_px as *const _ as usize - base_ptr,
-            TASKS_TRACE_INIT_PX_OFFSET
-        );
-        assert_eq!(
-            &trace.update_time as *const _ as usize - base_ptr,
-            TASKS_TRACE_UPDATE_TIME_OFFSET
-        );
-        assert_eq!(
-            &trace.ps as *const _ as usize - base_ptr,
-            TASKS_TRACE_PS_OFFSET
-        );
-        assert_eq!(
-            &trace.ps_px as *const _ as usize - base_ptr,
-            TASKS_TRACE_PS_PX_OFFSET
-        );
-        assert_eq!(
-            &trace.cancel_time as *const _ as usize - base_ptr,
-            TASKS_TRACE_CANCEL_TIME_OFFSET
-        );
-        assert_eq!(
-            &trace.payoff_time as *const _ as usize - base_ptr,
-            TASKS_TRACE_PAYOFF_TIME_OFFSET
-        );
-
-        // checking total size
-        assert_eq!(std::mem::size_of::<TasksTrace>(), TASKS_TRACE_SIZE);
-    }
-}
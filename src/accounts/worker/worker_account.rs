@@ -5,64 +5,11 @@ use crate::accounts::worker::band::Band;
 use crate::accounts::worker::svm_params::SvmParams;
 use crate::accounts::worker::worker_state::WorkerState;
 use crate::constants::{BOUNDS_COUNT, BUCKETS_COUNT};
+use crate::accounts::devol_layout::devol_layout;
 
 pub const WORKER_ACCOUNT_TAG_OFFSET: usize = 0;
 pub const WORKER_ACCOUNT_VERSION_OFFSET: usize = 4;
 pub const WORKER_ACCOUNT_ROOT_ADDRESS_OFFSET: usize = 8;
-pub const WORKER_ACCOUNT_ID_OFFSET: usize = 40;
-pub const WORKER_ACCOUNT_STATE_OFFSET: usize = 44;
-pub const WORKER_ACCOUNT_TASK_ID_OFFSET: usize = 48;
-pub const WORKER_ACCOUNT_INSTR_ID_OFFSET: usize = 52;
-pub const WORKER_ACCOUNT_OPS_COUNTER_OFFSET: usize = 56;
-pub const WORKER_ACCOUNT_FIRST_TIME_OFFSET: usize = 64;
-pub const WORKER_ACCOUNT_DURATION_OFFSET: usize = 72;
-pub const WORKER_ACCOUNT_INIT_PX_OFFSET: usize = 76;
-pub const WORKER_ACCOUNT_OWN_PS_OFFSET: usize = 84;
-pub const WORKER_ACCOUNT_WIDTH_FACTOR_OFFSET: usize = 88;
-pub const WORKER_ACCOUNT_FEE_RATE_OFFSET: usize = 96;
-pub const WORKER_ACCOUNT_FEE_RATIO_OFFSET: usize = 104;
-pub const WORKER_ACCOUNT_INVENTORIES_RATIO_OFFSET: usize = 112;
-pub const WORKER_ACCOUNT_FRACTIONS_OFFSET: usize = 120;
-pub const WORKER_ACCOUNT_MAX_ORDER_OFFSET: usize = 124;
-pub const WORKER_ACCOUNT_NEW_WIDTH_FACTOR_OFFSET: usize = 132;
-pub const WORKER_ACCOUNT_NEW_FEE_RATE_OFFSET: usize = 140;
-pub const WORKER_ACCOUNT_NEW_FEE_RATIO_OFFSET: usize = 148;
-pub const WORKER_ACCOUNT_NEW_INVENTORIES_RATIO_OFFSET: usize = 156;
-pub const WORKER_ACCOUNT_NEW_FRACTIONS_OFFSET: usize = 164;
-pub const WORKER_ACCOUNT_NEW_MAX_ORDER_OFFSET: usize = 168;
-pub const WORKER_ACCOUNT_NEW_MAX_INVENTORIES_OFFSET: usize = 176;
-pub const WORKER_ACCOUNT_POOL_TIME_OFFSET: usize = 184;
-pub const WORKER_ACCOUNT_LP_COUNTER_OFFSET: usize = 192;
-pub const WORKER_ACCOUNT_LAST_LP_TIME_OFFSET: usize = 200;
-pub const WORKER_ACCOUNT_POOL_SIZE_OFFSET: usize = 208;
-pub const WORKER_ACCOUNT_CPS_OFFSET: usize = 216;
-pub const WORKER_ACCOUNT_CPS_PX_OFFSET: usize = 224;
-pub const WORKER_ACCOUNT_CPS_IN_PS_OFFSET: usize = 232;
-pub const WORKER_ACCOUNT_PS_OFFSET: usize = 240;
-pub const WORKER_ACCOUNT_PS_PX_OFFSET: usize = 244;
-pub const WORKER_ACCOUNT_TASK_FEES_OFFSET: usize = 252;
-pub const WORKER_ACCOUNT_REST_OF_FEES_OFFSET: usize = 260;
-pub const WORKER_ACCOUNT_POOL_ID_OFFSET: usize = 268;
-pub const WORKER_ACCOUNT_START_OFFSET: usize = 272;
-pub const WORKER_ACCOUNT_EXPIRATION_OFFSET: usize = 280;
-pub const WORKER_ACCOUNT_POOL_CASHFLOW_OFFSET: usize = 288;
-pub const WORKER_ACCOUNT_SETTLEMENT_PX_OFFSET: usize = 296;
-pub const WORKER_ACCOUNT_POOL_DEPO_OFFSET: usize = 304;
-pub const WORKER_ACCOUNT_POOL_FEES_OFFSET: usize = 312;
-pub const WORKER_ACCOUNT_POOL_COUNTER_OFFSET: usize = 320;
-pub const WORKER_ACCOUNT_POOL_STRIKES_OFFSET: usize = 328;
-pub const WORKER_ACCOUNT_POOL_BOUNDS_OFFSET: usize = 1088;
-pub const WORKER_ACCOUNT_POOL_DISTRIB_OFFSET: usize = 1840;
-pub const WORKER_ACCOUNT_SVM_PARAMS_OFFSET: usize = 4880;
-pub const WORKER_ACCOUNT_MARGIN_VEGA_OFFSET: usize = 4920;
-pub const WORKER_ACCOUNT_MARGIN_VANNA_OFFSET: usize = 4928;
-pub const WORKER_ACCOUNT_MARGIN_VOLGA_OFFSET: usize = 4936;
-pub const WORKER_ACCOUNT_RANGE_LR_OFFSET: usize = 4944;
-pub const WORKER_ACCOUNT_W_LR_OFFSET: usize = 4952;
-pub const WORKER_ACCOUNT_MAX_LR_OFFSET: usize = 4960;
-pub const WORKER_ACCOUNT_MAX_PCT_POOL_OFFSET: usize = 4968;
-pub const WORKER_PERM_IMPACT_OFFSET: usize = 4976;
-pub const WORKER_ACCOUNT_SIZE: usize = 4984;
 pub const WORKER_ACCOUNT_TAG: u8 = 7;
 pub const WORKER_ACCOUNT_VERSION: u32 = 14;
 
@@ -125,6 +72,64 @@ pub struct WorkerAccount {
     pub perm_impact: f64,                   //  8 bytes, WORKER_PERM_IMPACT_OFFSET
 }
 
+devol_layout! {
+    WorkerAccount, WORKER_ACCOUNT_SIZE = 4984, test_worker_account_layout;
+    header: AccountHeader = 0,
+    id: u32 => WORKER_ACCOUNT_ID_OFFSET = 40,
+    state: WorkerState => WORKER_ACCOUNT_STATE_OFFSET = 44,
+    task_id: u32 => WORKER_ACCOUNT_TASK_ID_OFFSET = 48,
+    instr_id: u32 => WORKER_ACCOUNT_INSTR_ID_OFFSET = 52,
+    ops_counter: i64 => WORKER_ACCOUNT_OPS_COUNTER_OFFSET = 56,
+    first_time: i64 => WORKER_ACCOUNT_FIRST_TIME_OFFSET = 64,
+    duration: u32 => WORKER_ACCOUNT_DURATION_OFFSET = 72,
+    init_px: [u8; 8] => WORKER_ACCOUNT_INIT_PX_OFFSET = 76,
+    own_ps: u32 => WORKER_ACCOUNT_OWN_PS_OFFSET = 84,
+    width_factor: i64 => WORKER_ACCOUNT_WIDTH_FACTOR_OFFSET = 88,
+    fee_rate: i64 => WORKER_ACCOUNT_FEE_RATE_OFFSET = 96,
+    fee_ratio: i64 => WORKER_ACCOUNT_FEE_RATIO_OFFSET = 104,
+    inventories_ratio: i64 => WORKER_ACCOUNT_INVENTORIES_RATIO_OFFSET = 112,
+    fractions: u32 => WORKER_ACCOUNT_FRACTIONS_OFFSET = 120,
+    max_order: [u8; 8] => WORKER_ACCOUNT_MAX_ORDER_OFFSET = 124,
+    new_width_factor: [u8; 8] => WORKER_ACCOUNT_NEW_WIDTH_FACTOR_OFFSET = 132,
+    new_fee_rate: [u8; 8] => WORKER_ACCOUNT_NEW_FEE_RATE_OFFSET = 140,
+    new_fee_ratio: [u8; 8] => WORKER_ACCOUNT_NEW_FEE_RATIO_OFFSET = 148,
+    new_inventories_ratio: [u8; 8] => WORKER_ACCOUNT_NEW_INVENTORIES_RATIO_OFFSET = 156,
+    new_fractions: u32 => WORKER_ACCOUNT_NEW_FRACTIONS_OFFSET = 164,
+    new_max_order: i64 => WORKER_ACCOUNT_NEW_MAX_ORDER_OFFSET = 168,
+    new_max_inventories: i64 => WORKER_ACCOUNT_NEW_MAX_INVENTORIES_OFFSET = 176,
+    pool_time: u64 => WORKER_ACCOUNT_POOL_TIME_OFFSET = 184,
+    lp_counter: u64 => WORKER_ACCOUNT_LP_COUNTER_OFFSET = 192,
+    last_lp_time: u64 => WORKER_ACCOUNT_LAST_LP_TIME_OFFSET = 200,
+    pool_size: i64 => WORKER_ACCOUNT_POOL_SIZE_OFFSET = 208,
+    cps: i64 => WORKER_ACCOUNT_CPS_OFFSET = 216,
+    cps_px: i64 => WORKER_ACCOUNT_CPS_PX_OFFSET = 224,
+    cps_in_ps: i64 => WORKER_ACCOUNT_CPS_IN_PS_OFFSET = 232,
+    ps: u32 => WORKER_ACCOUNT_PS_OFFSET = 240,
+    ps_px: [u8; 8] => WORKER_ACCOUNT_PS_PX_OFFSET = 244,
+    task_fees: [u8; 8] => WORKER_ACCOUNT_TASK_FEES_OFFSET = 252,
+    rest_of_fees: [u8; 8] => WORKER_ACCOUNT_REST_OF_FEES_OFFSET = 260,
+    pool_id: u32 => WORKER_ACCOUNT_POOL_ID_OFFSET = 268,
+    start: i64 => WORKER_ACCOUNT_START_OFFSET = 272,
+    expiration: i64 => WORKER_ACCOUNT_EXPIRATION_OFFSET = 280,
+    pool_cashflow: i64 => WORKER_ACCOUNT_POOL_CASHFLOW_OFFSET = 288,
+    settlement_px: u64 => WORKER_ACCOUNT_SETTLEMENT_PX_OFFSET = 296,
+    pool_depo: i64 => WORKER_ACCOUNT_POOL_DEPO_OFFSET = 304,
+    pool_fees: u64 => WORKER_ACCOUNT_POOL_FEES_OFFSET = 312,
+    pool_counter: u64 => WORKER_ACCOUNT_POOL_COUNTER_OFFSET = 320,
+    pool_strikes: [i64; BUCKETS_COUNT] => WORKER_ACCOUNT_POOL_STRIKES_OFFSET = 328,
+    pool_bounds: [i64; BOUNDS_COUNT] => WORKER_ACCOUNT_POOL_BOUNDS_OFFSET = 1088,
+    pool_distrib: [Band; BUCKETS_COUNT] => WORKER_ACCOUNT_POOL_DISTRIB_OFFSET = 1840,
+    svm_params: SvmParams => WORKER_ACCOUNT_SVM_PARAMS_OFFSET = 4880,
+    margin_vega: i64 => WORKER_ACCOUNT_MARGIN_VEGA_OFFSET = 4920,
+    margin_vanna: i64 => WORKER_ACCOUNT_MARGIN_VANNA_OFFSET = 4928,
+    margin_volga: i64 => WORKER_ACCOUNT_MARGIN_VOLGA_OFFSET = 4936,
+    range_lr: i64 => WORKER_ACCOUNT_RANGE_LR_OFFSET = 4944,
+    w_lr: i64 => WORKER_ACCOUNT_W_LR_OFFSET = 4952,
+    max_lr: i64 => WORKER_ACCOUNT_MAX_LR_OFFSET = 4960,
+    max_pct_pool: i64 => WORKER_ACCOUNT_MAX_PCT_POOL_OFFSET = 4968,
+    perm_impact: f64 => WORKER_PERM_IMPACT_OFFSET = 4976,
+}
+
 impl WorkerAccount {
     #[inline(always)]
     pub fn get_init_px(&self) -> i64 { i64::from_ne_bytes(self.init_px) }
@@ -257,83 +262,3 @@ impl Default for WorkerAccount {
         }
     }
 }
-
-#[cfg(test)]
-mod tests {
-    use super::*;
-    use std::mem;
-
-    impl Band {
-        pub fn new(depo: i64, px: i64, loan: i64, prop: i64) -> Self {
-            Band { depo, px, loan, prop }
-        }
-    }
-
-    #[test]
-    fn test_worker_account_offsets() {
-
-        let account = WorkerAccount::default();
-
-        let base_ptr = &account as *const _ as usize;
-        // checking fields size
-        assert_eq!(&account.header.tag as *const _ as usize - base_ptr, WORKER_ACCOUNT_TAG_OFFSET);
-        assert_eq!(&account.header.version as *const _ as usize - base_ptr, WORKER_ACCOUNT_VERSION_OFFSET);
-        assert_eq!(&account.header.root as *const _ as usize - base_ptr, WORKER_ACCOUNT_ROOT_ADDRESS_OFFSET);
-        assert_eq!(&account.id as *const _ as usize - base_ptr, WORKER_ACCOUNT_ID_OFFSET);
-        assert_eq!(&account.state as *const _ as usize - base_ptr, WORKER_ACCOUNT_STATE_OFFSET);
-        assert_eq!(&account.task_id as *const _ as usize - base_ptr, WORKER_ACCOUNT_TASK_ID_OFFSET);
-        assert_eq!(&account.instr_id as *const _ as usize - base_ptr, WORKER_ACCOUNT_INSTR_ID_OFFSET);
-        assert_eq!(&account.ops_counter as *const _ as usize - base_ptr, WORKER_ACCOUNT_OPS_COUNTER_OFFSET);
-        assert_eq!(&account.first_time as *const _ as usize - base_ptr, WORKER_ACCOUNT_FIRST_TIME_OFFSET);
-        assert_eq!(&account.duration as *const _ as usize - base_ptr, WORKER_ACCOUNT_DURATION_OFFSET);
-        assert_eq!(&account.init_px as *const _ as usize - base_ptr, WORKER_ACCOUNT_INIT_PX_OFFSET);
-        assert_eq!(&account.own_ps as *const _ as usize - base_ptr, WORKER_ACCOUNT_OWN_PS_OFFSET);
-        assert_eq!(&account.width_factor as *const _ as usize - base_ptr, WORKER_ACCOUNT_WIDTH_FACTOR_OFFSET);
-        assert_eq!(&account.fee_rate as *const _ as usize - base_ptr, WORKER_ACCOUNT_FEE_RATE_OFFSET);
-        assert_eq!(&account.fee_ratio as *const _ as usize - base_ptr, WORKER_ACCOUNT_FEE_RATIO_OFFSET);
-        assert_eq!(&account.inventories_ratio as *const _ as usize - base_ptr, WORKER_ACCOUNT_INVENTORIES_RATIO_OFFSET);
-        assert_eq!(&account.fractions as *const _ as usize - base_ptr, WORKER_ACCOUNT_FRACTIONS_OFFSET);
-        assert_eq!(&account.max_order as *const _ as usize - base_ptr, WORKER_ACCOUNT_MAX_ORDER_OFFSET);
-        assert_eq!(&account.new_width_factor as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_WIDTH_FACTOR_OFFSET);
-        assert_eq!(&account.new_fee_rate as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_FEE_RATE_OFFSET);
-        assert_eq!(&account.new_fee_ratio as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_FEE_RATIO_OFFSET);
-        assert_eq!(&account.new_inventories_ratio as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_INVENTORIES_RATIO_OFFSET);
-        assert_eq!(&account.new_fractions as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_FRACTIONS_OFFSET);
-        assert_eq!(&account.new_max_order as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_MAX_ORDER_OFFSET);
-        assert_eq!(&account.new_max_inventories as *const _ as usize - base_ptr, WORKER_ACCOUNT_NEW_MAX_INVENTORIES_OFFSET);
-        assert_eq!(&account.pool_time as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_TIME_OFFSET);
-        assert_eq!(&account.lp_counter as *const _ as usize - base_ptr, WORKER_ACCOUNT_LP_COUNTER_OFFSET);
-        assert_eq!(&account.last_lp_time as *const _ as usize - base_ptr, WORKER_ACCOUNT_LAST_LP_TIME_OFFSET);
-        assert_eq!(&account.pool_size as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_SIZE_OFFSET);
-        assert_eq!(&account.cps as *const _ as usize - base_ptr, WORKER_ACCOUNT_CPS_OFFSET);
-        assert_eq!(&account.cps_px as *const _ as usize - base_ptr, WORKER_ACCOUNT_CPS_PX_OFFSET);
-        assert_eq!(&account.cps_in_ps as *const _ as usize - base_ptr, WORKER_ACCOUNT_CPS_IN_PS_OFFSET);
-        assert_eq!(&account.ps as *const _ as usize - base_ptr, WORKER_ACCOUNT_PS_OFFSET);
-        assert_eq!(&account.ps_px as *const _ as usize - base_ptr, WORKER_ACCOUNT_PS_PX_OFFSET);
-        assert_eq!(&account.task_fees as *const _ as usize - base_ptr, WORKER_ACCOUNT_TASK_FEES_OFFSET);
-        assert_eq!(&account.rest_of_fees as *const _ as usize - base_ptr, WORKER_ACCOUNT_REST_OF_FEES_OFFSET);
-        assert_eq!(&account.pool_id as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_ID_OFFSET);
-        assert_eq!(&account.start as *const _ as usize - base_ptr, WORKER_ACCOUNT_START_OFFSET);
-        assert_eq!(&account.expiration as *const _ as usize - base_ptr, WORKER_ACCOUNT_EXPIRATION_OFFSET);
-        assert_eq!(&account.pool_cashflow as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_CASHFLOW_OFFSET);
-        assert_eq!(&account.settlement_px as *const _ as usize - base_ptr, WORKER_ACCOUNT_SETTLEMENT_PX_OFFSET);
-        assert_eq!(&account.pool_depo as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_DEPO_OFFSET);
-        assert_eq!(&account.pool_fees as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_FEES_OFFSET);
-        assert_eq!(&account.pool_counter as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_COUNTER_OFFSET);
-        assert_eq!(&account.pool_strikes as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_STRIKES_OFFSET);
-        assert_eq!(&account.pool_bounds as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_BOUNDS_OFFSET);
-        assert_eq!(&account.pool_distrib as *const _ as usize - base_ptr, WORKER_ACCOUNT_POOL_DISTRIB_OFFSET);
-        assert_eq!(&account.svm_params as *const _ as usize - base_ptr, WORKER_ACCOUNT_SVM_PARAMS_OFFSET);
-        assert_eq!(&account.margin_vega as *const _ as usize - base_ptr, WORKER_ACCOUNT_MARGIN_VEGA_OFFSET);
-        assert_eq!(&account.margin_vanna as *const _ as usize - base_ptr, WORKER_ACCOUNT_MARGIN_VANNA_OFFSET);
-        assert_eq!(&account.margin_volga as *const _ as usize - base_ptr, WORKER_ACCOUNT_MARGIN_VOLGA_OFFSET);
-        assert_eq!(&account.range_lr as *const _ as usize - base_ptr, WORKER_ACCOUNT_RANGE_LR_OFFSET);
-        assert_eq!(&account.w_lr as *const _ as usize - base_ptr, WORKER_ACCOUNT_W_LR_OFFSET);
-        assert_eq!(&account.max_lr as *const _ as usize - base_ptr, WORKER_ACCOUNT_MAX_LR_OFFSET);
-        assert_eq!(&account.max_pct_pool as *const _ as usize - base_ptr, WORKER_ACCOUNT_MAX_PCT_POOL_OFFSET);
-        assert_eq!(&account.perm_impact as *const _ as usize - base_ptr, WORKER_PERM_IMPACT_OFFSET);
-
-        // checking total size
-        assert_eq!(mem::size_of::<WorkerAccount>(), WORKER_ACCOUNT_SIZE);
-    }
-}
@@ -1,5 +1,7 @@
 use std::fmt;
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
 #[derive(PartialEq, PartialOrd, Copy, Clone, Debug, Serialize, Deserialize)]
 #[repr(u32)]
@@ -10,8 +12,26 @@ pub enum WorkerState {
     Active = 4,
 }
 
+impl DevolParse for WorkerState {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u32(offset)? {
+            1 => Ok(WorkerState::Unassigned),
+            2 => Ok(WorkerState::Assigned),
+            3 => Ok(WorkerState::Inactive),
+            4 => Ok(WorkerState::Active),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for WorkerState {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u32(offset, *self as u32)
+    }
+}
+
 impl fmt::Display for WorkerState {
     fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
         write!(f, "{}", format!("{:?}", self).to_lowercase())
     }
-}
\ No newline at end of file
+}
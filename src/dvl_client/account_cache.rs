@@ -0,0 +1,183 @@
+use std::any::{Any, TypeId};
+use std::collections::HashMap;
+use std::sync::atomic::{AtomicU64, Ordering};
+use std::sync::Mutex;
+use std::time::{Duration, Instant};
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::devol_regular_account::DevolRegularAccount;
+use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+use crate::accounts::mints::mints_account::MintsAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+
+pub const DEFAULT_CACHE_TTL: Duration = Duration::from_secs(60);
+
+/// Platform accounts which change rarely enough to be kept by `DvlAccountCache`.
+pub trait DvlCacheable: DevolRegularAccount + Copy + Send + Sync + 'static {}
+
+impl DvlCacheable for RootAccount {}
+impl DvlCacheable for InstrumentsAccount {}
+impl DvlCacheable for OraclesAccount {}
+impl DvlCacheable for MintsAccount {}
+impl DvlCacheable for AllWorkersAccount {}
+
+#[derive(Clone, Debug)]
+pub struct DvlCacheConfig {
+    default_ttl: Duration,
+    ttls: HashMap<TypeId, Duration>,
+    max_slot_lag: Option<u64>,
+}
+
+impl DvlCacheConfig {
+    /// Keeps every cacheable account for `default_ttl`.
+    pub fn new(default_ttl: Duration) -> Self {
+        Self { default_ttl, ttls: HashMap::new(), max_slot_lag: None }
+    }
+
+    /// Overrides the time to live of `T`, e.g. a shorter one for `OraclesAccount`.
+    pub fn ttl<T: DvlCacheable>(mut self, ttl: Duration) -> Self {
+        self.ttls.insert(TypeId::of::<T>(), ttl);
+        self
+    }
+
+    /// Drops an account fetched more than `slots` slots before the newest slot seen by the cache.
+    pub fn max_slot_lag(mut self, slots: u64) -> Self {
+        self.max_slot_lag = Some(slots);
+        self
+    }
+
+    pub fn ttl_of<T: DvlCacheable>(&self) -> Duration {
+        self.ttls.get(&TypeId::of::<T>()).copied().unwrap_or(self.default_ttl)
+    }
+}
+
+impl Default for DvlCacheConfig {
+    fn default() -> Self {
+        Self::new(DEFAULT_CACHE_TTL)
+    }
+}
+
+struct CacheEntry {
+    account: Box<dyn Any + Send + Sync>,
+    slot: u64,
+    fetched_at: Instant,
+}
+
+/// Copies of the platform accounts with the slots they were fetched at. An account is returned
+/// until its TTL runs out, it falls behind by more than `max_slot_lag` slots or it is invalidated.
+pub struct DvlAccountCache {
+    config: DvlCacheConfig,
+    entries: Mutex<HashMap<TypeId, CacheEntry>>,
+    latest_slot: AtomicU64,
+}
+
+impl DvlAccountCache {
+    pub fn new(config: DvlCacheConfig) -> Self {
+        Self { config, entries: Mutex::new(HashMap::new()), latest_slot: AtomicU64::new(0) }
+    }
+
+    pub fn config(&self) -> &DvlCacheConfig {
+        &self.config
+    }
+
+    pub fn get<T: DvlCacheable>(&self) -> Option<Box<T>> {
+        let mut entries = self.entries.lock().unwrap();
+        let entry = entries.get(&TypeId::of::<T>())?;
+        if !self.is_fresh::<T>(entry, Instant::now()) {
+            entries.remove(&TypeId::of::<T>());
+            return None;
+        }
+        entry.account.downcast_ref::<T>().map(|account| Box::new(*account))
+    }
+
+    /// Stores `account` read at `slot`, unless a copy from a later slot is already cached.
+    pub fn insert<T: DvlCacheable>(&self, account: &T, slot: u64) {
+        self.observe_slot(slot);
+        let mut entries = self.entries.lock().unwrap();
+        if entries.get(&TypeId::of::<T>()).is_some_and(|entry| entry.slot > slot) {
+            return;
+        }
+        entries.insert(TypeId::of::<T>(), CacheEntry {
+            account: Box::new(*account),
+            slot,
+            fetched_at: Instant::now(),
+        });
+    }
+
+    pub fn invalidate<T: DvlCacheable>(&self) {
+        self.entries.lock().unwrap().remove(&TypeId::of::<T>());
+    }
+
+    pub fn invalidate_all(&self) {
+        self.entries.lock().unwrap().clear();
+    }
+
+    /// Drops the accounts fetched before `slot`, e.g. after a transaction changing the platform
+    /// configuration landed at `slot`.
+    pub fn invalidate_before_slot(&self, slot: u64) {
+        self.observe_slot(slot);
+        self.entries.lock().unwrap().retain(|_, entry| entry.slot >= slot);
+    }
+
+    /// Moves the newest known slot forward, which ages the cached accounts by `max_slot_lag`.
+    pub fn observe_slot(&self, slot: u64) {
+        self.latest_slot.fetch_max(slot, Ordering::Relaxed);
+    }
+
+    pub fn latest_slot(&self) -> u64 {
+        self.latest_slot.load(Ordering::Relaxed)
+    }
+
+    fn is_fresh<T: DvlCacheable>(&self, entry: &CacheEntry, now: Instant) -> bool {
+        let in_time = now.duration_since(entry.fetched_at) < self.config.ttl_of::<T>();
+        let in_slots = self.config.max_slot_lag
+            .is_none_or(|lag| self.latest_slot().saturating_sub(entry.slot) <= lag);
+        in_time && in_slots
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    fn root(clients_count: u32) -> Box<RootAccount> {
+        DevolAccountBuilder::<RootAccount>::new().with(|root| root.clients_count = clients_count).account()
+    }
+
+    #[test]
+    fn test_account_cache_ttl_and_invalidate() {
+        let cache = DvlAccountCache::new(DvlCacheConfig::default().ttl::<OraclesAccount>(Duration::ZERO));
+        assert!(cache.get::<RootAccount>().is_none());
+
+        cache.insert(&*root(3), 10);
+        assert_eq!(cache.get::<RootAccount>().unwrap().clients_count, 3);
+        assert!(cache.get::<MintsAccount>().is_none());
+
+        cache.insert(&*DevolAccountBuilder::<OraclesAccount>::new().account(), 10);
+        assert!(cache.get::<OraclesAccount>().is_none());
+
+        cache.invalidate::<RootAccount>();
+        assert!(cache.get::<RootAccount>().is_none());
+    }
+
+    #[test]
+    fn test_account_cache_slots() {
+        let cache = DvlAccountCache::new(DvlCacheConfig::default().max_slot_lag(100));
+        cache.insert(&*root(1), 50);
+        cache.insert(&*root(2), 40);
+        assert_eq!(cache.get::<RootAccount>().unwrap().clients_count, 1);
+
+        cache.observe_slot(150);
+        assert!(cache.get::<RootAccount>().is_some());
+        cache.observe_slot(151);
+        assert!(cache.get::<RootAccount>().is_none());
+
+        cache.insert(&*root(4), 200);
+        cache.insert(&*DevolAccountBuilder::<MintsAccount>::new().account(), 210);
+        cache.invalidate_before_slot(205);
+        assert!(cache.get::<RootAccount>().is_none());
+        assert!(cache.get::<MintsAccount>().is_some());
+        assert_eq!(cache.latest_slot(), 210);
+    }
+}
@@ -0,0 +1,550 @@
+use std::collections::{HashMap, VecDeque};
+use std::fs;
+use std::io::ErrorKind;
+use std::path::{Path, PathBuf};
+use std::sync::atomic::{AtomicU64, AtomicUsize, Ordering};
+use std::sync::{Mutex, RwLock};
+use async_trait::async_trait;
+use solana_account_decoder::{UiAccountEncoding, UiDataSliceConfig};
+use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+use solana_client::nonblocking::rpc_client::RpcClient;
+use solana_client::rpc_config::{RpcAccountInfoConfig, RpcProgramAccountsConfig, RpcSimulateTransactionConfig};
+use solana_client::rpc_filter::RpcFilterType;
+use solana_client::rpc_custom_error::JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED;
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_program::hash::{hashv, Hash};
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use solana_sdk::account::{Account, AccountSharedData};
+use solana_sdk::commitment_config::CommitmentConfig;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::{self, Transaction};
+
+/// Where `DvlClient` reads accounts from and sends transactions to. Implemented for `RpcClient`,
+/// for accounts kept in memory (`MemoryAccountSource`) and for a directory of recorded account
+/// data (`FixtureDirAccountSource`).
+#[async_trait]
+pub trait AccountSource: Send + Sync {
+    fn commitment(&self) -> CommitmentConfig {
+        CommitmentConfig::default()
+    }
+
+    /// Account with the slot it was read at, `None` if it does not exist.
+    async fn get_account_with_slot(&self, public_key: &Pubkey) -> ClientResult<(u64, Option<Account>)>;
+
+    /// Accounts in the order of `public_keys` read in one request at `commitment`, with the slot
+    /// they were read at. Fails if the source has not reached `min_context_slot` yet.
+    async fn get_multiple_accounts_with_config(
+        &self,
+        public_keys: &[Pubkey],
+        commitment: CommitmentConfig,
+        min_context_slot: Option<u64>,
+    ) -> ClientResult<(u64, Vec<Option<Account>>)>;
+
+    /// Accounts in the order of `public_keys` with the slot they were read at.
+    async fn get_multiple_accounts_with_slot(&self, public_keys: &[Pubkey]) -> ClientResult<(u64, Vec<Option<Account>>)> {
+        self.get_multiple_accounts_with_config(public_keys, self.commitment(), None).await
+    }
+
+    /// Accounts of `program_id` passing all the `filters`, with only `data_slice` of their data if
+    /// it is set.
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+        data_slice: Option<UiDataSliceConfig>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>>;
+
+    async fn get_latest_blockhash(&self, commitment: CommitmentConfig) -> ClientResult<Hash>;
+
+    async fn send_and_confirm_transaction(
+        &self,
+        transaction: &Transaction,
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Signature>;
+
+    /// Outcome of each of the `signatures` in their order, `None` for the transactions which
+    /// have not reached `commitment` or are unknown to the source.
+    async fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<transaction::Result<()>>>>;
+
+    /// Runs the transaction without signature checks on the latest blockhash.
+    async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+        commitment: CommitmentConfig,
+    ) -> ClientResult<RpcSimulateTransactionResult>;
+
+    /// Account at `public_key`, failing like `RpcClient::get_account` if it does not exist.
+    async fn get_account(&self, public_key: &Pubkey) -> ClientResult<Account> {
+        self.get_account_with_slot(public_key).await?.1.ok_or_else(|| account_not_found(public_key))
+    }
+
+    /// The RPC client behind the source, if any, for the calls the trait does not cover.
+    fn as_rpc_client(&self) -> Option<&RpcClient> {
+        None
+    }
+}
+
+pub fn account_not_found(public_key: &Pubkey) -> ClientError {
+    RpcError::ForUser(format!("AccountNotFound: pubkey={}", public_key)).into()
+}
+
+/// Error of a node which is behind `min_context_slot`.
+pub fn min_context_slot_not_reached() -> ClientError {
+    RpcError::RpcResponseError {
+        code: JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+        message: "Minimum context slot has not been reached".to_string(),
+        data: RpcResponseErrorData::Empty,
+    }.into()
+}
+
+fn min_context_slot_reached(slot: u64, min_context_slot: Option<u64>) -> bool {
+    min_context_slot.is_none_or(|min_context_slot| slot >= min_context_slot)
+}
+
+/// `getProgramAccounts` applied to local accounts, for the sources without a node.
+fn select_program_account(
+    program_id: &Pubkey,
+    filters: &[RpcFilterType],
+    data_slice: Option<UiDataSliceConfig>,
+    account: &Account,
+) -> Option<Account> {
+    let shared = AccountSharedData::from(account.clone());
+    if account.owner != *program_id || !filters.iter().all(|filter| filter.allows(&shared)) {
+        return None;
+    }
+    let mut account = account.clone();
+    if let Some(UiDataSliceConfig { offset, length }) = data_slice {
+        let start = offset.min(account.data.len());
+        let end = start.saturating_add(length).min(account.data.len());
+        account.data = account.data[start..end].to_vec();
+    }
+    Some(account)
+}
+
+#[async_trait]
+impl AccountSource for RpcClient {
+    fn commitment(&self) -> CommitmentConfig {
+        RpcClient::commitment(self)
+    }
+
+    async fn get_account_with_slot(&self, public_key: &Pubkey) -> ClientResult<(u64, Option<Account>)> {
+        let response = self.get_account_with_commitment(public_key, RpcClient::commitment(self)).await?;
+        Ok((response.context.slot, response.value))
+    }
+
+    async fn get_multiple_accounts_with_config(
+        &self,
+        public_keys: &[Pubkey],
+        commitment: CommitmentConfig,
+        min_context_slot: Option<u64>,
+    ) -> ClientResult<(u64, Vec<Option<Account>>)> {
+        let config = RpcAccountInfoConfig {
+            encoding: Some(UiAccountEncoding::Base64),
+            commitment: Some(commitment),
+            min_context_slot,
+            ..RpcAccountInfoConfig::default()
+        };
+        let response = RpcClient::get_multiple_accounts_with_config(self, public_keys, config).await?;
+        Ok((response.context.slot, response.value))
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+        data_slice: Option<UiDataSliceConfig>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let config = RpcProgramAccountsConfig {
+            filters: Some(filters),
+            account_config: RpcAccountInfoConfig {
+                encoding: Some(UiAccountEncoding::Base64),
+                data_slice,
+                commitment: Some(RpcClient::commitment(self)),
+                min_context_slot: None,
+            },
+            with_context: None,
+        };
+        self.get_program_accounts_with_config(program_id, config).await
+    }
+
+    async fn get_latest_blockhash(&self, commitment: CommitmentConfig) -> ClientResult<Hash> {
+        Ok(self.get_latest_blockhash_with_commitment(commitment).await?.0)
+    }
+
+    async fn send_and_confirm_transaction(
+        &self,
+        transaction: &Transaction,
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Signature> {
+        self.send_and_confirm_transaction_with_spinner_and_commitment(transaction, commitment).await
+    }
+
+    async fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+        commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<transaction::Result<()>>>> {
+        // Without the history the node only knows the recent signatures, an older attempt would
+        // look like it never landed.
+        let statuses = RpcClient::get_signature_statuses_with_history(self, signatures).await?.value;
+        Ok(statuses.into_iter()
+            .map(|status| status.filter(|status| status.satisfies_commitment(commitment)).map(|status| status.status))
+            .collect())
+    }
+
+    async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+        commitment: CommitmentConfig,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        let config = RpcSimulateTransactionConfig {
+            sig_verify: false,
+            replace_recent_blockhash: true,
+            commitment: Some(commitment),
+            ..RpcSimulateTransactionConfig::default()
+        };
+        Ok(self.simulate_transaction_with_config(transaction, config).await?.value)
+    }
+
+    async fn get_account(&self, public_key: &Pubkey) -> ClientResult<Account> {
+        RpcClient::get_account(self, public_key).await
+    }
+
+    fn as_rpc_client(&self) -> Option<&RpcClient> {
+        Some(self)
+    }
+}
+
+/// Accounts kept in memory for offline tests. Sent transactions are recorded rather than
+/// executed, each of them moves the slot forward and so the blockhash, unless a send fails with
+/// an error queued by `fail_next_send`. A recorded transaction has landed for
+/// `get_signature_statuses`, also when `fail_next_confirmation` made its send fail, unless the
+/// lookup fails with an error queued by `fail_next_status_lookup`. Simulations
+/// return the result set with `set_simulation`, a success without logs by default. Account reads
+/// are counted by `read_requests`.
+#[derive(Default)]
+pub struct MemoryAccountSource {
+    accounts: RwLock<HashMap<Pubkey, Account>>,
+    slot: AtomicU64,
+    reads: AtomicUsize,
+    sent: Mutex<Vec<Transaction>>,
+    /// Errors of the next sends, with whether the transaction is recorded before failing.
+    send_errors: Mutex<VecDeque<(ClientError, bool)>>,
+    status_errors: Mutex<VecDeque<ClientError>>,
+    simulated: Mutex<Vec<Transaction>>,
+    simulation: Mutex<Option<RpcSimulateTransactionResult>>,
+}
+
+impl MemoryAccountSource {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn insert(&self, public_key: Pubkey, account: Account) {
+        self.accounts.write().unwrap().insert(public_key, account);
+    }
+
+    pub fn remove(&self, public_key: &Pubkey) -> Option<Account> {
+        self.accounts.write().unwrap().remove(public_key)
+    }
+
+    pub fn slot(&self) -> u64 {
+        self.slot.load(Ordering::Relaxed)
+    }
+
+    pub fn set_slot(&self, slot: u64) {
+        self.slot.store(slot, Ordering::Relaxed);
+    }
+
+    /// Number of account, multiple accounts and program accounts requests served so far.
+    pub fn read_requests(&self) -> usize {
+        self.reads.load(Ordering::Relaxed)
+    }
+
+    pub fn sent_transactions(&self) -> Vec<Transaction> {
+        self.sent.lock().unwrap().clone()
+    }
+
+    /// The next send returns `error` without recording the transaction.
+    pub fn fail_next_send(&self, error: ClientError) {
+        self.send_errors.lock().unwrap().push_back((error, false));
+    }
+
+    /// The next send records the transaction, then returns `error` as if it was not confirmed.
+    pub fn fail_next_confirmation(&self, error: ClientError) {
+        self.send_errors.lock().unwrap().push_back((error, true));
+    }
+
+    /// The next `get_signature_statuses` returns `error`.
+    pub fn fail_next_status_lookup(&self, error: ClientError) {
+        self.status_errors.lock().unwrap().push_back(error);
+    }
+
+    pub fn simulated_transactions(&self) -> Vec<Transaction> {
+        self.simulated.lock().unwrap().clone()
+    }
+
+    pub fn set_simulation(&self, result: RpcSimulateTransactionResult) {
+        *self.simulation.lock().unwrap() = Some(result);
+    }
+}
+
+#[cfg(any(test, feature = "fixtures"))]
+impl MemoryAccountSource {
+    pub fn insert_fixture(&self, fixture: &crate::fixtures::account_fixture::AccountFixture) {
+        self.insert(fixture.key, fixture.to_account());
+    }
+}
+
+#[async_trait]
+impl AccountSource for MemoryAccountSource {
+    async fn get_account_with_slot(&self, public_key: &Pubkey) -> ClientResult<(u64, Option<Account>)> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        Ok((self.slot(), self.accounts.read().unwrap().get(public_key).cloned()))
+    }
+
+    async fn get_multiple_accounts_with_config(
+        &self,
+        public_keys: &[Pubkey],
+        _commitment: CommitmentConfig,
+        min_context_slot: Option<u64>,
+    ) -> ClientResult<(u64, Vec<Option<Account>>)> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        let accounts = self.accounts.read().unwrap();
+        if !min_context_slot_reached(self.slot(), min_context_slot) {
+            return Err(min_context_slot_not_reached());
+        }
+        Ok((self.slot(), public_keys.iter().map(|public_key| accounts.get(public_key).cloned()).collect()))
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+        data_slice: Option<UiDataSliceConfig>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        self.reads.fetch_add(1, Ordering::Relaxed);
+        let accounts = self.accounts.read().unwrap();
+        Ok(accounts.iter()
+            .filter_map(|(public_key, account)| {
+                select_program_account(program_id, &filters, data_slice, account).map(|account| (*public_key, account))
+            })
+            .collect())
+    }
+
+    async fn get_latest_blockhash(&self, _commitment: CommitmentConfig) -> ClientResult<Hash> {
+        Ok(hashv(&[&self.slot().to_le_bytes()]))
+    }
+
+    async fn send_and_confirm_transaction(
+        &self,
+        transaction: &Transaction,
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<Signature> {
+        let error = self.send_errors.lock().unwrap().pop_front();
+        if let Some((error, false)) = error {
+            return Err(error);
+        }
+        self.sent.lock().unwrap().push(transaction.clone());
+        self.slot.fetch_add(1, Ordering::Relaxed);
+        match error {
+            Some((error, _)) => Err(error),
+            None => Ok(transaction.signatures.first().copied().unwrap_or_default()),
+        }
+    }
+
+    async fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<transaction::Result<()>>>> {
+        if let Some(error) = self.status_errors.lock().unwrap().pop_front() {
+            return Err(error);
+        }
+        let sent = self.sent.lock().unwrap();
+        Ok(signatures.iter()
+            .map(|signature| sent.iter().any(|transaction| transaction.signatures.first() == Some(signature)).then_some(Ok(())))
+            .collect())
+    }
+
+    async fn simulate_transaction(
+        &self,
+        transaction: &Transaction,
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        self.simulated.lock().unwrap().push(transaction.clone());
+        Ok(self.simulation.lock().unwrap().clone().unwrap_or(RpcSimulateTransactionResult {
+            err: None,
+            logs: Some(Vec::new()),
+            accounts: None,
+            units_consumed: None,
+            return_data: None,
+            inner_instructions: None,
+        }))
+    }
+}
+
+/// Read-only source over a directory of `<public key>.bin` files holding raw account data, e.g.
+/// written by `solana account <public key> --output-file <public key>.bin` or `record`. Every
+/// account is owned by `owner`, files are read on each request.
+pub struct FixtureDirAccountSource {
+    dir: PathBuf,
+    owner: Pubkey,
+}
+
+impl FixtureDirAccountSource {
+    pub fn new(dir: impl Into<PathBuf>, owner: Pubkey) -> Self {
+        Self { dir: dir.into(), owner }
+    }
+
+    pub fn dir(&self) -> &Path {
+        &self.dir
+    }
+
+    /// Stores the data of an account, e.g. one just read from RPC, to replay it later.
+    pub fn record(&self, public_key: &Pubkey, account: &Account) -> std::io::Result<()> {
+        fs::create_dir_all(&self.dir)?;
+        fs::write(self.path(public_key), &account.data)
+    }
+
+    fn path(&self, public_key: &Pubkey) -> PathBuf {
+        self.dir.join(format!("{}.bin", public_key))
+    }
+
+    fn read(&self, public_key: &Pubkey) -> std::io::Result<Option<Account>> {
+        match fs::read(self.path(public_key)) {
+            Ok(data) => Ok(Some(Account {
+                lamports: Rent::default().minimum_balance(data.len()),
+                data,
+                owner: self.owner,
+                executable: false,
+                rent_epoch: 0,
+            })),
+            Err(e) if e.kind() == ErrorKind::NotFound => Ok(None),
+            Err(e) => Err(e),
+        }
+    }
+}
+
+#[async_trait]
+impl AccountSource for FixtureDirAccountSource {
+    async fn get_account_with_slot(&self, public_key: &Pubkey) -> ClientResult<(u64, Option<Account>)> {
+        Ok((0, self.read(public_key)?))
+    }
+
+    async fn get_multiple_accounts_with_config(
+        &self,
+        public_keys: &[Pubkey],
+        _commitment: CommitmentConfig,
+        min_context_slot: Option<u64>,
+    ) -> ClientResult<(u64, Vec<Option<Account>>)> {
+        if !min_context_slot_reached(0, min_context_slot) {
+            return Err(min_context_slot_not_reached());
+        }
+        let accounts = public_keys.iter().map(|public_key| self.read(public_key)).collect::<std::io::Result<_>>()?;
+        Ok((0, accounts))
+    }
+
+    async fn get_program_accounts(
+        &self,
+        program_id: &Pubkey,
+        filters: Vec<RpcFilterType>,
+        data_slice: Option<UiDataSliceConfig>,
+    ) -> ClientResult<Vec<(Pubkey, Account)>> {
+        let mut accounts = Vec::new();
+        for entry in fs::read_dir(&self.dir)? {
+            let path = entry?.path();
+            let public_key = match (path.extension(), path.file_stem().and_then(|stem| stem.to_str())) {
+                (Some(extension), Some(stem)) if extension == "bin" => stem.parse::<Pubkey>().ok(),
+                _ => None,
+            };
+            let Some(public_key) = public_key else {
+                continue;
+            };
+            if let Some(account) = self.read(&public_key)? {
+                if let Some(account) = select_program_account(program_id, &filters, data_slice, &account) {
+                    accounts.push((public_key, account));
+                }
+            }
+        }
+        Ok(accounts)
+    }
+
+    async fn get_latest_blockhash(&self, _commitment: CommitmentConfig) -> ClientResult<Hash> {
+        Ok(Hash::default())
+    }
+
+    async fn send_and_confirm_transaction(
+        &self,
+        _transaction: &Transaction,
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<Signature> {
+        Err(ClientErrorKind::Custom(format!("Fixture directory {} is read-only", self.dir.display())).into())
+    }
+
+    async fn get_signature_statuses(
+        &self,
+        signatures: &[Signature],
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<Vec<Option<transaction::Result<()>>>> {
+        Ok(vec![None; signatures.len()])
+    }
+
+    async fn simulate_transaction(
+        &self,
+        _transaction: &Transaction,
+        _commitment: CommitmentConfig,
+    ) -> ClientResult<RpcSimulateTransactionResult> {
+        Err(ClientErrorKind::Custom(format!("Fixture directory {} cannot run transactions", self.dir.display())).into())
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::accounts::mints::mints_account::MintsAccount;
+    use crate::accounts::root::root_account::RootAccount;
+    use crate::dvl_client::dvl_client::DvlClient;
+    use crate::fixtures::devol_account_builder::{DevolAccountBuilder, FIXTURE_PROGRAM_ID};
+    use crate::generate_pda::dvl_generate_pda;
+    use std::sync::Arc;
+
+    #[tokio::test]
+    async fn test_memory_account_source() {
+        let (source, client) = setup_memory_client();
+        let root_key = client.root_pda.key;
+        let mints_key = Pubkey::new_unique();
+        source.insert_fixture(&DevolAccountBuilder::<RootAccount>::new()
+            .key(root_key)
+            .with(|root| root.mints_address = mints_key)
+            .build());
+        assert!(client.get_account::<MintsAccount>(()).await.is_err());
+
+        source.insert_fixture(&DevolAccountBuilder::<MintsAccount>::new().key(mints_key).root(root_key).build());
+        assert!(client.get_account::<MintsAccount>(()).await.is_ok());
+        let accounts = client.get_accounts_batch(&[mints_key, Pubkey::new_unique()]).await.unwrap();
+        assert!(accounts[0].is_some() && accounts[1].is_none());
+    }
+
+    #[tokio::test]
+    async fn test_fixture_dir_account_source() {
+        let dir = std::env::temp_dir().join(format!("dvl-fixtures-{}", Pubkey::new_unique()));
+        let source = FixtureDirAccountSource::new(&dir, FIXTURE_PROGRAM_ID);
+        let admin = Pubkey::new_unique();
+        let root_key = dvl_generate_pda(&admin, "rt1", &FIXTURE_PROGRAM_ID).key;
+        let root = DevolAccountBuilder::<RootAccount>::new().key(root_key).build();
+        source.record(&root_key, &root.to_account()).unwrap();
+
+        let client = DvlClient::with_source(Arc::new(source), 1, admin, FIXTURE_PROGRAM_ID);
+        assert!(client.get_account::<RootAccount>(()).await.is_ok());
+        assert!(client.source.get_account(&Pubkey::new_unique()).await.is_err());
+        fs::remove_dir_all(dir).unwrap();
+    }
+}
@@ -0,0 +1,223 @@
+use std::collections::HashMap;
+use std::error::Error;
+use std::sync::RwLock;
+use solana_program::pubkey::Pubkey;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::client::client_account::client_account::{CLIENT_ACCOUNT_ID_OFFSET, CLIENT_ACCOUNT_SIGN_METHOD_OFFSET, CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET, ClientAccount};
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
+use crate::accounts::client::client_account::client_sign_method::ClientSignMethod;
+use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_client::dvl_client_error::DvlClientError;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Field a client account is looked up by.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum DvlClientLookup {
+    /// `signer_address`, the key signing for clients with `ClientSignMethod::SignerAccount`.
+    Signer(Pubkey),
+    /// `id`, the client number the logs refer to.
+    Id(u32),
+}
+
+impl DvlClientLookup {
+    fn offset(&self) -> usize {
+        match self {
+            DvlClientLookup::Signer(_) => CLIENT_ACCOUNT_SIGNER_ADDRESS_OFFSET,
+            DvlClientLookup::Id(_) => CLIENT_ACCOUNT_ID_OFFSET,
+        }
+    }
+
+    fn bytes(&self) -> Vec<u8> {
+        match self {
+            DvlClientLookup::Signer(signer) => signer.to_bytes().to_vec(),
+            DvlClientLookup::Id(id) => id.to_le_bytes().to_vec(),
+        }
+    }
+
+    fn matches(&self, account: &ClientAccountSnapshot) -> bool {
+        match self {
+            DvlClientLookup::Signer(signer) => is_signed_by(account, signer),
+            DvlClientLookup::Id(id) => account.id == *id,
+        }
+    }
+}
+
+/// The wallet clients keep a stale or default `signer_address`, which does not sign for them.
+fn is_signed_by(account: &ClientAccountSnapshot, signer: &Pubkey) -> bool {
+    account.sign_method == ClientSignMethod::SignerAccount && account.signer_address == *signer
+}
+
+#[derive(Default)]
+struct ClientIndexEntries {
+    by_signer: HashMap<Pubkey, Pubkey>,
+    by_id: HashMap<u32, Pubkey>,
+}
+
+/// Addresses of the client accounts by signer and id, so that a repeated lookup reads one account
+/// instead of scanning the program accounts. An entry is dropped when the account no longer
+/// matches it, e.g. after the client changed its signer.
+#[derive(Default)]
+pub struct DvlClientIndex {
+    entries: RwLock<ClientIndexEntries>,
+}
+
+impl DvlClientIndex {
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    pub fn get(&self, lookup: &DvlClientLookup) -> Option<Pubkey> {
+        let entries = self.entries.read().unwrap();
+        match lookup {
+            DvlClientLookup::Signer(signer) => entries.by_signer.get(signer).copied(),
+            DvlClientLookup::Id(id) => entries.by_id.get(id).copied(),
+        }
+    }
+
+    pub fn insert(&self, public_key: Pubkey, account: &ClientAccountSnapshot) {
+        let mut entries = self.entries.write().unwrap();
+        if account.sign_method == ClientSignMethod::SignerAccount && account.signer_address != Pubkey::default() {
+            entries.by_signer.insert(account.signer_address, public_key);
+        }
+        entries.by_id.insert(account.id, public_key);
+    }
+
+    pub fn remove(&self, public_key: &Pubkey) {
+        let mut entries = self.entries.write().unwrap();
+        entries.by_signer.retain(|_, key| key != public_key);
+        entries.by_id.retain(|_, key| key != public_key);
+    }
+
+    /// Number of the indexed client accounts.
+    pub fn len(&self) -> usize {
+        self.entries.read().unwrap().by_id.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.len() == 0
+    }
+
+    pub fn clear(&self) {
+        *self.entries.write().unwrap() = ClientIndexEntries::default();
+    }
+}
+
+/// Client account matching `lookup`, from the index of the client if it has one and the entry is
+/// still valid, otherwise from a scan. Several matching accounts are an error rather than an
+/// arbitrary pick.
+pub(crate) async fn find_client(
+    client: &DvlClient,
+    lookup: DvlClientLookup,
+) -> Result<(Pubkey, Box<ClientAccountSnapshot>), Box<dyn Error>> {
+    if lookup == DvlClientLookup::Signer(Pubkey::default()) {
+        return Err(Box::new(DvlClientError::Request("the default public key is not a client signer".to_string())));
+    }
+    if let Some(index) = &client.client_index {
+        if let Some(public_key) = index.get(&lookup) {
+            if let Ok(account) = ClientAccount::read_by_public_key(client, &public_key).await {
+                if lookup.matches(&account) {
+                    return Ok((public_key, account));
+                }
+            }
+            index.remove(&public_key);
+        }
+    }
+    let mut scan = client.scan::<ClientAccount>().memcmp(lookup.offset(), &lookup.bytes());
+    if let DvlClientLookup::Signer(_) = lookup {
+        scan = scan.memcmp(CLIENT_ACCOUNT_SIGN_METHOD_OFFSET, &(ClientSignMethod::SignerAccount as u32).to_le_bytes());
+    }
+    let scanned = scan.fetch().await?;
+    if scanned.len() > 1 {
+        return Err(Box::new(DvlClientError::Ambiguous(scanned.keys().copied().collect())));
+    }
+    let (public_key, account) = scanned.into_iter().next()
+        .ok_or_else(|| DvlError::new_with_account(AccountTag::Client, ContractError::AccountIsMissing))?;
+    let account = account?;
+    if let Some(index) = &client.client_index {
+        index.insert(public_key, &account);
+    }
+    Ok((public_key, account))
+}
+
+/// Indexes all the client accounts under the root with one scan.
+pub(crate) async fn index_clients(client: &DvlClient, index: &DvlClientIndex) -> Result<usize, Box<dyn Error>> {
+    let scanned = client.scan::<ClientAccount>().fetch().await?;
+    for (public_key, account) in scanned {
+        let account = account?;
+        index.insert(public_key, &account);
+    }
+    Ok(index.len())
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+
+    fn client_account(client: &DvlClient, key: Pubkey, id: u32, signer: Pubkey) -> ClientAccountBuilder {
+        ClientAccountBuilder::new().key(key).root(client.root_pda.key).id(id).signer_address(signer)
+            .with(|account| account.sign_method = ClientSignMethod::SignerAccount)
+    }
+
+    #[tokio::test]
+    async fn test_find_client() {
+        let (source, client) = setup_memory_client();
+        let keys: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        let signers: Vec<Pubkey> = (0..3).map(|_| Pubkey::new_unique()).collect();
+        for i in 0..3 {
+            source.insert_fixture(&client_account(&client, keys[i], i as u32, signers[i]).build());
+        }
+
+        let (key, account) = client.find_client_by_signer(&signers[1]).await.unwrap();
+        assert_eq!((key, account.id), (keys[1], 1));
+        assert_eq!(client.find_client_by_id(2).await.unwrap().0, keys[2]);
+        assert!(client.find_client_by_id(3).await.is_err());
+    }
+
+    #[tokio::test]
+    async fn test_find_client_by_signer_checks() {
+        let (source, client) = setup_memory_client();
+        let client = client.with_client_index();
+        let wallet_signer = Pubkey::new_unique();
+        source.insert_fixture(&client_account(&client, Pubkey::new_unique(), 0, Pubkey::default())
+            .with(|account| account.sign_method = ClientSignMethod::Wallet)
+            .build());
+        source.insert_fixture(&client_account(&client, Pubkey::new_unique(), 1, wallet_signer)
+            .with(|account| account.sign_method = ClientSignMethod::Wallet)
+            .build());
+        assert!(matches!(client.find_client_by_signer(&Pubkey::default()).await, Err(DvlClientError::Request(_))));
+        assert!(matches!(client.find_client_by_signer(&wallet_signer).await, Err(DvlClientError::Contract(..))));
+        client.index_clients().await.unwrap();
+        let index = client.client_index.as_ref().unwrap();
+        assert_eq!(index.get(&DvlClientLookup::Signer(wallet_signer)), None);
+        assert_eq!(index.get(&DvlClientLookup::Signer(Pubkey::default())), None);
+
+        let signer = Pubkey::new_unique();
+        source.insert_fixture(&client_account(&client, Pubkey::new_unique(), 2, signer).build());
+        source.insert_fixture(&client_account(&client, Pubkey::new_unique(), 3, signer).build());
+        let result = client.find_client_by_signer(&signer).await;
+        assert!(matches!(result, Err(DvlClientError::Ambiguous(keys)) if keys.len() == 2));
+    }
+
+    #[tokio::test]
+    async fn test_client_index() {
+        let (source, client) = setup_memory_client();
+        let client = client.with_client_index();
+        let key = Pubkey::new_unique();
+        let signer = Pubkey::new_unique();
+        source.insert_fixture(&client_account(&client, key, 7, signer).build());
+        source.insert_fixture(&client_account(&client, Pubkey::new_unique(), 8, Pubkey::new_unique()).build());
+        assert_eq!(client.index_clients().await.unwrap(), 2);
+        let index = client.client_index.as_ref().unwrap();
+        assert_eq!(index.get(&DvlClientLookup::Signer(signer)), Some(key));
+
+        let new_signer = Pubkey::new_unique();
+        source.insert_fixture(&client_account(&client, key, 7, new_signer).build());
+        assert!(client.find_client_by_signer(&signer).await.is_err());
+        assert_eq!(index.get(&DvlClientLookup::Signer(signer)), None);
+        assert_eq!(client.find_client_by_signer(&new_signer).await.unwrap().0, key);
+        assert_eq!(index.get(&DvlClientLookup::Id(7)), Some(key));
+    }
+}
@@ -0,0 +1,95 @@
+use std::future::Future;
+use std::sync::Arc;
+use solana_client::rpc_client::RpcClient;
+use solana_program::instruction::Instruction;
+use solana_program::pubkey::Pubkey;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::dvl_client::account_source::AccountSource;
+use crate::dvl_client::dvl_client::{DvlClient, DvlSendTransactionParams};
+use crate::dvl_client::dvl_client_error::DvlClientResult;
+use crate::dvl_client::simulation::DvlSimulation;
+use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
+
+/// Synchronous counterpart of `DvlClient` for scripts and services without an async runtime. The
+/// calls run the `DvlClient` ones on the runtime of the blocking `RpcClient`, so the accounts are
+/// read and decoded by the same code. Like `RpcClient`, it must not be used from a current-thread
+/// runtime.
+pub struct DvlBlockingClient {
+    rpc_client: RpcClient,
+    client: DvlClient,
+}
+
+impl DvlBlockingClient {
+    pub fn new(rpc_client: RpcClient, int_seed: usize, admin_public_key: Pubkey, program_id: Pubkey) -> Self {
+        let source: Arc<dyn AccountSource> = rpc_client.get_inner_client().clone();
+        let client = DvlClient::with_source(source, int_seed, admin_public_key, program_id);
+        Self { rpc_client, client }
+    }
+
+    pub fn rpc_client(&self) -> &RpcClient {
+        &self.rpc_client
+    }
+
+    /// The async client sharing the RPC connection.
+    pub fn client(&self) -> &DvlClient {
+        &self.client
+    }
+
+    pub fn get_account<'a, T: DvlReadable>(
+        &self,
+        params: T::DvlReadParams<'a>,
+    ) -> DvlClientResult<Box<T::DvlReadOutput>> {
+        self.block_on(self.client.get_account::<T>(params))
+    }
+
+    pub fn get_account_by_public_key<T: DvlReadable + Send>(
+        &self,
+        public_key: &Pubkey,
+    ) -> DvlClientResult<Box<T::DvlReadOutput>> {
+        self.block_on(self.client.get_account_by_public_key::<T>(public_key))
+    }
+
+    pub fn account_public_key<'a, T: DvlReadable>(
+        &self,
+        params: T::DvlReadParams<'a>,
+    ) -> DvlClientResult<Box<Pubkey>> {
+        self.block_on(self.client.account_public_key::<T>(params))
+    }
+
+    pub fn as_transaction_instruction<I: AsTransactionInstruction>(
+        &self,
+        instruction: &I,
+        signer: &Pubkey,
+        transaction_params: I::DvlTransactionInstructionParams,
+    ) -> DvlClientResult<Box<Instruction>> {
+        Ok(self.block_on(instruction.as_transaction_instruction(&self.client, signer, transaction_params))?)
+    }
+
+    pub fn send_transaction(&self, params: DvlSendTransactionParams<'_>) -> DvlClientResult<String> {
+        self.block_on(self.client.send_transaction(params))
+    }
+
+    pub fn simulate_transaction(&self, params: &DvlSendTransactionParams<'_>) -> DvlClientResult<DvlSimulation> {
+        self.block_on(self.client.simulate_transaction(params))
+    }
+
+    fn block_on<F: Future>(&self, future: F) -> F::Output {
+        tokio::task::block_in_place(|| self.rpc_client.runtime().block_on(future))
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::root::root_account::RootAccount;
+    use crate::tests::tests::setup_devol_client;
+
+    #[test]
+    fn test_blocking_client_public_keys() {
+        let async_client = setup_devol_client();
+        let rpc_client = RpcClient::new(async_client.rpc_client().unwrap().url());
+        let client = DvlBlockingClient::new(rpc_client, async_client.int_seed, async_client.admin_public_key, async_client.program_id);
+        assert_eq!(*client.account_public_key::<RootAccount>(()).unwrap(), async_client.root_pda.key);
+        assert_eq!(client.client().root_pda.key, async_client.root_pda.key);
+    }
+}
@@ -1,20 +1,43 @@
+use std::collections::HashMap;
 use std::error::Error;
-use solana_client::client_error::ClientErrorKind;
+use std::sync::{Arc, RwLock};
+use std::time::{Duration, Instant};
+use log::{error, log, warn, Level};
+use solana_client::client_error::ClientError;
 use solana_client::nonblocking::rpc_client::RpcClient;
-use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
 use solana_program::hash::Hash;
-use solana_program::instruction::{Instruction, InstructionError};
+use solana_program::instruction::Instruction;
+use solana_program::message::Message;
 use solana_program::pubkey::Pubkey;
-use solana_sdk::commitment_config::{CommitmentConfig, CommitmentLevel};
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
 use solana_sdk::compute_budget::ComputeBudgetInstruction;
-use solana_sdk::transaction::{Transaction, TransactionError};
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::Transaction;
 use crate::account_readers::dvl_readable::{DvlReadable};
+use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
 use crate::accounts::devol_account::DevolAccount;
+use crate::accounts::devol_any_account::{decode_any, DevolAnyAccount};
+use crate::dvl_client::account_source::AccountSource;
+use crate::dvl_client::dvl_client_error::{DvlClientError, DvlClientResult};
+use crate::dvl_client::client_index::{DvlClientIndex, DvlClientLookup, find_client, index_clients};
+use crate::dvl_client::account_cache::{DvlAccountCache, DvlCacheable, DvlCacheConfig};
+use crate::dvl_client::retry_policy::RetryPolicy;
+use crate::dvl_client::platform_accounts::DvlPlatformAccounts;
+use crate::dvl_client::scan::DvlScan;
+use crate::dvl_client::platform_snapshot::DvlPlatformSnapshot;
+use crate::dvl_client::simulation::{DvlSimulation, SIMULATION_COMPUTE_UNIT_LIMIT};
+use crate::dvl_client::snapshot::{DvlSnapshot, DvlSnapshotConfig};
+use crate::dvl_client::transaction_builder::DvlTransactionBuilder;
+use crate::dvl_client::subscription::{DvlSubscription, websocket_url_for};
 use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
 use crate::generate_pda::{dvl_generate_pda, PDA};
 
 pub struct DvlClient {
-    pub rpc_client: RpcClient,
+    pub source: Arc<dyn AccountSource>,
     pub int_seed: usize,
     pub admin_public_key: Pubkey,
     pub program_id: Pubkey,
@@ -23,15 +46,32 @@ pub struct DvlClient {
     pub root_seed: String,
     pub oracle_seed: String,
     pub root_pda: PDA,
+    pub cache: Option<DvlAccountCache>,
+    pub websocket_url: Option<String>,
+    pub client_index: Option<DvlClientIndex>,
+    pub retry_policy: RetryPolicy,
+    /// Trade log addresses by the client account PDA, scanned for once, see `TradeLogAccount`.
+    pub(crate) trade_logs: RwLock<HashMap<Pubkey, Pubkey>>,
 }
 
 impl DvlClient {
     pub fn new(client: RpcClient, int_seed: usize, admin_public_key: Pubkey, program_id: Pubkey) -> Self {
+        Self::with_source(Arc::new(client), int_seed, admin_public_key, program_id)
+    }
+
+    /// Client reading accounts from `source` instead of an `RpcClient`, e.g. a
+    /// `MemoryAccountSource` in tests.
+    pub fn with_source(
+        source: Arc<dyn AccountSource>,
+        int_seed: usize,
+        admin_public_key: Pubkey,
+        program_id: Pubkey,
+    ) -> Self {
         let root_seed = format!("rt{}", int_seed);
         let root_pda = dvl_generate_pda(&admin_public_key, &root_seed, &program_id);
 
         Self {
-            rpc_client: client,
+            source,
             int_seed,
             admin_public_key,
             program_id,
@@ -40,100 +80,403 @@ impl DvlClient {
             root_seed,
             oracle_seed: format!("orcl{}", int_seed),
             root_pda,
+            cache: None,
+            websocket_url: None,
+            client_index: None,
+            retry_policy: RetryPolicy::none(),
+            trade_logs: RwLock::new(HashMap::new()),
+        }
+    }
+
+    /// Retries the reads, the simulations and the sent transactions by `policy`, the
+    /// `DvlSendTransactionParams` may set their own. Each public method is retried as a whole,
+    /// the requests it makes are not retried on their own.
+    pub fn with_retry_policy(mut self, policy: RetryPolicy) -> Self {
+        self.retry_policy = policy;
+        self
+    }
+
+    /// Remembers the client accounts found by `find_client_by_signer` and `find_client_by_id`.
+    pub fn with_client_index(mut self) -> Self {
+        self.client_index = Some(DvlClientIndex::new());
+        self
+    }
+
+    /// Pubsub endpoint for `subscribe`, derived from the RPC URL when not set.
+    pub fn with_websocket_url(mut self, websocket_url: impl Into<String>) -> Self {
+        self.websocket_url = Some(websocket_url.into());
+        self
+    }
+
+    /// Keeps the platform accounts (`DvlCacheable`) between reads instead of fetching them on
+    /// every `get_account` and `account_public_key` call.
+    pub fn with_cache(mut self, config: DvlCacheConfig) -> Self {
+        self.cache = Some(DvlAccountCache::new(config));
+        self
+    }
+
+    pub fn invalidate<T: DvlCacheable>(&self) {
+        if let Some(cache) = &self.cache {
+            cache.invalidate::<T>();
+        }
+    }
+
+    pub fn cached_account<T: DvlCacheable>(&self) -> Option<Box<T>> {
+        self.cache.as_ref()?.get::<T>()
+    }
+
+    pub fn cache_account<T: DvlCacheable>(&self, account: &T, slot: u64) {
+        if let Some(cache) = &self.cache {
+            cache.insert(account, slot);
+        }
+    }
+
+    /// Reads a cacheable account and stores it with the slot it was read at.
+    pub(crate) async fn read_cacheable<T: DvlCacheable>(&self, public_key: &Pubkey) -> Result<Box<T>, Box<dyn Error>> {
+        if self.cache.is_none() {
+            let mut rpc_data = self.source.get_account(public_key).await?;
+            return T::from_account(public_key, &mut rpc_data, &self.root_pda.key, &self.program_id);
         }
+        let (slot, rpc_data) = self.source.get_account_with_slot(public_key).await?;
+        let mut rpc_data = rpc_data.ok_or_else(|| {
+            DvlError::new_with_account(AccountTag::from_u8(T::expected_tag()), ContractError::AccountIsMissing)
+        })?;
+        let account = T::from_account(public_key, &mut rpc_data, &self.root_pda.key, &self.program_id)?;
+        self.cache_account(&*account, slot);
+        Ok(account)
+    }
+
+    /// The RPC client if the client was created with one.
+    pub fn rpc_client(&self) -> Option<&RpcClient> {
+        self.source.as_rpc_client()
     }
 
     pub async fn get_account<'a, T: DvlReadable>(
         &self,
         params: T::DvlReadParams<'a>,
-    ) -> Result<Box<T>, Box<dyn Error>> {
-        T::read(self, &params).await
+    ) -> DvlClientResult<Box<T::DvlReadOutput>> {
+        let params = &params;
+        self.retry_policy.run(|| async move { Ok(T::read(self, params).await?) }).await
+    }
+
+    pub async fn get_account_by_public_key<T: DvlReadable + Send>(
+        &self,
+        public_key: &Pubkey,
+    ) -> DvlClientResult<Box<T::DvlReadOutput>> {
+        self.retry_policy.run(|| async move { Ok(T::read_by_public_key(self, public_key).await?) }).await
+    }
+
+    /// Pushes the decoded states of the account, e.g. a worker or a pools log, as it changes.
+    pub async fn subscribe<'a, T: DvlReadable>(
+        &self,
+        params: T::DvlReadParams<'a>,
+    ) -> DvlClientResult<DvlSubscription<'_, T>> {
+        let public_key = T::get_public_key(self, &params).await?;
+        self.subscribe_by_public_key::<T>(&public_key)
     }
 
-    pub async fn get_account_by_public_key<T: DvlReadable + DevolAccount + Copy + Send>(
+    pub fn subscribe_by_public_key<T: DvlReadable>(
         &self,
         public_key: &Pubkey,
-    ) -> Result<Box<T>, Box<dyn Error>> {
-        T::read_by_public_key(self, public_key).await
+    ) -> DvlClientResult<DvlSubscription<'_, T>> {
+        let websocket_url = match (&self.websocket_url, self.rpc_client()) {
+            (Some(websocket_url), _) => websocket_url.clone(),
+            (None, Some(rpc_client)) => websocket_url_for(&rpc_client.url()),
+            (None, None) => {
+                let message = "Websocket URL is required to subscribe without an RPC client";
+                return Err(DvlClientError::Request(message.to_string()));
+            }
+        };
+        Ok(DvlSubscription::new(self, websocket_url, *public_key))
     }
 
     pub async fn account_public_key<'a, T: DvlReadable>(
         &self,
         params: T::DvlReadParams<'a>,
-    ) -> Result<Box<Pubkey>, Box<dyn Error>> {
-        T::get_public_key(self, &params).await
+    ) -> DvlClientResult<Box<Pubkey>> {
+        let params = &params;
+        self.retry_policy.run(|| async move { Ok(T::get_public_key(self, params).await?) }).await
+    }
+
+    /// Fetches the accounts with `getMultipleAccounts`, up to `MAX_MULTIPLE_ACCOUNTS` keys per
+    /// request, and decodes each one by its tag. Accounts which do not exist are `None`.
+    pub async fn get_accounts_batch(
+        &self,
+        public_keys: &[Pubkey],
+    ) -> DvlClientResult<Vec<Option<DevolAnyAccount>>> {
+        Ok(self.get_accounts_batch_with_slot(public_keys).await?.1)
+    }
+
+    /// `get_accounts_batch` along with the lowest slot the requests were served at.
+    pub async fn get_accounts_batch_with_slot(
+        &self,
+        public_keys: &[Pubkey],
+    ) -> DvlClientResult<(u64, Vec<Option<DevolAnyAccount>>)> {
+        self.retry_policy.run(|| self.fetch_accounts_batch_with_slot(public_keys)).await
+    }
+
+    /// `get_accounts_batch_with_slot` without the retries, for the methods retried as a whole.
+    pub(crate) async fn fetch_accounts_batch_with_slot(
+        &self,
+        public_keys: &[Pubkey],
+    ) -> DvlClientResult<(u64, Vec<Option<DevolAnyAccount>>)> {
+        let mut slot = u64::MAX;
+        let mut accounts = Vec::with_capacity(public_keys.len());
+        for keys in public_keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            let (response_slot, rpc_accounts) = self.source.get_multiple_accounts_with_slot(keys).await?;
+            slot = slot.min(response_slot);
+            for rpc_data in rpc_accounts {
+                accounts.push(rpc_data.map(|rpc_data| self.decode_batch_account(&rpc_data)).transpose()?);
+            }
+        }
+        Ok((if accounts.is_empty() { 0 } else { slot }, accounts))
+    }
+
+    /// Reads the accounts in one request so that they show the same slot, e.g. a worker, its
+    /// traces, its pools log and a client account, see `DvlSnapshot`.
+    pub async fn snapshot(
+        &self,
+        public_keys: &[Pubkey],
+        config: DvlSnapshotConfig,
+    ) -> DvlClientResult<DvlSnapshot<'_>> {
+        self.retry_policy.run(|| async move { Ok(DvlSnapshot::read(self, public_keys, config).await?) }).await
+    }
+
+    /// Raw accounts of the whole platform, including every worker with its traces and logs, to be
+    /// saved with `DvlPlatformSnapshot::save` and replayed offline.
+    pub async fn platform_snapshot(&self) -> DvlClientResult<DvlPlatformSnapshot> {
+        self.retry_policy.run(|| async move { Ok(DvlPlatformSnapshot::fetch(self).await?) }).await
+    }
+
+    /// Client account with the `signer_address`, for the clients signing with a signer account.
+    pub async fn find_client_by_signer(
+        &self,
+        signer: &Pubkey,
+    ) -> DvlClientResult<(Pubkey, Box<ClientAccountSnapshot>)> {
+        let lookup = DvlClientLookup::Signer(*signer);
+        self.retry_policy.run(|| async move { Ok(find_client(self, lookup).await?) }).await
+    }
+
+    /// Client account with the `id` the logs refer to.
+    pub async fn find_client_by_id(&self, id: u32) -> DvlClientResult<(Pubkey, Box<ClientAccountSnapshot>)> {
+        let lookup = DvlClientLookup::Id(id);
+        self.retry_policy.run(|| async move { Ok(find_client(self, lookup).await?) }).await
+    }
+
+    /// Fills the client index with all the client accounts, returns the number of them.
+    pub async fn index_clients(&self) -> DvlClientResult<usize> {
+        let index = self.client_index.as_ref().ok_or_else(|| {
+            DvlClientError::Request("Client index is disabled, see `with_client_index`".to_string())
+        })?;
+        self.retry_policy.run(|| async move { Ok(index_clients(self, index).await?) }).await
+    }
+
+    /// All the accounts of type `T` under the root, e.g. `scan::<ClientAccount>()`, see `DvlScan`.
+    pub fn scan<T: DvlReadable + DevolAccount>(&self) -> DvlScan<'_, T> {
+        DvlScan::new(self)
+    }
+
+    /// Decodes an account of `get_accounts_batch`, checking its owner and root like the readers.
+    pub fn decode_batch_account(&self, rpc_data: &Account) -> Result<DevolAnyAccount, DvlError> {
+        let account = decode_any(&rpc_data.data)?;
+        if rpc_data.owner != self.program_id {
+            return Err(DvlError::new_with_account(account.tag(), ContractError::AccountOwner));
+        }
+        if account.tag() != AccountTag::Root && account.header().root != self.root_pda.key {
+            return Err(DvlError::new_with_account(account.tag(), ContractError::RootAddress));
+        }
+        Ok(account)
+    }
+
+    /// Root, oracles, instruments, workers and mints accounts in up to two `getMultipleAccounts`
+    /// requests, none if they are all cached.
+    pub async fn get_platform_accounts(&self) -> DvlClientResult<DvlPlatformAccounts> {
+        self.retry_policy.run(|| async move { Ok(DvlPlatformAccounts::fetch(self).await?) }).await
+    }
+
+    /// `get_platform_accounts` along with the accounts of `extra_keys`, e.g. PDAs, read in the
+    /// first platform batch rather than in requests of their own.
+    pub async fn get_platform_accounts_with(
+        &self,
+        extra_keys: &[Pubkey],
+    ) -> DvlClientResult<(DvlPlatformAccounts, Vec<Option<DevolAnyAccount>>)> {
+        self.retry_policy.run(|| async move { Ok(DvlPlatformAccounts::fetch_with(self, extra_keys).await?) }).await
+    }
+
+    /// Runs the transaction of `params` with `simulateTransaction`, unsigned, and returns the
+    /// DeVol error with the failing instruction, the logs and the compute units it used. With
+    /// `compute_budget_margin` the transaction runs with the highest compute unit limit.
+    pub async fn simulate_transaction(
+        &self,
+        params: &DvlSendTransactionParams<'_>,
+    ) -> DvlClientResult<DvlSimulation> {
+        let compute_budget = match params.compute_budget_margin {
+            Some(_) => Some(SIMULATION_COMPUTE_UNIT_LIMIT),
+            None => params.compute_budget,
+        };
+        let transaction = Transaction::new_with_payer(&params.instructions_with_budget(compute_budget), Some(params.signer));
+        let commitment = params.commitment_config.unwrap_or_else(|| self.source.commitment());
+        let transaction = &transaction;
+        let result = self.retry_policy
+            .run(|| async move { Ok(self.source.simulate_transaction(transaction, commitment).await?) })
+            .await?;
+        Ok(DvlSimulation::from_result(result, &transaction.message, &self.program_id))
     }
 
     pub async fn send_transaction(
         &self,
         mut params: DvlSendTransactionParams<'_>,
-    ) -> Result<String, Box<dyn Error>> {
-        if let Some(max_units) = params.compute_budget {
-            let compute_budget_instruction = ComputeBudgetInstruction::set_compute_unit_limit(max_units);
-            params.instructions.push(compute_budget_instruction);
+    ) -> DvlClientResult<String> {
+        if let Some(margin) = params.compute_budget_margin {
+            let simulation = self.simulate_transaction(&params).await?;
+            if let Some(error) = simulation.error {
+                return Err(DvlClientError::from_simulation_error(error, simulation.logs));
+            }
+            if let Some(compute_budget) = simulation.compute_budget(margin) {
+                params.compute_budget = Some(compute_budget);
+            }
+        }
+        let instructions = params.instructions_with_budget(params.compute_budget);
+        self.send_instructions(&params, instructions).await
+    }
+
+    /// Sends the transactions of the builder one after another with the signer and the retry
+    /// settings of `params`, returns their signatures. The instructions and the compute budget
+    /// come from the builder, so `params` must not set them.
+    pub async fn send_transactions(
+        &self,
+        builder: &DvlTransactionBuilder,
+        params: DvlSendTransactionParams<'_>,
+    ) -> DvlClientResult<Vec<String>> {
+        let budget_set = params.compute_budget.is_some()
+            || params.compute_unit_price.is_some()
+            || params.compute_budget_margin.is_some();
+        if !params.instructions.is_empty() || budget_set {
+            let message = "Instructions and compute budget of the transactions are set on the builder";
+            return Err(DvlClientError::Request(message.to_string()));
         }
-        if let Some(compute_unit_price) = params.compute_unit_price {
-            let priority_fee_instruction = ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price);
-            params.instructions.push(priority_fee_instruction);
+        if builder.payer() != params.signer {
+            let message = format!("Builder payer {} is not the signer {}", builder.payer(), params.signer);
+            return Err(DvlClientError::Request(message));
         }
+        let mut signatures = Vec::new();
+        for instructions in builder.build()? {
+            signatures.push(self.send_instructions(&params, instructions).await?);
+        }
+        Ok(signatures)
+    }
 
-        let commitment = params.commitment_config.unwrap_or_else(|| self.rpc_client.commitment());
-        let retries = params.max_retries.unwrap_or(1);
-        let delay = params.retry_delay.unwrap_or(1);
+    /// Sends the instructions until an attempt lands. A failed attempt may still land later, so
+    /// before each retry and before giving up the statuses of all the attempts are checked and the
+    /// signature of the one which landed is returned. The transaction is signed again only after
+    /// its blockhash expired, and with `client_account_guard` only if the `ops_counter` of the
+    /// client account did not change since the first attempt.
+    async fn send_instructions(
+        &self,
+        params: &DvlSendTransactionParams<'_>,
+        instructions: Vec<Instruction>,
+    ) -> DvlClientResult<String> {
+        let commitment = params.commitment_config.unwrap_or_else(|| self.source.commitment());
+        let policy = params.retry_policy(&self.retry_policy);
         let log_prefix = params.log_prefix.unwrap_or("");
-        let verbose = params.verbose.unwrap_or(false);
-
-        for i in 0..retries {
-            let latest_blockhash = self.rpc_client.get_latest_blockhash_with_commitment(
-                CommitmentConfig{commitment: CommitmentLevel::Finalized}
-            ).await.map_err(|e| Box::new(e) as Box<dyn Error>)?.0;
-            let mut new_transaction = Transaction::new_with_payer(&params.instructions, Some(&params.signer));
-            (params.signer_fn)(&mut new_transaction, latest_blockhash)?;
+        let level = if params.verbose.unwrap_or(false) { Level::Info } else { Level::Debug };
+        let ops_counter = match params.client_account_guard {
+            Some(public_key) => Some(self.ops_counter(&public_key).await?),
+            None => None,
+        };
 
-            let send_result = self.rpc_client.send_and_confirm_transaction_with_spinner_and_commitment(&new_transaction, commitment).await;
-
-            match send_result {
+        let start = Instant::now();
+        let mut attempt = 1;
+        let mut transaction = self.sign_transaction(params, &instructions, commitment, &policy).await?;
+        let mut signatures = vec![transaction.signatures[0]];
+        loop {
+            let error = match self.source.send_and_confirm_transaction(&transaction, commitment).await {
                 Ok(signature) => {
-                    if verbose {
-                        println!("[INFO] {}Transaction sent successfully with signature: {}", log_prefix, signature);
-                    }
+                    log!(level, "{}Transaction sent with signature {}", log_prefix, signature);
                     return Ok(signature.to_string());
                 }
-                Err(e) if i < retries - 1 => {
-                    if verbose {
-                        println!("[INFO] {}Retrying transaction due to error: {:?}", log_prefix, e);
-                    }
-                    match e.kind {
-                        ClientErrorKind::RpcError(RpcError::RpcResponseError { code, message, data }) => {
-                            if let RpcResponseErrorData::SendTransactionPreflightFailure(sim_result) = data {
-                                if let Some(TransactionError::InstructionError(index, InstructionError::Custom(error_code))) = sim_result.err {
-                                    let dvl_error = DvlError::from_code(error_code);
-                                    println!("[ERROR] {}Custom error in instruction at index {}: {}", log_prefix, index, dvl_error);
-                                    return Err(Box::new(dvl_error));
-                                } else {
-                                    println!("[ERROR] {}Transaction simulation failed with error: {:?}", log_prefix, sim_result.err);
-                                }
-                            } else {
-                                println!("[ERROR] {}RPC error: {}, code: {}, message: {}", log_prefix, code, message, data);
-                            }
-                        }
-                        _ => println!("[ERROR] {}Unexpected error kind: {:?}", log_prefix, e.kind),
-                    }
-                    tokio::time::sleep(tokio::time::Duration::from_secs(delay)).await;
+                Err(e) => DvlClientError::from_transaction(e, &transaction.message, &self.program_id),
+            };
+            // The preflight rejected the only attempt, nothing was sent.
+            let rejected = attempt == 1 && matches!(error, DvlClientError::Contract(..) | DvlClientError::Simulation { .. });
+            if !rejected {
+                if let Some(signature) = self.landed_signature(&signatures, &transaction.message, commitment, &policy).await? {
+                    log!(level, "{}Transaction landed with signature {} of attempt {}", log_prefix, signature, attempt);
+                    return Ok(signature.to_string());
                 }
-                Err(e) => {
-                    if verbose {
-                        eprintln!("[ERROR] {}Failed to send transaction after {} attempts: {}", log_prefix, retries, e);
+            }
+            let Some(delay) = policy.retry_delay(attempt, start.elapsed(), &error) else {
+                error!("{}Failed to send transaction after {} attempts: {}", log_prefix, attempt, error);
+                return Err(error);
+            };
+            warn!("{}Retrying transaction in {:?} after attempt {} failed: {}", log_prefix, delay, attempt, error);
+            tokio::time::sleep(delay).await;
+            if let Some(signature) = self.landed_signature(&signatures, &transaction.message, commitment, &policy).await? {
+                log!(level, "{}Transaction landed with signature {} before attempt {}", log_prefix, signature, attempt + 1);
+                return Ok(signature.to_string());
+            }
+            if let DvlClientError::BlockhashExpired = error {
+                if let (Some(public_key), Some(ops_counter)) = (params.client_account_guard, ops_counter) {
+                    if self.ops_counter(&public_key).await? != ops_counter {
+                        error!("{}Client account {} changed since the first attempt, not sending again", log_prefix, public_key);
+                        return Err(DvlClientError::Unconfirmed(signatures));
                     }
-                    return Err(Box::new(e));
                 }
+                transaction = self.sign_transaction(params, &instructions, commitment, &policy).await?;
+                signatures.push(transaction.signatures[0]);
             }
+            attempt += 1;
         }
+    }
 
-        if verbose {
-            eprintln!("[ERROR] {}All retry attempts failed", log_prefix);
+    /// Signature of the attempt of `message` which reached `commitment`, at least confirmed, the
+    /// error it failed with if it did not succeed. A processed attempt may still be dropped with
+    /// its fork, so it does not count. The attempts were sent, so when their statuses cannot be
+    /// checked the transaction is `Unconfirmed` rather than failed with the lookup error.
+    async fn landed_signature(
+        &self,
+        signatures: &[Signature],
+        message: &Message,
+        commitment: CommitmentConfig,
+        policy: &RetryPolicy,
+    ) -> DvlClientResult<Option<Signature>> {
+        let commitment = match commitment.is_at_least_confirmed() {
+            true => commitment,
+            false => CommitmentConfig::confirmed(),
+        };
+        let statuses = policy
+            .run(|| async move { Ok(self.source.get_signature_statuses(signatures, commitment).await?) })
+            .await
+            .map_err(|error| {
+                error!("Failed to check the statuses of {} sent attempts: {}", signatures.len(), error);
+                DvlClientError::Unconfirmed(signatures.to_vec())
+            })?;
+        match signatures.iter().zip(statuses).find_map(|(signature, status)| Some((*signature, status?))) {
+            Some((signature, Ok(()))) => Ok(Some(signature)),
+            Some((_, Err(error))) => Err(DvlClientError::from_transaction(ClientError::from(error), message, &self.program_id)),
+            None => Ok(None),
         }
-        Err(Box::new(std::io::Error::new(std::io::ErrorKind::Other, "All retry attempts failed")))
+    }
+
+    async fn ops_counter(&self, client_account: &Pubkey) -> DvlClientResult<i64> {
+        Ok(self.get_account_by_public_key::<ClientAccount>(client_account).await?.ops_counter)
+    }
+
+    /// The transaction signed on the latest blockhash. It is sent again as it is after errors
+    /// other than an expired blockhash.
+    async fn sign_transaction(
+        &self,
+        params: &DvlSendTransactionParams<'_>,
+        instructions: &[Instruction],
+        commitment: CommitmentConfig,
+        policy: &RetryPolicy,
+    ) -> DvlClientResult<Transaction> {
+        let latest_blockhash = policy
+            .run(|| async move { Ok(self.source.get_latest_blockhash(commitment).await?) })
+            .await?;
+        let mut transaction = Transaction::new_with_payer(instructions, Some(params.signer));
+        (params.signer_fn)(&mut transaction, latest_blockhash).map_err(|e| DvlClientError::Signing(e.to_string()))?;
+        Ok(transaction)
     }
 }
 
@@ -146,8 +489,144 @@ pub struct DvlSendTransactionParams<'a> {
     pub commitment_config: Option<CommitmentConfig>,
     pub compute_budget: Option<u32>,
     pub compute_unit_price: Option<u64>,
+    /// Sets `compute_budget` to the units of a simulation plus this percent of them, the
+    /// transaction is not sent if the simulation fails.
+    pub compute_budget_margin: Option<u32>,
+    /// Logs the sent transactions at the info level instead of debug, see the `log` crate.
     pub verbose: Option<bool>,
+    /// Prepended to the log records of the transaction.
     pub log_prefix: Option<&'a str>,
+    /// Attempts with `retry_delay` seconds between them, unless `retry_policy` is set.
     pub max_retries: Option<usize>,
     pub retry_delay: Option<u64>,
-}
\ No newline at end of file
+    /// Retries of this transaction, the policy of the client if neither this nor `max_retries`
+    /// and `retry_delay` are set.
+    pub retry_policy: Option<RetryPolicy>,
+    /// Client account whose `ops_counter` the transaction increments, e.g. of an option trade.
+    /// A transaction is not signed again on a new blockhash if the counter moved since the first
+    /// attempt, even when no attempt is known to the node.
+    pub client_account_guard: Option<Pubkey>,
+}
+
+impl<'a> DvlSendTransactionParams<'a> {
+    /// Sends `instructions` signed by `signer_fn` for `signer`, with the defaults of the client
+    /// for everything else.
+    pub fn new(instructions: Vec<Instruction>, signer: &'a Pubkey, signer_fn: SignerFunction) -> Self {
+        Self {
+            instructions,
+            signer,
+            signer_fn,
+            commitment_config: None,
+            compute_budget: None,
+            compute_unit_price: None,
+            compute_budget_margin: None,
+            verbose: None,
+            log_prefix: None,
+            max_retries: None,
+            retry_delay: None,
+            retry_policy: None,
+            client_account_guard: None,
+        }
+    }
+
+    fn retry_policy(&self, client_policy: &RetryPolicy) -> RetryPolicy {
+        if let Some(policy) = &self.retry_policy {
+            return policy.clone();
+        }
+        match (self.max_retries, self.retry_delay) {
+            (None, None) => client_policy.clone(),
+            (max_retries, retry_delay) => RetryPolicy::fixed(
+                max_retries.unwrap_or(1),
+                Duration::from_secs(retry_delay.unwrap_or(1)),
+            ),
+        }
+    }
+
+    /// The instructions followed by the compute budget ones.
+    fn instructions_with_budget(&self, compute_budget: Option<u32>) -> Vec<Instruction> {
+        let mut instructions = self.instructions.clone();
+        if let Some(max_units) = compute_budget {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(max_units));
+        }
+        if let Some(compute_unit_price) = self.compute_unit_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price));
+        }
+        instructions
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use solana_client::rpc_request::RpcError;
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+
+    #[tokio::test]
+    async fn test_send_does_not_repeat_landed_attempts() {
+        let (source, client) = setup_memory_client();
+        let client = client.with_retry_policy(RetryPolicy::fixed(3, Duration::ZERO));
+        let client_key = Pubkey::new_unique();
+        let client_account = |ops_counter| {
+            ClientAccountBuilder::new().key(client_key).root(client.root_pda.key).with(|account| account.set_ops_counter(ops_counter)).build()
+        };
+        source.insert_fixture(&client_account(1));
+        let payer = Arc::new(Keypair::new());
+        let payer_key = payer.pubkey();
+        let signatures = Arc::new(AtomicUsize::new(0));
+        let params = |trade_on_sign: bool| {
+            let (signer_payer, signatures, source, traded) = (payer.clone(), signatures.clone(), source.clone(), client_account(2));
+            DvlSendTransactionParams {
+                client_account_guard: Some(client_key),
+                ..DvlSendTransactionParams::new(
+                    vec![Instruction::new_with_bytes(client.program_id, &[1], vec![])],
+                    &payer_key,
+                    Box::new(move |transaction, hash| {
+                        signatures.fetch_add(1, Ordering::Relaxed);
+                        if trade_on_sign {
+                            source.insert_fixture(&traded);
+                        }
+                        Ok(transaction.try_sign(&[&*signer_payer], hash)?)
+                    }),
+                )
+            }
+        };
+        let unconfirmed = || -> ClientError { RpcError::ForUser("unable to confirm transaction".to_string()).into() };
+
+        source.fail_next_confirmation(unconfirmed());
+        let signature = client.send_transaction(params(false)).await.unwrap();
+        let sent = source.sent_transactions();
+        assert_eq!((sent.len(), signatures.load(Ordering::Relaxed)), (1, 1));
+        assert_eq!(signature, sent[0].signatures[0].to_string());
+
+        source.fail_next_send(unconfirmed());
+        client.send_transaction(params(false)).await.unwrap();
+        assert_eq!((source.sent_transactions().len(), signatures.load(Ordering::Relaxed)), (2, 3));
+
+        source.fail_next_send(unconfirmed());
+        let error = client.send_transaction(params(true)).await.err().unwrap();
+        assert!(matches!(error, DvlClientError::Unconfirmed(signatures) if signatures.len() == 1));
+        assert_eq!((source.sent_transactions().len(), signatures.load(Ordering::Relaxed)), (2, 4));
+    }
+
+    #[tokio::test]
+    async fn test_send_unconfirmed_when_statuses_fail() {
+        let (source, client) = setup_memory_client();
+        let payer = Keypair::new();
+        let payer_key = payer.pubkey();
+        let params = DvlSendTransactionParams::new(
+            vec![Instruction::new_with_bytes(client.program_id, &[1], vec![])],
+            &payer_key,
+            Box::new(move |transaction, hash| Ok(transaction.try_sign(&[&payer], hash)?)),
+        );
+        source.fail_next_confirmation(RpcError::ForUser("unable to confirm transaction".to_string()).into());
+        source.fail_next_status_lookup(RpcError::RpcRequestError("connection reset".to_string()).into());
+        let error = client.send_transaction(params).await.err().unwrap();
+        let sent = source.sent_transactions();
+        assert!(matches!(error, DvlClientError::Unconfirmed(signatures) if signatures == vec![sent[0].signatures[0]]));
+        assert_eq!(sent.len(), 1);
+    }
+}
@@ -0,0 +1,203 @@
+use std::error::Error;
+use solana_client::client_error::{ClientError, ClientErrorKind};
+use solana_client::rpc_request::{RpcError, RpcResponseErrorData};
+use solana_program::message::Message;
+use solana_sdk::pubkey::Pubkey;
+use solana_sdk::signature::Signature;
+use solana_sdk::transaction::TransactionError;
+use thiserror::Error;
+use crate::dvl_client::simulation::{decode_transaction_error, DvlSimulationError};
+use crate::dvl_error::DvlError;
+
+/// Start of the message of `RpcClient` when a sent transaction is not confirmed before its
+/// blockhash expires.
+const UNCONFIRMED_TRANSACTION_MESSAGE: &str = "unable to confirm transaction";
+
+pub type DvlClientResult<T> = Result<T, DvlClientError>;
+
+/// Error of the `DvlClient` methods.
+#[derive(Error, Debug)]
+pub enum DvlClientError {
+    #[error("RPC request failed: {0}")]
+    Rpc(Box<ClientError>),
+    /// Returned by the DeVol program from the instruction at the index, or found by the client
+    /// checks of an account, without index.
+    #[error("{0}{}", .1.map(|index| format!(", instruction: {}", index)).unwrap_or_default())]
+    Contract(DvlError, Option<u8>),
+    /// The transaction failed in the preflight or the requested simulation, not in a DeVol
+    /// instruction.
+    #[error("Transaction simulation failed: {error}")]
+    Simulation { error: TransactionError, logs: Vec<String> },
+    #[error("Transaction blockhash expired before it was confirmed")]
+    BlockhashExpired,
+    #[error("Request timed out")]
+    Timeout,
+    /// A transaction may have executed, but none of its attempts is known to have landed: the
+    /// client account changed since the first attempt while the node knows none of their
+    /// signatures, or their statuses could not be checked. It is not sent again.
+    #[error("Transaction may have executed, none of its {} signatures is confirmed", .0.len())]
+    Unconfirmed(Vec<Signature>),
+    #[error("Transaction signing failed: {0}")]
+    Signing(String),
+    #[error("Account decoding failed: {0}")]
+    Decode(String),
+    /// The client cannot make the request as asked, e.g. too many accounts for one request.
+    #[error("Invalid request: {0}")]
+    Request(String),
+    /// Several accounts match a lookup which expects exactly one.
+    #[error("{} accounts match, expected one", .0.len())]
+    Ambiguous(Vec<Pubkey>),
+}
+
+impl DvlClientError {
+    /// The error of `message` which failed in the preflight or a simulation. A custom error is a
+    /// DeVol one only if the failing instruction calls `program_id`.
+    pub fn from_simulation(error: TransactionError, logs: Vec<String>, message: &Message, program_id: &Pubkey) -> Self {
+        Self::from_simulation_error(decode_transaction_error(error, message, program_id), logs)
+    }
+
+    /// The error of sending `message`, with the custom errors of its instructions calling
+    /// `program_id` decoded as DeVol ones.
+    pub fn from_transaction(error: ClientError, message: &Message, program_id: &Pubkey) -> Self {
+        if let Some((error, logs)) = preflight_failure(&error) {
+            return Self::from_simulation(error, logs, message, program_id);
+        }
+        if let ClientErrorKind::TransactionError(transaction_error) = error.kind() {
+            if let DvlSimulationError::Contract { instruction_index, error } = decode_transaction_error(transaction_error.clone(), message, program_id) {
+                return DvlClientError::Contract(error, Some(instruction_index));
+            }
+        }
+        Self::from(error)
+    }
+
+    pub(crate) fn from_simulation_error(error: DvlSimulationError, logs: Vec<String>) -> Self {
+        match error {
+            DvlSimulationError::Contract { instruction_index, error } => DvlClientError::Contract(error, Some(instruction_index)),
+            DvlSimulationError::Transaction(TransactionError::BlockhashNotFound) => DvlClientError::BlockhashExpired,
+            DvlSimulationError::Transaction(error) => DvlClientError::Simulation { error, logs },
+        }
+    }
+}
+
+/// Error and logs of the preflight simulation which rejected a sent transaction.
+fn preflight_failure(error: &ClientError) -> Option<(TransactionError, Vec<String>)> {
+    match error.kind() {
+        ClientErrorKind::RpcError(RpcError::RpcResponseError {
+            data: RpcResponseErrorData::SendTransactionPreflightFailure(result),
+            ..
+        }) => Some((result.err.clone()?, result.logs.clone().unwrap_or_default())),
+        _ => None,
+    }
+}
+
+/// Without the transaction the custom errors are not decoded, they may come from any program, see
+/// `DvlClientError::from_transaction`.
+impl From<ClientError> for DvlClientError {
+    fn from(error: ClientError) -> Self {
+        if let Some((error, logs)) = preflight_failure(&error) {
+            return Self::from_simulation_error(DvlSimulationError::Transaction(error), logs);
+        }
+        match error.kind() {
+            ClientErrorKind::TransactionError(TransactionError::BlockhashNotFound) => DvlClientError::BlockhashExpired,
+            ClientErrorKind::RpcError(RpcError::ForUser(message)) if message.starts_with(UNCONFIRMED_TRANSACTION_MESSAGE) => {
+                DvlClientError::BlockhashExpired
+            }
+            ClientErrorKind::Reqwest(e) if e.is_timeout() => DvlClientError::Timeout,
+            ClientErrorKind::SigningError(e) => DvlClientError::Signing(e.to_string()),
+            _ => DvlClientError::Rpc(Box::new(error)),
+        }
+    }
+}
+
+impl From<DvlError> for DvlClientError {
+    fn from(error: DvlError) -> Self {
+        DvlClientError::Contract(error, None)
+    }
+}
+
+impl From<DvlSimulationError> for DvlClientError {
+    fn from(error: DvlSimulationError) -> Self {
+        Self::from_simulation_error(error, Vec::new())
+    }
+}
+
+/// Errors of the account readers and the other helpers returning `Box<dyn Error>`.
+impl From<Box<dyn Error>> for DvlClientError {
+    fn from(error: Box<dyn Error>) -> Self {
+        let error = match error.downcast::<DvlClientError>() {
+            Ok(error) => return *error,
+            Err(error) => error,
+        };
+        let error = match error.downcast::<DvlError>() {
+            Ok(error) => return DvlClientError::Contract(*error, None),
+            Err(error) => error,
+        };
+        match error.downcast::<ClientError>() {
+            Ok(error) => DvlClientError::from(*error),
+            Err(error) => DvlClientError::Decode(error.to_string()),
+        }
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use solana_program::instruction::{Instruction, InstructionError};
+    use solana_client::rpc_response::RpcSimulateTransactionResult;
+    use crate::errors::{AccountTag, ContractError};
+
+    #[test]
+    fn test_client_error_kinds() {
+        let code = DvlError::new_with_account(AccountTag::Worker, ContractError::InvalidAccountId).encode();
+        let preflight = || -> ClientError {
+            RpcError::RpcResponseError {
+                code: -32002,
+                message: "Transaction simulation failed".to_string(),
+                data: RpcResponseErrorData::SendTransactionPreflightFailure(RpcSimulateTransactionResult {
+                    err: Some(TransactionError::InstructionError(2, InstructionError::Custom(code))),
+                    logs: Some(vec![]),
+                    accounts: None,
+                    units_consumed: None,
+                    return_data: None,
+                    inner_instructions: None,
+                }),
+            }.into()
+        };
+        let program_id = Pubkey::new_unique();
+        let other_program_id = Pubkey::new_unique();
+        let instruction = |program_id| Instruction::new_with_bytes(program_id, &[], vec![]);
+        let message = Message::new(&[instruction(other_program_id), instruction(other_program_id), instruction(program_id)], None);
+        match DvlClientError::from_transaction(preflight(), &message, &program_id) {
+            DvlClientError::Contract(error, index) => assert_eq!((error.encode(), index), (code, Some(2))),
+            error => panic!("unexpected {:?}", error),
+        }
+        let message = Message::new(&[instruction(program_id), instruction(program_id), instruction(other_program_id)], None);
+        assert!(matches!(
+            DvlClientError::from_transaction(preflight(), &message, &program_id),
+            DvlClientError::Simulation { error: TransactionError::InstructionError(2, _), .. },
+        ));
+        assert!(matches!(DvlClientError::from(preflight()), DvlClientError::Simulation { .. }));
+        let failed: ClientError = TransactionError::InstructionError(2, InstructionError::Custom(code)).into();
+        assert!(matches!(DvlClientError::from_transaction(failed, &message, &program_id), DvlClientError::Rpc(_)));
+
+        let expired: ClientError = RpcError::ForUser(format!("{}. This can happen", UNCONFIRMED_TRANSACTION_MESSAGE)).into();
+        assert!(matches!(DvlClientError::from(expired), DvlClientError::BlockhashExpired));
+        let not_found: ClientError = RpcError::ForUser("AccountNotFound".to_string()).into();
+        assert!(matches!(DvlClientError::from(not_found), DvlClientError::Rpc(_)));
+        assert!(matches!(
+            DvlClientError::from_simulation(TransactionError::AccountNotFound, vec!["log".to_string()], &message, &program_id),
+            DvlClientError::Simulation { logs, .. } if logs.len() == 1,
+        ));
+
+        let boxed: Box<dyn Error> = Box::new(DvlError::new_with_account(AccountTag::Client, ContractError::AccountOwner));
+        assert!(matches!(DvlClientError::from(boxed), DvlClientError::Contract(_, None)));
+        let boxed: Box<dyn Error> = Box::new(DvlClientError::Timeout);
+        assert!(matches!(DvlClientError::from(boxed), DvlClientError::Timeout));
+        let boxed: Box<dyn Error> = "Wrong data".into();
+        assert!(matches!(DvlClientError::from(boxed), DvlClientError::Decode(_)));
+        assert_eq!(
+            DvlClientError::Contract(DvlError::from_code(code), Some(1)).to_string(),
+            format!("{}, instruction: 1", DvlError::from_code(code)),
+        );
+    }
+}
@@ -0,0 +1,204 @@
+use std::error::Error;
+use std::marker::PhantomData;
+use serde::{Deserialize, Serialize};
+use solana_program::pubkey::Pubkey;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::ring_log::RingLog;
+use crate::dvl_client::dvl_client::DvlClient;
+
+/// Position of a `LogTailer` in a log. The default cursor starts from the oldest record.
+#[derive(Clone, Copy, PartialEq, Eq, Debug, Default, Serialize, Deserialize)]
+pub struct LogCursor {
+    /// Id of the newest record returned so far.
+    pub last_id: Option<i64>,
+    /// `RingLog::written_count` at the last poll.
+    pub written_count: u64,
+}
+
+/// Records which were written to the log but overwritten before they were polled.
+#[derive(Clone, Copy, PartialEq, Eq, Debug)]
+pub enum LogGap {
+    /// Number of the lost records, known for the logs which count every record written.
+    Lost(u64),
+    /// The last returned record was overwritten, so records between it and the oldest one may
+    /// have been lost.
+    Unknown,
+}
+
+/// New records of a log since the previous poll.
+#[derive(Clone, Debug)]
+pub struct LogTail<R> {
+    /// New records from the oldest to the newest.
+    pub records: Vec<R>,
+    pub gap: Option<LogGap>,
+}
+
+/// Polls a log account and returns only the records written since the previous poll.
+pub struct LogTailer<T> {
+    public_key: Pubkey,
+    cursor: LogCursor,
+    _account: PhantomData<T>,
+}
+
+impl<T> LogTailer<T>
+    where
+        T: RingLog,
+        T::Record: Copy,
+{
+    /// Tails the log from its oldest record.
+    pub fn new(public_key: Pubkey) -> Self {
+        Self::with_cursor(public_key, LogCursor::default())
+    }
+
+    /// Resumes tailing from a saved cursor.
+    pub fn with_cursor(public_key: Pubkey, cursor: LogCursor) -> Self {
+        Self { public_key, cursor, _account: PhantomData }
+    }
+
+    pub fn public_key(&self) -> &Pubkey {
+        &self.public_key
+    }
+
+    /// Cursor to save to resume after a restart.
+    pub fn cursor(&self) -> LogCursor {
+        self.cursor
+    }
+
+    pub async fn poll(&mut self, client: &DvlClient) -> Result<LogTail<T::Record>, Box<dyn Error>>
+        where
+            T: DvlReadable<DvlReadOutput = T> + Send,
+    {
+        let account = client.get_account_by_public_key::<T>(&self.public_key).await?;
+        Ok(self.advance(&account))
+    }
+
+    /// Returns the records of `account` newer than the cursor and moves the cursor to the newest
+    /// one.
+    pub fn advance(&mut self, account: &T) -> LogTail<T::Record> {
+        let records: Vec<T::Record> = match self.cursor.last_id {
+            Some(last_id) => account.since_id(last_id).copied().collect(),
+            None => account.iter_chronological().copied().collect(),
+        };
+        let gap = self.gap(account, &records);
+        if let Some(newest) = records.last() {
+            self.cursor.last_id = Some(T::record_id(newest));
+        }
+        self.cursor.written_count = account.written_count();
+        LogTail { records, gap }
+    }
+
+    fn gap(&self, account: &T, records: &[T::Record]) -> Option<LogGap> {
+        // A fresh cursor asks for the records the log still holds, it has lost none of them.
+        let last_id = self.cursor.last_id?;
+        if account.overwritten_count() > 0 {
+            let written = account.written_count().saturating_sub(self.cursor.written_count);
+            let lost = written.saturating_sub(T::CAPACITY as u64);
+            return (lost > 0).then_some(LogGap::Lost(lost));
+        }
+        // The logs with a capped count only show that the last returned record is gone.
+        (account.is_full() && records.len() == account.len() && T::record_id(&records[0]) > last_id.saturating_add(1))
+            .then_some(LogGap::Unknown)
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
+    use crate::accounts::worker::pool_logs::pool_logs_account::{POOLS_LOG_BUFFER_CAPACITY, PoolLogsAccount};
+    use crate::accounts::worker::tasks_log::task_log::TasksLog;
+    use crate::accounts::worker::tasks_log::tasks_log_account::{TASKS_LOG_BUFFER_CAPACITY, TasksLogAccount};
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+    use crate::fixtures::pool_logs_account_builder::PoolLogsAccountBuilder;
+
+    fn push_tasks(account: &mut TasksLogAccount, ids: std::ops::RangeInclusive<i64>) {
+        for id in ids {
+            let index = if account.count == 0 { 0 } else { (account.last as usize + 1) % TASKS_LOG_BUFFER_CAPACITY };
+            let mut record = TasksLog::default();
+            record.set_id(id);
+            account.data[index] = record;
+            account.last = index as u32;
+            account.count = (account.count + 1).min(TASKS_LOG_BUFFER_CAPACITY as u32);
+        }
+    }
+
+    fn push_pools(builder: PoolLogsAccountBuilder, operations: std::ops::RangeInclusive<u64>) -> PoolLogsAccountBuilder {
+        operations.fold(builder, |builder, operation| {
+            builder.push_record(&PoolLogRecord { worker_operations_count: operation, ..PoolLogRecord::default() })
+        })
+    }
+
+    #[test]
+    fn test_log_tailer_returns_new_records() {
+        let mut account = DevolAccountBuilder::<TasksLogAccount>::new().account();
+        let mut tailer = LogTailer::<TasksLogAccount>::new(Pubkey::new_unique());
+        assert!(tailer.advance(&account).records.is_empty());
+
+        push_tasks(&mut account, 1..=3);
+        let tail = tailer.advance(&account);
+        assert_eq!(tail.records.iter().map(TasksLog::get_id).collect::<Vec<_>>(), vec![1, 2, 3]);
+        assert_eq!(tail.gap, None);
+
+        push_tasks(&mut account, 4..=5);
+        let tail = tailer.advance(&account);
+        assert_eq!(tail.records.iter().map(TasksLog::get_id).collect::<Vec<_>>(), vec![4, 5]);
+        assert!(tailer.advance(&account).records.is_empty());
+        assert_eq!(tailer.cursor().last_id, Some(5));
+    }
+
+    #[test]
+    fn test_log_tailer_capped_count_gap() {
+        let mut account = DevolAccountBuilder::<TasksLogAccount>::new().account();
+        let mut tailer = LogTailer::<TasksLogAccount>::new(Pubkey::new_unique());
+        push_tasks(&mut account, 1..=2);
+        tailer.advance(&account);
+
+        push_tasks(&mut account, 3..=TASKS_LOG_BUFFER_CAPACITY as i64 + 2);
+        assert_eq!(tailer.advance(&account).gap, None);
+
+        push_tasks(&mut account, TASKS_LOG_BUFFER_CAPACITY as i64 + 3..=2 * TASKS_LOG_BUFFER_CAPACITY as i64 + 10);
+        let tail = tailer.advance(&account);
+        assert_eq!(tail.gap, Some(LogGap::Unknown));
+        assert_eq!(tail.records.len(), TASKS_LOG_BUFFER_CAPACITY);
+    }
+
+    #[test]
+    fn test_log_tailer_counted_gap_and_resume() {
+        let public_key = Pubkey::new_unique();
+        let mut builder = push_pools(PoolLogsAccountBuilder::new(), 1..=10);
+        let mut tailer = LogTailer::<PoolLogsAccount>::new(public_key);
+        assert_eq!(tailer.advance(&builder.account()).records.len(), 10);
+
+        let json = serde_json::to_string(&tailer.cursor()).unwrap();
+        let cursor: LogCursor = serde_json::from_str(&json).unwrap();
+        assert_eq!(cursor, tailer.cursor());
+        let mut tailer = LogTailer::<PoolLogsAccount>::with_cursor(public_key, cursor);
+
+        builder = push_pools(builder, 11..=POOLS_LOG_BUFFER_CAPACITY as u64 + 15);
+        let tail = tailer.advance(&builder.account());
+        assert_eq!(tail.gap, Some(LogGap::Lost(5)));
+        assert_eq!(tail.records.len(), POOLS_LOG_BUFFER_CAPACITY);
+        assert_eq!(tail.records[0].worker_operations_count, 16);
+
+        builder = push_pools(builder, POOLS_LOG_BUFFER_CAPACITY as u64 + 16..=POOLS_LOG_BUFFER_CAPACITY as u64 + 17);
+        let tail = tailer.advance(&builder.account());
+        assert_eq!(tail.gap, None);
+        assert_eq!(tail.records.len(), 2);
+    }
+
+    #[tokio::test]
+    async fn test_log_tailer_fresh_cursor_on_overflowed_log() {
+        let (source, client) = setup_memory_client();
+        let builder = PoolLogsAccountBuilder::new().root(client.root_pda.key);
+        let fixture = push_pools(builder, 1..=POOLS_LOG_BUFFER_CAPACITY as u64 + 20).build();
+        source.insert_fixture(&fixture);
+
+        let mut tailer = LogTailer::<PoolLogsAccount>::new(fixture.key);
+        let tail = tailer.poll(&client).await.unwrap();
+        assert_eq!(tail.gap, None);
+        assert_eq!(tail.records.len(), POOLS_LOG_BUFFER_CAPACITY);
+        assert_eq!(tail.records[0].worker_operations_count, 21);
+        assert!(tailer.poll(&client).await.unwrap().records.is_empty());
+    }
+}
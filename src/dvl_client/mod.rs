@@ -1 +1,16 @@
-pub mod dvl_client;
\ No newline at end of file
+pub mod account_cache;
+pub mod account_source;
+pub mod client_index;
+pub mod dvl_client;
+pub mod dvl_client_error;
+#[cfg(feature = "blocking")]
+pub mod dvl_blocking_client;
+pub mod log_tailer;
+pub mod platform_accounts;
+pub mod platform_snapshot;
+pub mod retry_policy;
+pub mod scan;
+pub mod simulation;
+pub mod snapshot;
+pub mod subscription;
+pub mod transaction_builder;
@@ -0,0 +1,195 @@
+use std::error::Error;
+use solana_program::pubkey::Pubkey;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::all_workers::worker::Worker;
+use crate::accounts::devol_any_account::{DevolAnyAccount, DevolAnyVariant};
+use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+use crate::accounts::mints::mint::Mint;
+use crate::accounts::mints::mints_account::MintsAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+use crate::accounts::worker::worker_account::WorkerAccount;
+use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
+
+/// Platform accounts which hold the addresses of the other accounts, fetched in two batches: the
+/// root and the oracles PDAs, then the accounts the root points to.
+pub struct DvlPlatformAccounts {
+    pub root_key: Pubkey,
+    pub root: Box<RootAccount>,
+    pub oracles_key: Pubkey,
+    pub oracles: Box<OraclesAccount>,
+    pub instruments: Box<InstrumentsAccount>,
+    pub all_workers: Box<AllWorkersAccount>,
+    pub mints: Box<MintsAccount>,
+}
+
+impl DvlPlatformAccounts {
+    /// Takes the accounts from the client cache when all the accounts of a batch are there.
+    pub async fn fetch(client: &DvlClient) -> Result<Self, Box<dyn Error>> {
+        Ok(Self::fetch_with(client, &[]).await?.0)
+    }
+
+    /// `fetch` along with the accounts of `extra_keys`, in their order, read in the first batch.
+    /// With the root and the oracles cached they are read alone, in one request.
+    pub async fn fetch_with(
+        client: &DvlClient,
+        extra_keys: &[Pubkey],
+    ) -> Result<(Self, Vec<Option<DevolAnyAccount>>), Box<dyn Error>> {
+        let root_key = *RootAccount::get_public_key(client, &()).await?;
+        let oracles_key = *OraclesAccount::get_public_key(client, &()).await?;
+        let (root, oracles, extra) = match (client.cached_account::<RootAccount>(), client.cached_account::<OraclesAccount>()) {
+            (Some(root), Some(oracles)) if extra_keys.is_empty() => (root, oracles, Vec::new()),
+            (Some(root), Some(oracles)) => (root, oracles, client.fetch_accounts_batch_with_slot(extra_keys).await?.1),
+            _ => {
+                let keys = [&[root_key, oracles_key], extra_keys].concat();
+                let (slot, accounts) = client.fetch_accounts_batch_with_slot(&keys).await?;
+                let mut accounts = accounts.into_iter();
+                let root: Box<RootAccount> = take_account(&mut accounts, AccountTag::Root)?;
+                let oracles: Box<OraclesAccount> = take_account(&mut accounts, AccountTag::Oracle)?;
+                client.cache_account(&*root, slot);
+                client.cache_account(&*oracles, slot);
+                (root, oracles, accounts.collect())
+            }
+        };
+
+        let cached = (
+            client.cached_account::<InstrumentsAccount>(),
+            client.cached_account::<AllWorkersAccount>(),
+            client.cached_account::<MintsAccount>(),
+        );
+        let (instruments, all_workers, mints) = match cached {
+            (Some(instruments), Some(all_workers), Some(mints)) => (instruments, all_workers, mints),
+            _ => {
+                let keys = [root.instruments_address, root.workers_address, root.mints_address];
+                let (slot, accounts) = client.fetch_accounts_batch_with_slot(&keys).await?;
+                let mut accounts = accounts.into_iter();
+                let instruments: Box<InstrumentsAccount> = take_account(&mut accounts, AccountTag::Instruments)?;
+                let all_workers: Box<AllWorkersAccount> = take_account(&mut accounts, AccountTag::AllWorkers)?;
+                let mints: Box<MintsAccount> = take_account(&mut accounts, AccountTag::Mints)?;
+                client.cache_account(&*instruments, slot);
+                client.cache_account(&*all_workers, slot);
+                client.cache_account(&*mints, slot);
+                (instruments, all_workers, mints)
+            }
+        };
+        Ok((Self { root_key, root, oracles_key, oracles, instruments, all_workers, mints }, extra))
+    }
+
+    pub fn instruments_key(&self) -> Pubkey {
+        self.root.instruments_address
+    }
+
+    pub fn all_workers_key(&self) -> Pubkey {
+        self.root.workers_address
+    }
+
+    pub fn mints_key(&self) -> Pubkey {
+        self.root.mints_address
+    }
+
+    /// Addresses of the worker `id` and its logs.
+    pub fn worker(&self, id: u32) -> &Worker {
+        &self.all_workers.workers[id as usize]
+    }
+
+    pub fn mint(&self, id: u32) -> &Mint {
+        &self.mints.data[id as usize]
+    }
+
+    /// Fetches the worker `id` with one more request, checking its id like the `WorkerAccount` reader.
+    /// Its address is only known from the all workers account, so it cannot join a platform batch.
+    pub async fn fetch_worker(&self, client: &DvlClient, id: u32) -> Result<Box<WorkerAccount>, Box<dyn Error>> {
+        let mut accounts = client.get_accounts_batch(&[self.worker(id).address]).await?.into_iter();
+        let worker: Box<WorkerAccount> = take_account(&mut accounts, AccountTag::Worker)?;
+        if worker.id != id {
+            return Err(Box::new(DvlError::new_with_account(AccountTag::Worker, ContractError::InvalidAccountId)));
+        }
+        Ok(worker)
+    }
+}
+
+/// Next account of a batch, which must exist and be a `T`.
+pub fn take_account<T: DevolAnyVariant>(
+    accounts: &mut impl Iterator<Item = Option<DevolAnyAccount>>,
+    tag: AccountTag,
+) -> Result<Box<T>, DvlError> {
+    accounts.next()
+        .flatten()
+        .ok_or_else(|| DvlError::new_with_account(tag, ContractError::AccountIsMissing))?
+        .into_account()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+    use crate::dvl_client::account_cache::DvlCacheConfig;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+    use crate::tests::tests::setup_devol_client;
+
+    #[test]
+    fn test_decode_batch_account() {
+        let client = setup_devol_client();
+        let root = DevolAccountBuilder::<RootAccount>::new()
+            .program_id(client.program_id)
+            .root(Pubkey::new_unique())
+            .build();
+        let mints = DevolAccountBuilder::<MintsAccount>::new()
+            .program_id(client.program_id)
+            .root(client.root_pda.key)
+            .build();
+        let batch = vec![
+            Some(client.decode_batch_account(&root.to_account()).unwrap()),
+            None,
+            Some(client.decode_batch_account(&mints.to_account()).unwrap()),
+        ];
+        let mut accounts = batch.clone().into_iter();
+        assert!(take_account::<RootAccount>(&mut accounts, AccountTag::Root).is_ok());
+        let missing = take_account::<OraclesAccount>(&mut accounts, AccountTag::Oracle).err().unwrap();
+        assert_eq!(missing.encode(), DvlError::new_with_account(AccountTag::Oracle, ContractError::AccountIsMissing).encode());
+        assert!(take_account::<MintsAccount>(&mut accounts, AccountTag::Mints).is_ok());
+
+        let mut accounts = batch.into_iter();
+        let wrong = take_account::<InstrumentsAccount>(&mut accounts, AccountTag::Instruments).err().unwrap();
+        assert_eq!(wrong.encode(), DvlError::new_with_account(AccountTag::Root, ContractError::WrongAccountTag).encode());
+
+        let foreign_owner = DevolAccountBuilder::<PoolsTraceAccount>::new()
+            .program_id(Pubkey::new_unique())
+            .root(client.root_pda.key)
+            .build();
+        let error = client.decode_batch_account(&foreign_owner.to_account()).err().unwrap();
+        assert_eq!(error.encode(), DvlError::new_with_account(AccountTag::PoolsTrace, ContractError::AccountOwner).encode());
+
+        let foreign_root = DevolAccountBuilder::<MintsAccount>::new()
+            .program_id(client.program_id)
+            .root(Pubkey::new_unique())
+            .build();
+        let error = client.decode_batch_account(&foreign_root.to_account()).err().unwrap();
+        assert_eq!(error.encode(), DvlError::new_with_account(AccountTag::Mints, ContractError::RootAddress).encode());
+    }
+
+    #[tokio::test]
+    async fn test_platform_accounts_from_cache() {
+        let client = setup_devol_client().with_cache(DvlCacheConfig::default());
+        let instruments_address = Pubkey::new_unique();
+        let root = DevolAccountBuilder::<RootAccount>::new()
+            .with(|root| root.instruments_address = instruments_address)
+            .account();
+        client.cache_account(&*root, 1);
+        client.cache_account(&*DevolAccountBuilder::<OraclesAccount>::new().account(), 1);
+        client.cache_account(&*DevolAccountBuilder::<InstrumentsAccount>::new().account(), 1);
+        client.cache_account(&*DevolAccountBuilder::<AllWorkersAccount>::new().account(), 1);
+        client.cache_account(&*DevolAccountBuilder::<MintsAccount>::new().account(), 1);
+
+        let platform = client.get_platform_accounts().await.unwrap();
+        assert_eq!(platform.instruments_key(), instruments_address);
+        assert_eq!(*client.account_public_key::<InstrumentsAccount>(()).await.unwrap(), instruments_address);
+
+        client.invalidate::<RootAccount>();
+        assert!(client.cached_account::<RootAccount>().is_none());
+        assert!(client.cached_account::<MintsAccount>().is_some());
+    }
+}
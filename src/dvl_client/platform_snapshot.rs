@@ -0,0 +1,246 @@
+use std::error::Error;
+use std::fs;
+use std::io::{self, ErrorKind, Read, Write};
+use std::path::Path;
+use std::sync::Arc;
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+use crate::dvl_client::account_source::MemoryAccountSource;
+use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_client::snapshot::{DvlSnapshot, DvlSnapshotConfig};
+
+pub const PLATFORM_SNAPSHOT_MAGIC: [u8; 8] = *b"DVLSNAPS";
+/// Version of the file layout written by `DvlPlatformSnapshot::write`.
+pub const PLATFORM_SNAPSHOT_VERSION: u32 = 1;
+
+/// Raw platform accounts captured by `DvlClient::platform_snapshot`: the root, oracles,
+/// instruments, all-workers and mints accounts, then every worker with its pools trace, tasks
+/// trace, pools log and tasks log. The accounts are read at `slot` or later, see
+/// `DvlSnapshot::follow_up`. Saved to a file, it replays the platform without network through
+/// `DvlPlatformSnapshot::client`.
+#[derive(Clone, Debug, PartialEq)]
+pub struct DvlPlatformSnapshot {
+    pub program_id: Pubkey,
+    pub admin_public_key: Pubkey,
+    pub int_seed: usize,
+    pub slot: u64,
+    /// In the order they were read, the accounts which do not exist are left out.
+    pub accounts: Vec<(Pubkey, Account)>,
+}
+
+impl DvlPlatformSnapshot {
+    pub(crate) async fn fetch(client: &DvlClient) -> Result<Self, Box<dyn Error>> {
+        let root_key = *RootAccount::get_public_key(client, &()).await?;
+        let oracles_key = *OraclesAccount::get_public_key(client, &()).await?;
+        let first = DvlSnapshot::read(client, &[root_key, oracles_key], DvlSnapshotConfig::default()).await?;
+        let root = first.get::<RootAccount>(&root_key)?;
+        let mut accounts = Vec::new();
+        collect(&first, &mut accounts);
+
+        let platform = first.follow_up(&[root.instruments_address, root.workers_address, root.mints_address]).await?;
+        let all_workers = platform.get::<AllWorkersAccount>(&root.workers_address)?;
+        collect(&platform, &mut accounts);
+
+        let workers_keys: Vec<Pubkey> = all_workers.workers.iter()
+            .take(all_workers.count as usize)
+            .flat_map(|worker| [
+                worker.address,
+                worker.pools_trace_address,
+                worker.tasks_trace_address,
+                worker.pools_log_address,
+                worker.tasks_log_address,
+            ])
+            .collect();
+        for keys in workers_keys.chunks(MAX_MULTIPLE_ACCOUNTS) {
+            collect(&first.follow_up(keys).await?, &mut accounts);
+        }
+        Ok(Self {
+            program_id: client.program_id,
+            admin_public_key: client.admin_public_key,
+            int_seed: client.int_seed,
+            slot: first.slot(),
+            accounts,
+        })
+    }
+
+    pub fn get(&self, public_key: &Pubkey) -> Option<&Account> {
+        self.accounts.iter().find(|(key, _)| key == public_key).map(|(_, account)| account)
+    }
+
+    /// Source serving the accounts of the snapshot at its slot.
+    pub fn to_source(&self) -> MemoryAccountSource {
+        let source = MemoryAccountSource::new();
+        for (public_key, account) in &self.accounts {
+            source.insert(*public_key, account.clone());
+        }
+        source.set_slot(self.slot);
+        source
+    }
+
+    /// Client of the same platform reading the snapshot instead of the network.
+    pub fn client(&self) -> DvlClient {
+        DvlClient::with_source(Arc::new(self.to_source()), self.int_seed, self.admin_public_key, self.program_id)
+    }
+
+    pub fn save(&self, path: impl AsRef<Path>) -> io::Result<()> {
+        let mut file = io::BufWriter::new(fs::File::create(path)?);
+        self.write(&mut file)?;
+        file.flush()
+    }
+
+    pub fn load(path: impl AsRef<Path>) -> io::Result<Self> {
+        Self::read(&mut io::BufReader::new(fs::File::open(path)?))
+    }
+
+    /// Little-endian layout: the magic, the version, the platform, the slot and the number of
+    /// accounts, then for each account its key, owner, lamports, executable flag, rent epoch and
+    /// the data prefixed with its length.
+    pub fn write(&self, writer: &mut impl Write) -> io::Result<()> {
+        writer.write_all(&PLATFORM_SNAPSHOT_MAGIC)?;
+        writer.write_all(&PLATFORM_SNAPSHOT_VERSION.to_le_bytes())?;
+        writer.write_all(self.program_id.as_ref())?;
+        writer.write_all(self.admin_public_key.as_ref())?;
+        writer.write_all(&(self.int_seed as u64).to_le_bytes())?;
+        writer.write_all(&self.slot.to_le_bytes())?;
+        writer.write_all(&(self.accounts.len() as u32).to_le_bytes())?;
+        for (public_key, account) in &self.accounts {
+            writer.write_all(public_key.as_ref())?;
+            writer.write_all(account.owner.as_ref())?;
+            writer.write_all(&account.lamports.to_le_bytes())?;
+            writer.write_all(&[account.executable as u8])?;
+            writer.write_all(&account.rent_epoch.to_le_bytes())?;
+            writer.write_all(&(account.data.len() as u32).to_le_bytes())?;
+            writer.write_all(&account.data)?;
+        }
+        Ok(())
+    }
+
+    pub fn read(reader: &mut impl Read) -> io::Result<Self> {
+        if read_array::<8>(reader)? != PLATFORM_SNAPSHOT_MAGIC {
+            return Err(invalid_data("Not a platform snapshot".to_string()));
+        }
+        let version = read_u32(reader)?;
+        if version != PLATFORM_SNAPSHOT_VERSION {
+            return Err(invalid_data(format!(
+                "Platform snapshot version {} is not supported, expected {}",
+                version,
+                PLATFORM_SNAPSHOT_VERSION,
+            )));
+        }
+        let program_id = read_pubkey(reader)?;
+        let admin_public_key = read_pubkey(reader)?;
+        let int_seed = usize::try_from(read_u64(reader)?).map_err(|e| invalid_data(e.to_string()))?;
+        let slot = read_u64(reader)?;
+        let count = read_u32(reader)?;
+        let mut accounts = Vec::new();
+        for _ in 0..count {
+            let public_key = read_pubkey(reader)?;
+            let owner = read_pubkey(reader)?;
+            let lamports = read_u64(reader)?;
+            let executable = read_array::<1>(reader)?[0] != 0;
+            let rent_epoch = read_u64(reader)?;
+            let mut data = Vec::new();
+            let len = read_u32(reader)? as u64;
+            if reader.take(len).read_to_end(&mut data)? as u64 != len {
+                return Err(ErrorKind::UnexpectedEof.into());
+            }
+            accounts.push((public_key, Account { lamports, data, owner, executable, rent_epoch }));
+        }
+        Ok(Self { program_id, admin_public_key, int_seed, slot, accounts })
+    }
+}
+
+fn collect(snapshot: &DvlSnapshot, accounts: &mut Vec<(Pubkey, Account)>) {
+    for public_key in snapshot.keys() {
+        if let Some(account) = snapshot.raw(public_key) {
+            if !accounts.iter().any(|(key, _)| key == public_key) {
+                accounts.push((*public_key, account.clone()));
+            }
+        }
+    }
+}
+
+fn invalid_data(message: String) -> io::Error {
+    io::Error::new(ErrorKind::InvalidData, message)
+}
+
+fn read_array<const N: usize>(reader: &mut impl Read) -> io::Result<[u8; N]> {
+    let mut bytes = [0u8; N];
+    reader.read_exact(&mut bytes)?;
+    Ok(bytes)
+}
+
+fn read_u32(reader: &mut impl Read) -> io::Result<u32> {
+    Ok(u32::from_le_bytes(read_array(reader)?))
+}
+
+fn read_u64(reader: &mut impl Read) -> io::Result<u64> {
+    Ok(u64::from_le_bytes(read_array(reader)?))
+}
+
+fn read_pubkey(reader: &mut impl Read) -> io::Result<Pubkey> {
+    Ok(Pubkey::new_from_array(read_array(reader)?))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+    use crate::accounts::mints::mints_account::MintsAccount;
+    use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+    use crate::accounts::worker::worker_account::WorkerAccount;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    #[tokio::test]
+    async fn test_platform_snapshot() {
+        let (source, client) = setup_memory_client();
+        let root_key = client.root_pda.key;
+        let (instruments_key, workers_key, mints_key) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let worker = DevolAccountBuilder::<WorkerAccount>::new().root(root_key).with(|worker| worker.id = 0).build();
+        let pools_trace = DevolAccountBuilder::<PoolsTraceAccount>::new().root(root_key).build();
+        source.insert_fixture(&DevolAccountBuilder::<RootAccount>::new().key(root_key).with(|root| {
+            root.instruments_address = instruments_key;
+            root.workers_address = workers_key;
+            root.mints_address = mints_key;
+        }).build());
+        let oracles_key = *client.account_public_key::<OraclesAccount>(()).await.unwrap();
+        source.insert_fixture(&DevolAccountBuilder::<OraclesAccount>::new().key(oracles_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<InstrumentsAccount>::new().key(instruments_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<MintsAccount>::new().key(mints_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<AllWorkersAccount>::new().key(workers_key).root(root_key).with(|all_workers| {
+            all_workers.count = 1;
+            all_workers.workers[0].address = worker.key;
+            all_workers.workers[0].pools_trace_address = pools_trace.key;
+        }).build());
+        source.insert_fixture(&worker);
+        source.insert_fixture(&pools_trace);
+        source.set_slot(42);
+
+        let snapshot = client.platform_snapshot().await.unwrap();
+        assert_eq!(snapshot.slot, 42);
+        assert_eq!(snapshot.accounts.len(), 7);
+
+        let path = std::env::temp_dir().join(format!("dvl-platform-snapshot-{}.bin", Pubkey::new_unique()));
+        snapshot.save(&path).unwrap();
+        let loaded = DvlPlatformSnapshot::load(&path).unwrap();
+        fs::remove_file(&path).unwrap();
+        assert_eq!(loaded, snapshot);
+
+        let offline = loaded.client();
+        assert_eq!(offline.root_pda.key, root_key);
+        assert_eq!(offline.get_account_by_public_key::<WorkerAccount>(&worker.key).await.unwrap().id, 0);
+        assert_eq!(offline.get_platform_accounts().await.unwrap().all_workers.count, 1);
+
+        let mut data = Vec::new();
+        snapshot.write(&mut data).unwrap();
+        data[8] = 2;
+        assert_eq!(DvlPlatformSnapshot::read(&mut data.as_slice()).err().unwrap().kind(), ErrorKind::InvalidData);
+        assert!(DvlPlatformSnapshot::read(&mut &data[..data.len() - 1]).is_err());
+    }
+}
@@ -0,0 +1,335 @@
+use std::future::Future;
+use std::time::{Duration, Instant};
+use rand::Rng;
+use solana_client::client_error::ClientErrorKind;
+use solana_client::rpc_custom_error::{
+    JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE,
+    JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED,
+    JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY,
+};
+use solana_client::rpc_request::RpcError;
+use solana_sdk::transaction::TransactionError;
+use crate::dvl_client::dvl_client_error::{DvlClientError, DvlClientResult};
+
+/// When and how often the client repeats a failed RPC call or transaction: up to `max_attempts`
+/// attempts, waiting `initial_delay` multiplied by `multiplier` after each failure up to
+/// `max_delay`, randomized by `jitter` of the delay either way. No attempt starts after
+/// `max_elapsed` from the first one. Only the errors `retry_if` accepts are retried.
+#[derive(Clone, Debug)]
+pub struct RetryPolicy {
+    pub max_attempts: usize,
+    pub initial_delay: Duration,
+    pub multiplier: f64,
+    pub max_delay: Duration,
+    /// Share of the delay, from 0 to 1, added or taken at random so that clients do not retry
+    /// in step.
+    pub jitter: f64,
+    pub max_elapsed: Option<Duration>,
+    pub retry_if: fn(&DvlClientError) -> bool,
+}
+
+impl Default for RetryPolicy {
+    fn default() -> Self {
+        Self {
+            max_attempts: 5,
+            initial_delay: Duration::from_millis(500),
+            multiplier: 2.0,
+            max_delay: Duration::from_secs(8),
+            jitter: 0.2,
+            max_elapsed: Some(Duration::from_secs(60)),
+            retry_if: is_retryable,
+        }
+    }
+}
+
+impl RetryPolicy {
+    /// One attempt, the errors are returned as they are.
+    pub fn none() -> Self {
+        Self { max_attempts: 1, ..Self::default() }
+    }
+
+    /// `max_attempts` attempts `delay` apart.
+    pub fn fixed(max_attempts: usize, delay: Duration) -> Self {
+        Self {
+            max_attempts,
+            initial_delay: delay,
+            multiplier: 1.0,
+            max_delay: delay,
+            jitter: 0.0,
+            max_elapsed: None,
+            retry_if: is_retryable,
+        }
+    }
+
+    /// Delay before the attempt after `attempt`, counted from 1, without the jitter.
+    pub fn backoff(&self, attempt: usize) -> Duration {
+        let exponent = attempt.saturating_sub(1).min(i32::MAX as usize) as i32;
+        let delay = self.initial_delay.as_secs_f64() * self.multiplier.powi(exponent);
+        Duration::from_secs_f64(delay.min(self.max_delay.as_secs_f64()))
+    }
+
+    /// How long to wait before retrying after `error` failed the `attempt` started `elapsed`
+    /// after the first one, `None` if the error is final.
+    pub fn retry_delay(&self, attempt: usize, elapsed: Duration, error: &DvlClientError) -> Option<Duration> {
+        if attempt >= self.max_attempts || !(self.retry_if)(error) {
+            return None;
+        }
+        let delay = self.backoff(attempt);
+        let jitter = self.jitter.clamp(0.0, 1.0);
+        let delay = match jitter > 0.0 {
+            true => delay.mul_f64(rand::thread_rng().gen_range(1.0 - jitter..=1.0 + jitter)),
+            false => delay,
+        };
+        match self.max_elapsed {
+            Some(max_elapsed) if elapsed + delay > max_elapsed => None,
+            _ => Some(delay),
+        }
+    }
+
+    /// Runs `operation` until it succeeds or fails with an error not to retry, returns the last
+    /// error then.
+    pub async fn run<T, F, Fut>(&self, mut operation: F) -> DvlClientResult<T>
+        where
+            F: FnMut() -> Fut,
+            Fut: Future<Output = DvlClientResult<T>>,
+    {
+        let start = Instant::now();
+        let mut attempt = 1;
+        loop {
+            let error = match operation().await {
+                Ok(value) => return Ok(value),
+                Err(error) => error,
+            };
+            match self.retry_delay(attempt, start.elapsed(), &error) {
+                Some(delay) => tokio::time::sleep(delay).await,
+                None => return Err(error),
+            }
+            attempt += 1;
+        }
+    }
+}
+
+/// Errors which may not happen again: network failures, overloaded or lagging nodes, expired
+/// blockhashes and transactions the cluster could not schedule. The DeVol program errors, e.g.
+/// `CostLimitExceeded`, repeat for the same transaction and are final.
+pub fn is_retryable(error: &DvlClientError) -> bool {
+    match error {
+        DvlClientError::Rpc(error) => match error.kind() {
+            ClientErrorKind::Io(_) | ClientErrorKind::Reqwest(_) => true,
+            ClientErrorKind::RpcError(RpcError::RpcRequestError(_)) => true,
+            ClientErrorKind::RpcError(RpcError::RpcResponseError { code, .. }) => matches!(
+                *code,
+                JSON_RPC_SERVER_ERROR_NODE_UNHEALTHY
+                    | JSON_RPC_SERVER_ERROR_MIN_CONTEXT_SLOT_NOT_REACHED
+                    | JSON_RPC_SERVER_ERROR_BLOCK_NOT_AVAILABLE
+            ),
+            _ => false,
+        },
+        DvlClientError::Simulation { error, .. } => matches!(
+            error,
+            TransactionError::AccountInUse
+                | TransactionError::WouldExceedMaxBlockCostLimit
+                | TransactionError::WouldExceedMaxAccountCostLimit
+                | TransactionError::WouldExceedAccountDataBlockLimit
+                | TransactionError::ClusterMaintenance
+        ),
+        DvlClientError::BlockhashExpired | DvlClientError::Timeout => true,
+        DvlClientError::Contract(..)
+        | DvlClientError::Unconfirmed(_)
+        | DvlClientError::Signing(_)
+        | DvlClientError::Decode(_)
+        | DvlClientError::Request(_)
+        | DvlClientError::Ambiguous(_) => false,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use std::sync::Arc;
+    use std::sync::atomic::{AtomicUsize, Ordering};
+    use async_trait::async_trait;
+    use solana_account_decoder::UiDataSliceConfig;
+    use solana_client::client_error::{ClientError, ClientErrorKind, Result as ClientResult};
+    use solana_client::rpc_filter::RpcFilterType;
+    use solana_client::rpc_response::RpcSimulateTransactionResult;
+    use solana_program::hash::Hash;
+    use solana_program::instruction::{Instruction, InstructionError};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::account::Account;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::signature::{Keypair, Signature};
+    use solana_sdk::signer::Signer;
+    use solana_sdk::transaction::{self, Transaction};
+    use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+    use crate::dvl_client::account_source::AccountSource;
+    use crate::dvl_client::dvl_client::{DvlClient, DvlSendTransactionParams};
+    use crate::dvl_error::DvlError;
+    use crate::errors::{AccountTag, ContractError};
+    use crate::fixtures::devol_account_builder::FIXTURE_PROGRAM_ID;
+
+    #[test]
+    fn test_retry_delays() {
+        let policy = RetryPolicy { jitter: 0.0, ..RetryPolicy::default() };
+        assert_eq!(policy.backoff(1), Duration::from_millis(500));
+        assert_eq!(policy.backoff(3), Duration::from_secs(2));
+        assert_eq!(policy.backoff(10), Duration::from_secs(8));
+        let expired = DvlClientError::BlockhashExpired;
+        assert_eq!(policy.retry_delay(2, Duration::ZERO, &expired), Some(Duration::from_secs(1)));
+        assert_eq!(policy.retry_delay(5, Duration::ZERO, &expired), None);
+        assert_eq!(policy.retry_delay(2, Duration::from_millis(59_500), &expired), None);
+        let cost = DvlClientError::Contract(DvlError::new(ContractError::CostLimitExceeded), Some(0));
+        assert_eq!(policy.retry_delay(1, Duration::ZERO, &cost), None);
+
+        let jittered = RetryPolicy::default();
+        for _ in 0..20 {
+            let delay = jittered.retry_delay(1, Duration::ZERO, &expired).unwrap();
+            assert!(delay >= Duration::from_millis(400) && delay <= Duration::from_millis(600));
+        }
+    }
+
+    #[tokio::test]
+    async fn test_run() {
+        let policy = RetryPolicy::fixed(3, Duration::ZERO);
+        let calls = AtomicUsize::new(0);
+        let result = policy.run(|| async {
+            match calls.fetch_add(1, Ordering::Relaxed) {
+                0 => Err(DvlClientError::Timeout),
+                call => Ok(call),
+            }
+        }).await;
+        assert_eq!(result.unwrap(), 1);
+
+        calls.store(0, Ordering::Relaxed);
+        let result: DvlClientResult<()> = policy.run(|| async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err(DvlClientError::Contract(DvlError::new_with_account(AccountTag::Client, ContractError::AccountOwner), None))
+        }).await;
+        assert!(matches!(result, Err(DvlClientError::Contract(..))));
+        assert_eq!(calls.load(Ordering::Relaxed), 1);
+
+        calls.store(0, Ordering::Relaxed);
+        let result: DvlClientResult<()> = policy.run(|| async {
+            calls.fetch_add(1, Ordering::Relaxed);
+            Err(DvlClientError::Timeout)
+        }).await;
+        assert!(matches!(result, Err(DvlClientError::Timeout)));
+        assert_eq!(calls.load(Ordering::Relaxed), 3);
+    }
+
+    #[tokio::test]
+    async fn test_send_retries() {
+        let (source, client) = setup_memory_client();
+        let client = client.with_retry_policy(RetryPolicy::fixed(3, Duration::ZERO));
+        let payer = Arc::new(Keypair::new());
+        let payer_key = payer.pubkey();
+        let signatures = Arc::new(AtomicUsize::new(0));
+        let params = || {
+            let (signer_payer, signatures) = (payer.clone(), signatures.clone());
+            DvlSendTransactionParams::new(
+                vec![Instruction::new_with_bytes(client.program_id, &[1], vec![])],
+                &payer_key,
+                Box::new(move |transaction, hash| {
+                    signatures.fetch_add(1, Ordering::Relaxed);
+                    Ok(transaction.try_sign(&[&*signer_payer], hash)?)
+                }),
+            )
+        };
+
+        source.fail_next_send(RpcError::RpcRequestError("connection reset".to_string()).into());
+        source.fail_next_send(RpcError::ForUser("unable to confirm transaction".to_string()).into());
+        client.send_transaction(params()).await.unwrap();
+        assert_eq!(source.sent_transactions().len(), 1);
+        assert_eq!(signatures.load(Ordering::Relaxed), 2);
+
+        let code = DvlError::new(ContractError::CostLimitExceeded).encode();
+        let failed: ClientError = TransactionError::InstructionError(0, InstructionError::Custom(code)).into();
+        source.fail_next_send(failed);
+        let error = client.send_transaction(params()).await.err().unwrap();
+        assert!(matches!(error, DvlClientError::Contract(_, Some(0))));
+        assert_eq!(source.sent_transactions().len(), 1);
+
+        source.fail_next_send(RpcError::RpcRequestError("connection reset".to_string()).into());
+        let mut once = params();
+        once.max_retries = Some(1);
+        assert!(matches!(client.send_transaction(once).await, Err(DvlClientError::Rpc(_))));
+    }
+
+    /// Source whose account reads all fail with a retryable error, counting them.
+    #[derive(Default)]
+    struct FailingReads {
+        reads: AtomicUsize,
+    }
+
+    impl FailingReads {
+        fn read_error(&self) -> ClientError {
+            self.reads.fetch_add(1, Ordering::Relaxed);
+            RpcError::RpcRequestError("connection reset".to_string()).into()
+        }
+
+        fn unsupported() -> ClientError {
+            ClientErrorKind::Custom("The source only fails reads".to_string()).into()
+        }
+    }
+
+    #[async_trait]
+    impl AccountSource for FailingReads {
+        async fn get_account_with_slot(&self, _public_key: &Pubkey) -> ClientResult<(u64, Option<Account>)> {
+            Err(self.read_error())
+        }
+
+        async fn get_multiple_accounts_with_config(
+            &self,
+            _public_keys: &[Pubkey],
+            _commitment: CommitmentConfig,
+            _min_context_slot: Option<u64>,
+        ) -> ClientResult<(u64, Vec<Option<Account>>)> {
+            Err(self.read_error())
+        }
+
+        async fn get_program_accounts(
+            &self,
+            _program_id: &Pubkey,
+            _filters: Vec<RpcFilterType>,
+            _data_slice: Option<UiDataSliceConfig>,
+        ) -> ClientResult<Vec<(Pubkey, Account)>> {
+            Err(self.read_error())
+        }
+
+        async fn get_latest_blockhash(&self, _commitment: CommitmentConfig) -> ClientResult<Hash> {
+            Err(FailingReads::unsupported())
+        }
+
+        async fn send_and_confirm_transaction(&self, _transaction: &Transaction, _commitment: CommitmentConfig) -> ClientResult<Signature> {
+            Err(FailingReads::unsupported())
+        }
+
+        async fn get_signature_statuses(
+            &self,
+            _signatures: &[Signature],
+            _commitment: CommitmentConfig,
+        ) -> ClientResult<Vec<Option<transaction::Result<()>>>> {
+            Err(FailingReads::unsupported())
+        }
+
+        async fn simulate_transaction(&self, _transaction: &Transaction, _commitment: CommitmentConfig) -> ClientResult<RpcSimulateTransactionResult> {
+            Err(FailingReads::unsupported())
+        }
+    }
+
+    #[tokio::test]
+    async fn test_reads_retry_once() {
+        let source = Arc::new(FailingReads::default());
+        let client = DvlClient::with_source(source.clone(), 1, Pubkey::new_unique(), FIXTURE_PROGRAM_ID)
+            .with_retry_policy(RetryPolicy::fixed(3, Duration::ZERO));
+        assert!(matches!(client.get_platform_accounts().await, Err(DvlClientError::Rpc(_))));
+        assert_eq!(source.reads.swap(0, Ordering::Relaxed), 3);
+        assert!(client.get_account::<InstrumentsAccount>(()).await.is_err());
+        assert_eq!(source.reads.swap(0, Ordering::Relaxed), 3);
+        assert!(client.platform_snapshot().await.is_err());
+        assert_eq!(source.reads.swap(0, Ordering::Relaxed), 3);
+        assert!(client.find_client_by_id(1).await.is_err());
+        assert_eq!(source.reads.swap(0, Ordering::Relaxed), 3);
+    }
+}
@@ -0,0 +1,164 @@
+use std::error::Error;
+use std::marker::PhantomData;
+use solana_account_decoder::UiDataSliceConfig;
+use solana_client::rpc_filter::{Memcmp, RpcFilterType};
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::client::client_account::client_account::{CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET, ClientAccount};
+use crate::accounts::client::trade_log::trade_log_account::{TRADE_LOG_ACCOUNT_MAIN_ADDRESS_OFFSET, TradeLogAccount};
+use crate::accounts::devol_account::DevolAccount;
+use crate::dvl_client::dvl_client::DvlClient;
+
+pub const HEADER_TAG_OFFSET: usize = 0;
+pub const HEADER_ROOT_OFFSET: usize = 8;
+
+/// Accounts holding the address of the account they belong to, which `DvlScan::owner` matches.
+pub trait DvlOwnedAccount: DevolAccount {
+    fn owner_offset() -> usize;
+}
+
+/// Owned by the wallet of the client.
+impl DvlOwnedAccount for ClientAccount {
+    fn owner_offset() -> usize { CLIENT_ACCOUNT_OWNER_ADDRESS_OFFSET }
+}
+
+/// Owned by the client account.
+impl DvlOwnedAccount for TradeLogAccount {
+    fn owner_offset() -> usize { TRADE_LOG_ACCOUNT_MAIN_ADDRESS_OFFSET }
+}
+
+/// `getProgramAccounts` request for the accounts of type `T` under the root of the client,
+/// selected by the tag and the root in their header.
+pub struct DvlScan<'a, T> {
+    client: &'a DvlClient,
+    filters: Vec<RpcFilterType>,
+    _account: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: DvlReadable + DevolAccount> DvlScan<'a, T> {
+    pub(crate) fn new(client: &'a DvlClient) -> Self {
+        let tag = T::expected_tag() as u32;
+        Self {
+            client,
+            filters: vec![
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(HEADER_TAG_OFFSET, &tag.to_le_bytes())),
+                RpcFilterType::Memcmp(Memcmp::new_base58_encoded(HEADER_ROOT_OFFSET, client.root_pda.key.as_ref())),
+            ],
+            _account: PhantomData,
+        }
+    }
+
+    /// Only the accounts with `bytes` at `offset`.
+    pub fn memcmp(mut self, offset: usize, bytes: &[u8]) -> Self {
+        self.filters.push(RpcFilterType::Memcmp(Memcmp::new_base58_encoded(offset, bytes)));
+        self
+    }
+
+    /// Only the accounts belonging to `owner`, see `DvlOwnedAccount`.
+    pub fn owner(self, owner: &Pubkey) -> Self
+        where
+            T: DvlOwnedAccount,
+    {
+        self.memcmp(T::owner_offset(), owner.as_ref())
+    }
+
+    /// Addresses of the accounts without their data, e.g. to count the client accounts.
+    pub async fn keys(&self) -> Result<Vec<Pubkey>, Box<dyn Error>> {
+        let data_slice = UiDataSliceConfig { offset: 0, length: 0 };
+        let accounts = self.client.source
+            .get_program_accounts(&self.client.program_id, self.filters.clone(), Some(data_slice))
+            .await?;
+        Ok(accounts.into_iter().map(|(public_key, _)| public_key).collect())
+    }
+
+    /// Downloads the accounts, which are decoded only when iterated.
+    pub async fn fetch(self) -> Result<DvlScanned<'a, T>, Box<dyn Error>> {
+        let accounts = self.client.source
+            .get_program_accounts(&self.client.program_id, self.filters, None)
+            .await?;
+        Ok(DvlScanned { client: self.client, accounts, _account: PhantomData })
+    }
+}
+
+/// Raw accounts found by `DvlScan`, iterated as `(public key, decoded account)` pairs.
+pub struct DvlScanned<'a, T> {
+    client: &'a DvlClient,
+    accounts: Vec<(Pubkey, Account)>,
+    _account: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: DvlReadable> DvlScanned<'a, T> {
+    pub fn len(&self) -> usize {
+        self.accounts.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.accounts.is_empty()
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Pubkey> {
+        self.accounts.iter().map(|(public_key, _)| public_key)
+    }
+}
+
+impl<'a, T: DvlReadable> IntoIterator for DvlScanned<'a, T> {
+    type Item = (Pubkey, Result<Box<T::DvlReadOutput>, Box<dyn Error>>);
+    type IntoIter = DvlScannedIter<'a, T>;
+
+    fn into_iter(self) -> Self::IntoIter {
+        DvlScannedIter { client: self.client, accounts: self.accounts.into_iter(), _account: PhantomData }
+    }
+}
+
+pub struct DvlScannedIter<'a, T> {
+    client: &'a DvlClient,
+    accounts: std::vec::IntoIter<(Pubkey, Account)>,
+    _account: PhantomData<fn() -> T>,
+}
+
+impl<T: DvlReadable> Iterator for DvlScannedIter<'_, T> {
+    type Item = (Pubkey, Result<Box<T::DvlReadOutput>, Box<dyn Error>>);
+
+    fn next(&mut self) -> Option<Self::Item> {
+        let (public_key, mut rpc_data) = self.accounts.next()?;
+        Some((public_key, T::from_rpc_account(self.client, &public_key, &mut rpc_data)))
+    }
+
+    fn size_hint(&self) -> (usize, Option<usize>) {
+        self.accounts.size_hint()
+    }
+}
+
+impl<T: DvlReadable> ExactSizeIterator for DvlScannedIter<'_, T> {}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::accounts::worker::worker_account::WorkerAccount;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    #[tokio::test]
+    async fn test_scan_client_accounts() {
+        let (source, client) = setup_memory_client();
+        let root = client.root_pda.key;
+        let owner = Pubkey::new_unique();
+        for id in 0..3 {
+            source.insert_fixture(&ClientAccountBuilder::new().root(root).id(id).owner_address(owner).build());
+        }
+        source.insert_fixture(&ClientAccountBuilder::new().root(root).id(3).with_pools(2).build());
+        source.insert_fixture(&ClientAccountBuilder::new().root(Pubkey::new_unique()).owner_address(owner).build());
+        source.insert_fixture(&ClientAccountBuilder::new().root(root).program_id(Pubkey::new_unique()).build());
+        source.insert_fixture(&DevolAccountBuilder::<WorkerAccount>::new().root(root).build());
+
+        assert_eq!(client.scan::<ClientAccount>().keys().await.unwrap().len(), 4);
+        let scanned = client.scan::<ClientAccount>().owner(&owner).fetch().await.unwrap();
+        assert_eq!(scanned.len(), 3);
+        let mut ids: Vec<u32> = scanned.into_iter().map(|(_, account)| account.unwrap().id).collect();
+        ids.sort();
+        assert_eq!(ids, vec![0, 1, 2]);
+        assert_eq!(client.scan::<WorkerAccount>().fetch().await.unwrap().len(), 1);
+    }
+}
@@ -0,0 +1,161 @@
+use solana_client::rpc_response::RpcSimulateTransactionResult;
+use solana_program::instruction::InstructionError;
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::transaction::TransactionError;
+use crate::dvl_error::DvlError;
+
+/// Compute unit limit of the simulations measuring the units of a transaction, the most a
+/// transaction may request.
+pub const SIMULATION_COMPUTE_UNIT_LIMIT: u32 = 1_400_000;
+
+/// Why a simulated transaction failed.
+#[derive(Clone, Debug)]
+pub enum DvlSimulationError {
+    /// The DeVol program returned `error` from the instruction at `instruction_index`.
+    Contract { instruction_index: u8, error: DvlError },
+    /// Any other failure, e.g. of another program or of the transaction checks.
+    Transaction(TransactionError),
+}
+
+/// Outcome of `DvlClient::simulate_transaction`.
+#[derive(Clone, Debug)]
+pub struct DvlSimulation {
+    pub error: Option<DvlSimulationError>,
+    pub logs: Vec<String>,
+    pub units_consumed: Option<u64>,
+}
+
+impl DvlSimulation {
+    pub fn is_ok(&self) -> bool {
+        self.error.is_none()
+    }
+
+    /// Failing instruction index and the DeVol error it returned.
+    pub fn dvl_error(&self) -> Option<(u8, DvlError)> {
+        match self.error {
+            Some(DvlSimulationError::Contract { instruction_index, error }) => Some((instruction_index, error)),
+            _ => None,
+        }
+    }
+
+    /// Compute unit limit of the simulated units plus `margin_percent` of them, `None` if the
+    /// units are unknown.
+    pub fn compute_budget(&self, margin_percent: u32) -> Option<u32> {
+        let units = self.units_consumed?;
+        let budget = units.saturating_mul(100 + margin_percent as u64).div_ceil(100);
+        Some(budget.min(SIMULATION_COMPUTE_UNIT_LIMIT as u64) as u32)
+    }
+}
+
+impl DvlSimulation {
+    /// Outcome of simulating `message`, whose instructions calling `program_id` are the DeVol ones.
+    pub fn from_result(result: RpcSimulateTransactionResult, message: &Message, program_id: &Pubkey) -> Self {
+        Self {
+            error: result.err.map(|error| decode_transaction_error(error, message, program_id)),
+            logs: result.logs.unwrap_or_default(),
+            units_consumed: result.units_consumed,
+        }
+    }
+}
+
+/// Decodes the custom error of an instruction of `message` as a `DvlError` if the instruction
+/// calls `program_id`, the codes of the other programs mean something else.
+pub fn decode_transaction_error(error: TransactionError, message: &Message, program_id: &Pubkey) -> DvlSimulationError {
+    match error {
+        TransactionError::InstructionError(instruction_index, InstructionError::Custom(code))
+            if message.program_id(instruction_index as usize) == Some(program_id) =>
+        {
+            DvlSimulationError::Contract { instruction_index, error: DvlError::from_code(code) }
+        }
+        error => DvlSimulationError::Transaction(error),
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use solana_program::instruction::{AccountMeta, Instruction};
+    use solana_program::pubkey::Pubkey;
+    use solana_sdk::compute_budget::ComputeBudgetInstruction;
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
+    use crate::dvl_client::dvl_client::DvlSendTransactionParams;
+    use crate::errors::{AccountTag, ContractError};
+
+    fn simulation_result(err: Option<TransactionError>, units_consumed: Option<u64>) -> RpcSimulateTransactionResult {
+        RpcSimulateTransactionResult {
+            err,
+            logs: Some(vec!["Program log: failed".to_string()]),
+            accounts: None,
+            units_consumed,
+            return_data: None,
+            inner_instructions: None,
+        }
+    }
+
+    #[test]
+    fn test_simulation_result() {
+        let program_id = Pubkey::new_unique();
+        let payer = Pubkey::new_unique();
+        let message = Message::new(&[
+            ComputeBudgetInstruction::set_compute_unit_limit(1000),
+            Instruction::new_with_bytes(program_id, &[1], vec![]),
+        ], Some(&payer));
+        let code = DvlError::new_with_account(AccountTag::Client, ContractError::AccountIsMissing).encode();
+        let error = TransactionError::InstructionError(1, InstructionError::Custom(code));
+        let simulation = DvlSimulation::from_result(simulation_result(Some(error), Some(1000)), &message, &program_id);
+        assert!(!simulation.is_ok());
+        let (index, error) = simulation.dvl_error().unwrap();
+        assert_eq!((index, error.encode()), (1, code));
+        assert_eq!(simulation.logs.len(), 1);
+        assert_eq!(simulation.compute_budget(15), Some(1150));
+
+        let huge = DvlSimulation { error: None, logs: vec![], units_consumed: Some(1_300_000) };
+        assert_eq!(huge.compute_budget(20), Some(SIMULATION_COMPUTE_UNIT_LIMIT));
+        assert!(matches!(
+            decode_transaction_error(TransactionError::AccountNotFound, &message, &program_id),
+            DvlSimulationError::Transaction(TransactionError::AccountNotFound),
+        ));
+        let budget_error = TransactionError::InstructionError(0, InstructionError::Custom(code));
+        assert!(matches!(
+            decode_transaction_error(budget_error, &message, &program_id),
+            DvlSimulationError::Transaction(TransactionError::InstructionError(0, InstructionError::Custom(_))),
+        ));
+    }
+
+    #[tokio::test]
+    async fn test_send_with_simulated_budget() {
+        let (source, client) = setup_memory_client();
+        let payer = Arc::new(Keypair::new());
+        let payer_key = payer.pubkey();
+        let params = || {
+            let signer_payer = payer.clone();
+            let instruction = Instruction::new_with_bytes(client.program_id, &[1], vec![AccountMeta::new(Pubkey::new_unique(), false)]);
+            DvlSendTransactionParams {
+                compute_budget_margin: Some(10),
+                ..DvlSendTransactionParams::new(
+                    vec![instruction],
+                    &payer_key,
+                    Box::new(move |transaction, hash| Ok(transaction.try_sign(&[&*signer_payer], hash)?)),
+                )
+            }
+        };
+
+        let code = DvlError::new_with_account(AccountTag::Worker, ContractError::InvalidAccountId).encode();
+        source.set_simulation(simulation_result(Some(TransactionError::InstructionError(0, InstructionError::Custom(code))), Some(500)));
+        let simulation = client.simulate_transaction(&params()).await.unwrap();
+        assert_eq!(simulation.dvl_error().unwrap().1.encode(), code);
+        let limit = ComputeBudgetInstruction::set_compute_unit_limit(SIMULATION_COMPUTE_UNIT_LIMIT);
+        assert_eq!(source.simulated_transactions()[0].message.instructions[1].data, limit.data);
+        assert!(client.send_transaction(params()).await.is_err());
+        assert!(source.sent_transactions().is_empty());
+
+        source.set_simulation(simulation_result(None, Some(2000)));
+        client.send_transaction(params()).await.unwrap();
+        let limit = ComputeBudgetInstruction::set_compute_unit_limit(2200);
+        assert_eq!(source.sent_transactions()[0].message.instructions[1].data, limit.data);
+    }
+}
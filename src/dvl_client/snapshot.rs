@@ -0,0 +1,120 @@
+use std::error::Error;
+use solana_client::rpc_request::MAX_MULTIPLE_ACCOUNTS;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::accounts::devol_any_account::DevolAnyAccount;
+use crate::dvl_client::account_source::account_not_found;
+use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_client::dvl_client_error::DvlClientError;
+use crate::dvl_error::DvlError;
+
+#[derive(Clone, Copy, Debug, Default)]
+pub struct DvlSnapshotConfig {
+    /// Commitment of the read, the one of the source if not set.
+    pub commitment: Option<CommitmentConfig>,
+    /// The read fails if the node has not reached this slot yet.
+    pub min_context_slot: Option<u64>,
+}
+
+/// Accounts read in a single `getMultipleAccounts` request, so they all show the state at `slot`.
+pub struct DvlSnapshot<'a> {
+    client: &'a DvlClient,
+    slot: u64,
+    commitment: CommitmentConfig,
+    accounts: Vec<(Pubkey, Option<Account>)>,
+}
+
+impl<'a> DvlSnapshot<'a> {
+    /// Up to `MAX_MULTIPLE_ACCOUNTS` accounts, more do not fit in one request.
+    pub(crate) async fn read(
+        client: &'a DvlClient,
+        public_keys: &[Pubkey],
+        config: DvlSnapshotConfig,
+    ) -> Result<Self, Box<dyn Error>> {
+        if public_keys.len() > MAX_MULTIPLE_ACCOUNTS {
+            let message = format!(
+                "Snapshot of {} accounts exceeds {} accounts of one request",
+                public_keys.len(),
+                MAX_MULTIPLE_ACCOUNTS,
+            );
+            return Err(Box::new(DvlClientError::Request(message)));
+        }
+        let commitment = config.commitment.unwrap_or_else(|| client.source.commitment());
+        let (slot, accounts) = client.source
+            .get_multiple_accounts_with_config(public_keys, commitment, config.min_context_slot)
+            .await?;
+        Ok(Self { client, slot, commitment, accounts: public_keys.iter().copied().zip(accounts).collect() })
+    }
+
+    /// Context slot of the read.
+    pub fn slot(&self) -> u64 {
+        self.slot
+    }
+
+    pub fn keys(&self) -> impl Iterator<Item = &Pubkey> {
+        self.accounts.iter().map(|(public_key, _)| public_key)
+    }
+
+    /// Raw account, `None` if it does not exist or is not in the snapshot.
+    pub fn raw(&self, public_key: &Pubkey) -> Option<&Account> {
+        self.accounts.iter().find(|(key, _)| key == public_key)?.1.as_ref()
+    }
+
+    /// The account decoded as `T` with the checks of `T::from_rpc_account`.
+    pub fn get<T: DvlReadable>(&self, public_key: &Pubkey) -> Result<Box<T::DvlReadOutput>, Box<dyn Error>> {
+        let mut rpc_data = self.raw(public_key).cloned().ok_or_else(|| account_not_found(public_key))?;
+        T::from_rpc_account(self.client, public_key, &mut rpc_data)
+    }
+
+    /// The account decoded by its tag, `None` if it does not exist or is not in the snapshot.
+    pub fn get_any(&self, public_key: &Pubkey) -> Option<Result<DevolAnyAccount, DvlError>> {
+        self.raw(public_key).map(|rpc_data| self.client.decode_batch_account(rpc_data))
+    }
+
+    /// Reads more accounts at the same commitment from a node which has reached the slot of this
+    /// snapshot, so they are not older than the accounts already read.
+    pub async fn follow_up(&self, public_keys: &[Pubkey]) -> Result<DvlSnapshot<'a>, Box<dyn Error>> {
+        let config = DvlSnapshotConfig { commitment: Some(self.commitment), min_context_slot: Some(self.slot) };
+        Self::read(self.client, public_keys, config).await
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use crate::accounts::client::client_account::client_account::ClientAccount;
+    use crate::accounts::worker::worker_account::WorkerAccount;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    #[tokio::test]
+    async fn test_snapshot() {
+        let (source, client) = setup_memory_client();
+        let worker = DevolAccountBuilder::<WorkerAccount>::new().root(client.root_pda.key).with(|worker| worker.id = 4).build();
+        let client_account = ClientAccountBuilder::new().root(client.root_pda.key).id(9).build();
+        source.insert_fixture(&worker);
+        source.insert_fixture(&client_account);
+        source.set_slot(20);
+
+        let missing = Pubkey::new_unique();
+        let snapshot = client.snapshot(&[worker.key, client_account.key, missing], DvlSnapshotConfig::default()).await.unwrap();
+        assert_eq!(snapshot.slot(), 20);
+        assert_eq!(snapshot.get::<WorkerAccount>(&worker.key).unwrap().id, 4);
+        assert_eq!(snapshot.get::<ClientAccount>(&client_account.key).unwrap().id, 9);
+        assert!(snapshot.get::<WorkerAccount>(&client_account.key).is_err());
+        assert!(snapshot.get::<WorkerAccount>(&missing).is_err());
+        assert!(snapshot.get_any(&missing).is_none());
+        assert!(matches!(snapshot.get_any(&worker.key), Some(Ok(DevolAnyAccount::Worker(_)))));
+
+        source.set_slot(19);
+        assert!(snapshot.follow_up(&[worker.key]).await.is_err());
+        source.set_slot(21);
+        assert_eq!(snapshot.follow_up(&[worker.key]).await.unwrap().slot(), 21);
+
+        let keys = vec![Pubkey::new_unique(); MAX_MULTIPLE_ACCOUNTS + 1];
+        assert!(client.snapshot(&keys, DvlSnapshotConfig::default()).await.is_err());
+    }
+}
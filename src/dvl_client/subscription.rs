@@ -0,0 +1,246 @@
+use std::error::Error;
+use std::marker::PhantomData;
+use std::pin::Pin;
+use std::sync::Arc;
+use std::task::{Context, Poll};
+use std::time::Duration;
+use futures::{Stream, StreamExt};
+use solana_account_decoder::UiAccountEncoding;
+use solana_client::nonblocking::pubsub_client::PubsubClient;
+use solana_client::rpc_config::RpcAccountInfoConfig;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::account::Account;
+use solana_sdk::commitment_config::CommitmentConfig;
+use tokio::sync::mpsc;
+use tokio::task::JoinHandle;
+use url::Url;
+use crate::account_readers::dvl_readable::DvlReadable;
+use crate::dvl_client::account_source::AccountSource;
+use crate::dvl_client::dvl_client::DvlClient;
+
+/// Delay before subscribing again after the websocket connection is lost.
+pub const RESUBSCRIBE_DELAY: Duration = Duration::from_secs(1);
+
+type SubscriptionError = Box<dyn Error + Send + Sync>;
+type RawUpdate = Result<(u64, Account), SubscriptionError>;
+
+/// Decoded account state pushed by `DvlSubscription`.
+pub struct DvlAccountUpdate<T> {
+    pub slot: u64,
+    pub public_key: Pubkey,
+    pub account: Box<T>,
+}
+
+/// Stream of the states of one account from `accountSubscribe`. The subscription is renewed after
+/// a disconnect, and the current state is read right after every subscribe so that the changes
+/// made while disconnected are not lost. States not newer than the last one returned are skipped.
+/// Connection errors are returned as items and the stream goes on; it ends when dropped.
+pub struct DvlSubscription<'a, T: DvlReadable> {
+    client: &'a DvlClient,
+    public_key: Pubkey,
+    receiver: mpsc::UnboundedReceiver<RawUpdate>,
+    task: JoinHandle<()>,
+    last_slot: Option<u64>,
+    _account: PhantomData<fn() -> T>,
+}
+
+impl<'a, T: DvlReadable> DvlSubscription<'a, T> {
+    pub(crate) fn new(client: &'a DvlClient, websocket_url: String, public_key: Pubkey) -> Self {
+        let (sender, receiver) = mpsc::unbounded_channel();
+        let task = tokio::spawn(run_subscription(
+            websocket_url,
+            public_key,
+            client.source.clone(),
+            client.source.commitment(),
+            sender,
+        ));
+        Self { client, public_key, receiver, task, last_slot: None, _account: PhantomData }
+    }
+
+    pub fn public_key(&self) -> &Pubkey {
+        &self.public_key
+    }
+}
+
+impl<T: DvlReadable> Stream for DvlSubscription<'_, T> {
+    type Item = Result<DvlAccountUpdate<T::DvlReadOutput>, Box<dyn Error>>;
+
+    fn poll_next(self: Pin<&mut Self>, cx: &mut Context<'_>) -> Poll<Option<Self::Item>> {
+        let this = self.get_mut();
+        loop {
+            let (slot, mut rpc_data) = match this.receiver.poll_recv(cx) {
+                Poll::Ready(Some(Ok(update))) => update,
+                Poll::Ready(Some(Err(e))) => return Poll::Ready(Some(Err(e))),
+                Poll::Ready(None) => return Poll::Ready(None),
+                Poll::Pending => return Poll::Pending,
+            };
+            if this.last_slot.is_some_and(|last_slot| slot <= last_slot) {
+                continue;
+            }
+            this.last_slot = Some(slot);
+            let update = T::from_rpc_account(this.client, &this.public_key, &mut rpc_data)
+                .map(|account| DvlAccountUpdate { slot, public_key: this.public_key, account });
+            return Poll::Ready(Some(update));
+        }
+    }
+}
+
+impl<T: DvlReadable> Drop for DvlSubscription<'_, T> {
+    fn drop(&mut self) {
+        self.task.abort();
+    }
+}
+
+async fn run_subscription(
+    websocket_url: String,
+    public_key: Pubkey,
+    source: Arc<dyn AccountSource>,
+    commitment: CommitmentConfig,
+    sender: mpsc::UnboundedSender<RawUpdate>,
+) {
+    loop {
+        if let Err(e) = subscribe_once(&websocket_url, &public_key, &*source, commitment, &sender).await {
+            if sender.send(Err(e)).is_err() {
+                return;
+            }
+        }
+        if sender.is_closed() {
+            return;
+        }
+        tokio::time::sleep(RESUBSCRIBE_DELAY).await;
+    }
+}
+
+/// Forwards the updates until the connection is closed.
+async fn subscribe_once(
+    websocket_url: &str,
+    public_key: &Pubkey,
+    source: &dyn AccountSource,
+    commitment: CommitmentConfig,
+    sender: &mpsc::UnboundedSender<RawUpdate>,
+) -> Result<(), SubscriptionError> {
+    let pubsub = PubsubClient::new(websocket_url).await?;
+    let config = RpcAccountInfoConfig {
+        encoding: Some(UiAccountEncoding::Base64),
+        commitment: Some(commitment),
+        ..RpcAccountInfoConfig::default()
+    };
+    let (mut notifications, _unsubscribe) = pubsub.account_subscribe(public_key, Some(config)).await?;
+
+    let (slot, account) = source.get_account_with_slot(public_key).await?;
+    if let Some(account) = account {
+        if sender.send(Ok((slot, account))).is_err() {
+            return Ok(());
+        }
+    }
+    while let Some(response) = notifications.next().await {
+        let Some(account) = response.value.decode::<Account>() else {
+            continue;
+        };
+        if sender.send(Ok((response.context.slot, account))).is_err() {
+            return Ok(());
+        }
+    }
+    Ok(())
+}
+
+/// Websocket URL of an RPC node the way the Solana CLI derives it: `ws` for `http`, `wss` for
+/// `https`, and the port next to an explicit non-default RPC one. Unparsable URLs are returned
+/// unchanged.
+pub fn websocket_url_for(rpc_url: &str) -> String {
+    let Ok(mut url) = Url::parse(rpc_url) else {
+        return rpc_url.to_string();
+    };
+    let scheme = match url.scheme() {
+        "https" => "wss",
+        "http" => "ws",
+        _ => return rpc_url.to_string(),
+    };
+    // Neither fails for an `http(s)` URL, both schemes are special and have a host.
+    let _ = url.set_scheme(scheme);
+    if let Some(port) = url.port().and_then(|port| port.checked_add(1)) {
+        let _ = url.set_port(Some(port));
+    }
+    url.to_string()
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use futures::SinkExt;
+    use serde_json::{json, Value};
+    use solana_account_decoder::UiAccount;
+    use tokio::net::TcpListener;
+    use tokio_tungstenite::tungstenite::Message;
+    use crate::accounts::worker::worker_account::WorkerAccount;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+    fn worker(client: &DvlClient, id: u32) -> Account {
+        DevolAccountBuilder::<WorkerAccount>::new()
+            .root(client.root_pda.key)
+            .with(|worker| worker.id = id)
+            .build()
+            .to_account()
+    }
+
+    /// Stand-in pubsub node: answers the subscribe request of each connection, pushes one account
+    /// notification and drops all the connections but the last.
+    async fn serve(listener: TcpListener, public_key: Pubkey, notifications: Vec<(u64, Account)>) {
+        let count = notifications.len();
+        for (i, (slot, account)) in notifications.into_iter().enumerate() {
+            let (stream, _) = listener.accept().await.unwrap();
+            let mut ws = tokio_tungstenite::accept_async(stream).await.unwrap();
+            let request: Value = loop {
+                if let Some(Ok(Message::Text(text))) = ws.next().await {
+                    break serde_json::from_str(&text).unwrap();
+                }
+            };
+            ws.send(Message::Text(json!({"jsonrpc": "2.0", "result": i + 1, "id": request["id"]}).to_string())).await.unwrap();
+            let value = UiAccount::encode(&public_key, &account, UiAccountEncoding::Base64, None, None);
+            let notification = json!({
+                "jsonrpc": "2.0",
+                "method": "accountNotification",
+                "params": {"result": {"context": {"slot": slot}, "value": value}, "subscription": i + 1},
+            });
+            ws.send(Message::Text(notification.to_string())).await.unwrap();
+            if i + 1 < count {
+                ws.close(None).await.unwrap();
+            } else {
+                std::future::pending::<()>().await;
+            }
+        }
+    }
+
+    #[tokio::test]
+    async fn test_subscription_resubscribes() {
+        let listener = TcpListener::bind("127.0.0.1:0").await.unwrap();
+        let address = listener.local_addr().unwrap();
+        let (source, client) = setup_memory_client();
+        let client = client.with_websocket_url(format!("ws://{}", address));
+        let public_key = Pubkey::new_unique();
+        source.set_slot(3);
+        source.insert(public_key, worker(&client, 1));
+        tokio::spawn(serve(listener, public_key, vec![(5, worker(&client, 2)), (7, worker(&client, 3))]));
+
+        let mut subscription = client.subscribe_by_public_key::<WorkerAccount>(&public_key).unwrap();
+        let mut updates = Vec::new();
+        while updates.len() < 3 {
+            let update = tokio::time::timeout(Duration::from_secs(10), subscription.next()).await.unwrap().unwrap();
+            if let Ok(update) = update {
+                updates.push((update.slot, update.account.id));
+            }
+        }
+        assert_eq!(updates, vec![(3, 1), (5, 2), (7, 3)]);
+    }
+
+    #[test]
+    fn test_websocket_url_for() {
+        assert_eq!(websocket_url_for("http://127.0.0.1:8899"), "ws://127.0.0.1:8900/");
+        assert_eq!(websocket_url_for("https://api.devnet.solana.com"), "wss://api.devnet.solana.com/");
+        assert_eq!(websocket_url_for("https://rpc.example.com:443/key"), "wss://rpc.example.com/key");
+        assert_eq!(websocket_url_for("http://rpc.example.com:80/key?a=1"), "ws://rpc.example.com/key?a=1");
+        assert_eq!(websocket_url_for("https://rpc.example.com:8443"), "wss://rpc.example.com:8444/");
+        assert_eq!(websocket_url_for("http://127.0.0.1:65535"), "ws://127.0.0.1:65535/");
+    }
+}
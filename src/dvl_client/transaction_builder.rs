@@ -0,0 +1,222 @@
+use solana_program::instruction::{AccountMeta, Instruction};
+use solana_program::message::Message;
+use solana_program::pubkey::Pubkey;
+use solana_sdk::compute_budget::ComputeBudgetInstruction;
+use solana_sdk::signature::Signature;
+use crate::dvl_client::dvl_client_error::{DvlClientError, DvlClientResult};
+use crate::instructions_data::common::instruction_size_params::INSTRUCTION_SIZE_LIMIT;
+
+/// Composes DeVol instructions, e.g. several payoffs or an option trade with a token transfer, into
+/// as few transactions as fit the `INSTRUCTION_SIZE_LIMIT` packet. An account shared by several
+/// instructions is counted once. The instructions keep their order, each transaction takes as many
+/// of the next ones as fit, and the compute budget instructions are appended to every transaction.
+#[derive(Clone, Debug)]
+pub struct DvlTransactionBuilder {
+    payer: Pubkey,
+    instructions: Vec<Instruction>,
+    compute_budget: Option<u32>,
+    compute_unit_price: Option<u64>,
+}
+
+impl DvlTransactionBuilder {
+    pub fn new(payer: Pubkey) -> Self {
+        Self { payer, instructions: Vec::new(), compute_budget: None, compute_unit_price: None }
+    }
+
+    pub fn with_instruction(mut self, instruction: Instruction) -> Self {
+        self.push(instruction);
+        self
+    }
+
+    pub fn push(&mut self, instruction: Instruction) {
+        self.instructions.push(instruction);
+    }
+
+    /// Compute unit limit of each transaction.
+    pub fn compute_budget(mut self, max_units: u32) -> Self {
+        self.compute_budget = Some(max_units);
+        self
+    }
+
+    pub fn compute_unit_price(mut self, compute_unit_price: u64) -> Self {
+        self.compute_unit_price = Some(compute_unit_price);
+        self
+    }
+
+    pub fn payer(&self) -> &Pubkey {
+        &self.payer
+    }
+
+    pub fn instructions(&self) -> &[Instruction] {
+        &self.instructions
+    }
+
+    pub fn len(&self) -> usize {
+        self.instructions.len()
+    }
+
+    pub fn is_empty(&self) -> bool {
+        self.instructions.is_empty()
+    }
+
+    /// Accounts of all the instructions once each, the payer first, signer and writable if any
+    /// instruction needs them so.
+    pub fn account_metas(&self) -> Vec<AccountMeta> {
+        let mut metas = vec![AccountMeta::new(self.payer, true)];
+        for meta in self.instructions.iter().flat_map(|instruction| &instruction.accounts) {
+            match metas.iter_mut().find(|known| known.pubkey == meta.pubkey) {
+                Some(known) => {
+                    known.is_signer |= meta.is_signer;
+                    known.is_writable |= meta.is_writable;
+                }
+                None => metas.push(meta.clone()),
+            }
+        }
+        metas
+    }
+
+    /// Serialized size of a signed transaction of the instructions paid by the payer.
+    pub fn transaction_size(&self, instructions: &[Instruction]) -> usize {
+        let message = Message::new(instructions, Some(&self.payer));
+        let signatures = message.header.num_required_signatures as usize;
+        short_vec_size(signatures) + signatures * std::mem::size_of::<Signature>() + message.serialize().len()
+    }
+
+    /// Instructions of each transaction, the compute budget ones included. Fails if an instruction
+    /// does not fit a transaction alone.
+    pub fn build(&self) -> DvlClientResult<Vec<Vec<Instruction>>> {
+        let budget = self.budget_instructions();
+        let mut transactions = Vec::new();
+        let mut current: Vec<Instruction> = Vec::new();
+        for (index, instruction) in self.instructions.iter().enumerate() {
+            current.push(instruction.clone());
+            if self.fits(&current, &budget) {
+                continue;
+            }
+            current.pop();
+            if !current.is_empty() {
+                transactions.push(Self::with_budget(std::mem::take(&mut current), &budget));
+                current.push(instruction.clone());
+                if self.fits(&current, &budget) {
+                    continue;
+                }
+            }
+            let message = format!("Instruction {} does not fit a transaction of {} bytes", index, INSTRUCTION_SIZE_LIMIT);
+            return Err(DvlClientError::Request(message));
+        }
+        if !current.is_empty() {
+            transactions.push(Self::with_budget(current, &budget));
+        }
+        Ok(transactions)
+    }
+
+    fn budget_instructions(&self) -> Vec<Instruction> {
+        let mut instructions = Vec::new();
+        if let Some(max_units) = self.compute_budget {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_limit(max_units));
+        }
+        if let Some(compute_unit_price) = self.compute_unit_price {
+            instructions.push(ComputeBudgetInstruction::set_compute_unit_price(compute_unit_price));
+        }
+        instructions
+    }
+
+    fn with_budget(mut instructions: Vec<Instruction>, budget: &[Instruction]) -> Vec<Instruction> {
+        instructions.extend_from_slice(budget);
+        instructions
+    }
+
+    fn fits(&self, instructions: &[Instruction], budget: &[Instruction]) -> bool {
+        self.transaction_size(&Self::with_budget(instructions.to_vec(), budget)) <= INSTRUCTION_SIZE_LIMIT
+    }
+}
+
+/// Bytes of the compact-u16 length prefix of `len` items.
+fn short_vec_size(len: usize) -> usize {
+    match len {
+        0..=0x7f => 1,
+        0x80..=0x3fff => 2,
+        _ => 3,
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use std::sync::Arc;
+    use super::*;
+    use crate::tests::tests::setup_memory_client;
+    use solana_sdk::commitment_config::CommitmentConfig;
+    use solana_sdk::signature::Keypair;
+    use solana_sdk::signer::Signer;
+    use solana_sdk::transaction::Transaction;
+    use crate::dvl_client::dvl_client::DvlSendTransactionParams;
+
+    fn instruction(program_id: Pubkey, accounts: &[Pubkey], data_len: usize) -> Instruction {
+        let accounts = accounts.iter().map(|public_key| AccountMeta::new(*public_key, false)).collect();
+        Instruction::new_with_bytes(program_id, &vec![1; data_len], accounts)
+    }
+
+    #[test]
+    fn test_transaction_size() {
+        let payer = Keypair::new();
+        let builder = DvlTransactionBuilder::new(payer.pubkey());
+        let instructions = vec![instruction(Pubkey::new_unique(), &[Pubkey::new_unique(), Pubkey::new_unique()], 100)];
+        let mut transaction = Transaction::new_with_payer(&instructions, Some(&payer.pubkey()));
+        transaction.sign(&[&payer], Default::default());
+        assert_eq!(builder.transaction_size(&instructions), bincode::serialize(&transaction).unwrap().len());
+    }
+
+    #[test]
+    fn test_build_splits_minimally() {
+        let payer = Pubkey::new_unique();
+        let program_id = Pubkey::new_unique();
+        let shared = Pubkey::new_unique();
+        let mut builder = DvlTransactionBuilder::new(payer).compute_budget(200_000).compute_unit_price(10);
+        for _ in 0..5 {
+            builder.push(instruction(program_id, &[shared, Pubkey::new_unique()], 400));
+        }
+        assert_eq!(builder.account_metas().len(), 7);
+        let transactions = builder.build().unwrap();
+        assert_eq!(transactions.iter().map(|instructions| instructions.len()).collect::<Vec<_>>(), vec![4, 4, 3]);
+        for instructions in &transactions {
+            assert!(builder.transaction_size(instructions) <= INSTRUCTION_SIZE_LIMIT);
+        }
+
+        let accounts: Vec<Pubkey> = (0..25).map(|_| Pubkey::new_unique()).collect();
+        let shared_accounts = DvlTransactionBuilder::new(payer)
+            .with_instruction(instruction(program_id, &accounts, 0))
+            .with_instruction(instruction(program_id, &accounts, 0));
+        assert_eq!(shared_accounts.build().unwrap().len(), 1);
+        let other_accounts: Vec<Pubkey> = (0..25).map(|_| Pubkey::new_unique()).collect();
+        let distinct_accounts = shared_accounts.with_instruction(instruction(program_id, &other_accounts, 0));
+        assert_eq!(distinct_accounts.build().unwrap().len(), 2);
+
+        let too_large = DvlTransactionBuilder::new(payer).with_instruction(instruction(program_id, &[], INSTRUCTION_SIZE_LIMIT));
+        assert!(too_large.build().is_err());
+        assert!(DvlTransactionBuilder::new(payer).build().unwrap().is_empty());
+    }
+
+    #[tokio::test]
+    async fn test_send_transactions() {
+        let (source, client) = setup_memory_client();
+        let payer = Arc::new(Keypair::new());
+        let mut builder = DvlTransactionBuilder::new(payer.pubkey());
+        for _ in 0..3 {
+            builder.push(instruction(client.program_id, &[Pubkey::new_unique()], 400));
+        }
+        let signer_payer = payer.clone();
+        let payer_key = payer.pubkey();
+        let params = DvlSendTransactionParams {
+            commitment_config: Some(CommitmentConfig::confirmed()),
+            ..DvlSendTransactionParams::new(
+                vec![],
+                &payer_key,
+                Box::new(move |transaction, hash| Ok(transaction.try_sign(&[&*signer_payer], hash)?)),
+            )
+        };
+        let signatures = client.send_transactions(&builder, params).await.unwrap();
+        assert_eq!(signatures.len(), 2);
+        let sent = source.sent_transactions();
+        assert_eq!(sent.iter().map(|transaction| transaction.message.instructions.len()).collect::<Vec<_>>(), vec![2, 1]);
+    }
+}
@@ -1,3 +1,4 @@
+use serde::{Deserialize, Serialize};
 use std::fmt::{Debug};
 use strum_macros::{EnumIter, FromRepr};
 use thiserror::Error;
@@ -169,6 +170,10 @@ pub enum ContractError {
     InvalidBasketStrikeId       = 0x0050,
     #[error("Trading basket length is greater than Pool Log can save")]
     BasketLengthIsTooBig        = 0x0051,
+    #[error("Account data is not aligned for zero-copy access, copy the account out instead")]
+    AccountAlignment            = 0x0052,
+    #[error("Account field has a value out of its range")]
+    AccountFieldValue           = 0x0053,
 
     #[error("Test abort")]
     TestAbort                   = 0xFFFE,
@@ -179,7 +184,7 @@ pub enum ContractError {
 }
 
 #[repr(u8)]
-#[derive(Copy, Clone, Debug, EnumIter, FromRepr, PartialEq)]
+#[derive(Copy, Clone, Debug, EnumIter, FromRepr, PartialEq, Serialize, Deserialize)]
 pub enum AccountTag {
     // Equals to the account tags (WORKER_ACCOUNT_TAG, ROOT_ACCOUNT_TAG etc.)
     Root                = 0x00,
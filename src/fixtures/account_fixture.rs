@@ -0,0 +1,83 @@
+use solana_program::account_info::AccountInfo;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+
+/// Raw program account for tests: the key, the owner, the lamports and the data.
+///
+/// The data is backed by `u64` words to get the alignment of the on-chain account data, so
+/// `AccountInfo` references built from it pass the alignment checks of `AccountView`.
+#[derive(Clone, PartialEq, Debug)]
+pub struct AccountFixture {
+    pub key: Pubkey,
+    pub owner: Pubkey,
+    pub lamports: u64,
+    words: Vec<u64>,
+    len: usize,
+}
+
+impl AccountFixture {
+    /// Rent-exempt account holding a copy of `data`.
+    pub fn new(key: Pubkey, owner: Pubkey, data: &[u8]) -> Self {
+        let mut fixture = Self {
+            key,
+            owner,
+            lamports: Rent::default().minimum_balance(data.len()),
+            words: Vec::new(),
+            len: 0,
+        };
+        fixture.resize(data.len());
+        fixture.data_mut().copy_from_slice(data);
+        fixture
+    }
+
+    pub fn data(&self) -> &[u8] {
+        &self.padded_data()[..self.len]
+    }
+
+    pub fn data_mut(&mut self) -> &mut [u8] {
+        let len = self.len;
+        &mut self.padded_data_mut()[..len]
+    }
+
+    pub fn into_data(self) -> Vec<u8> {
+        self.data().to_vec()
+    }
+
+    /// Borrows the fixture as an `AccountInfo`, the way the program receives it.
+    pub fn account_info(&mut self, is_signer: bool, is_writable: bool) -> AccountInfo<'_> {
+        let data = unsafe { std::slice::from_raw_parts_mut(self.words.as_mut_ptr() as *mut u8, self.len) };
+        AccountInfo::new(&self.key, is_signer, is_writable, &mut self.lamports, data, &self.owner, false, 0)
+    }
+
+    /// Copy of the fixture as returned by the RPC client.
+    #[cfg(feature = "solana-sdk")]
+    pub fn to_account(&self) -> solana_sdk::account::Account {
+        solana_sdk::account::Account {
+            lamports: self.lamports,
+            data: self.data().to_vec(),
+            owner: self.owner,
+            executable: false,
+            rent_epoch: 0,
+        }
+    }
+
+    /// Changes the data length, new bytes are zeroed.
+    pub(crate) fn resize(&mut self, len: usize) {
+        self.words.resize(len.div_ceil(8), 0);
+        let padding = self.words.len() * 8 - len;
+        self.len = len;
+        let padded_data = self.padded_data_mut();
+        let padded_len = padded_data.len();
+        padded_data[padded_len - padding..].fill(0);
+    }
+
+    /// Data including the zeroed padding up to the 8-byte boundary. Views over accounts with a
+    /// trailing padding, like `ClientAccount`, need the whole `size_of` to hand out a reference.
+    pub(crate) fn padded_data_mut(&mut self) -> &mut [u8] {
+        unsafe { std::slice::from_raw_parts_mut(self.words.as_mut_ptr() as *mut u8, self.words.len() * 8) }
+    }
+
+    fn padded_data(&self) -> &[u8] {
+        unsafe { std::slice::from_raw_parts(self.words.as_ptr() as *const u8, self.words.len() * 8) }
+    }
+}
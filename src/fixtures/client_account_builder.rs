@@ -0,0 +1,84 @@
+use solana_program::pubkey::Pubkey;
+use crate::accounts::client::client_account::client_account::{CLIENT_ACCOUNT_SIZE, ClientAccount};
+use crate::accounts::client::client_account::client_pool::{CLIENT_POOL_SIZE, ClientPool};
+use crate::accounts::devol_account::DevolAccount;
+use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+pub type ClientAccountBuilder = DevolAccountBuilder<ClientAccount>;
+
+impl DevolAccountBuilder<ClientAccount> {
+    pub fn id(self, id: u32) -> Self {
+        self.with(|account| account.id = id)
+    }
+
+    pub fn owner_address(self, owner_address: Pubkey) -> Self {
+        self.with(|account| account.owner_address = owner_address)
+    }
+
+    pub fn signer_address(self, signer_address: Pubkey) -> Self {
+        self.with(|account| account.signer_address = signer_address)
+    }
+
+    /// Resizes the pools tail to `count` pools, the added pools are default ones.
+    pub fn with_pools(mut self, count: usize) -> Self {
+        let pools_count = self.pools_count();
+        self.fixture.resize(CLIENT_ACCOUNT_SIZE + count * CLIENT_POOL_SIZE);
+        self = self.with(|account| account.set_pools_count(count as u32));
+        for index in pools_count..count {
+            self = self.pool(index, &ClientPool::default());
+        }
+        self
+    }
+
+    /// Appends a pool to the pools tail.
+    pub fn push_pool(self, pool: &ClientPool) -> Self {
+        let index = self.pools_count();
+        self.with_pools(index + 1).pool(index, pool)
+    }
+
+    /// Replaces an existing pool.
+    pub fn pool(mut self, index: usize, pool: &ClientPool) -> Self {
+        ClientAccount::view_mut(self.fixture.data_mut()).unwrap().set_pool(index, pool).unwrap();
+        self
+    }
+
+    fn pools_count(&self) -> usize {
+        ClientAccount::view(self.fixture.data()).unwrap().get_pools_count() as usize
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
+    use crate::accounts::client::client_account::signer_account_params::SignerAccountParams;
+    use crate::fixtures::devol_account_builder::{FIXTURE_PROGRAM_ID, FIXTURE_ROOT_ADDRESS};
+
+    #[test]
+    fn test_client_account_builder_pools() {
+        let signer = Pubkey::new_unique();
+        let mut pool = ClientPool::default();
+        pool.instr_id = 7;
+        let mut fixture = ClientAccountBuilder::new()
+            .id(11)
+            .signer_address(signer)
+            .with_pools(2)
+            .push_pool(&pool)
+            .build();
+        assert_eq!(fixture.data().len(), CLIENT_ACCOUNT_SIZE + 3 * CLIENT_POOL_SIZE);
+
+        let account_info = fixture.account_info(true, false);
+        let signer_params = SignerAccountParams { signer: &signer, devol_sign: true };
+        let account = ClientAccount::from_account_info(&account_info, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID, Some(&signer_params)).unwrap();
+        assert_eq!(account.id, 11);
+        assert_eq!(account.get_pools_count(), 3);
+        drop(account);
+
+        let snapshot = ClientAccountSnapshot::from_account_data(fixture.data()).unwrap();
+        assert_eq!(snapshot.pools[1], ClientPool::default());
+        assert_eq!(snapshot.pools[2], pool);
+
+        let snapshot = ClientAccountSnapshot::from_account_data(ClientAccountBuilder::new().with_pools(3).with_pools(1).data()).unwrap();
+        assert_eq!(snapshot.get_pools_count(), 1);
+    }
+}
@@ -0,0 +1,193 @@
+use std::marker::PhantomData;
+use solana_program::pubkey::Pubkey;
+use solana_program::rent::Rent;
+use crate::accounts::account_header::AccountHeader;
+use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+use crate::accounts::client::client_account::client_account::ClientAccount;
+use crate::accounts::client::payoff_log::payoff_log_account::PayoffLogAccount;
+use crate::accounts::client::trade_log::trade_log_account::TradeLogAccount;
+use crate::accounts::devol_account::DevolAccount;
+use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+use crate::accounts::mints::mint_log::mint_log_account::MintLogAccount;
+use crate::accounts::mints::mints_account::MintsAccount;
+use crate::accounts::oracles::oracles_account::OraclesAccount;
+use crate::accounts::root::root_account::RootAccount;
+use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
+use crate::accounts::worker::pool_logs::v8::pools_log_account_v8::PoolLogsAccountV8;
+use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
+use crate::accounts::worker::tasks_log::tasks_log_account::TasksLogAccount;
+use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
+use crate::accounts::worker::worker_account::{WORKER_ACCOUNT_STATE_OFFSET, WorkerAccount};
+use crate::accounts::worker::worker_state::WorkerState;
+use crate::fixtures::account_fixture::AccountFixture;
+
+/// Devnet program which owns the fixture accounts by default.
+pub const FIXTURE_PROGRAM_ID: Pubkey = solana_program::pubkey!("2aJHohZdg4oaSuXGQzSDzZC3BJvEoN5JhpBu9GERiroo");
+/// Root account of the fixture accounts by default.
+pub const FIXTURE_ROOT_ADDRESS: Pubkey = solana_program::pubkey!("HrWYxhCJgJ6mpBpkF1yvfdMipHBXA7iciVmGaTTz1rqE");
+
+/// Account which can be built by `DevolAccountBuilder`.
+pub trait DevolAccountFixture: DevolAccount + Copy {
+    /// Makes the zeroed account data a valid `Self`, e.g. sets the enum fields which have no
+    /// zero variant.
+    fn init_data(_data: &mut [u8]) {}
+}
+
+/// Builds the data of a program account: zeroed `expected_size` bytes with the tag, the current
+/// version and the root in the header. The account is owned by `FIXTURE_PROGRAM_ID` and rooted at
+/// `FIXTURE_ROOT_ADDRESS` unless `program_id` and `root` say otherwise.
+pub struct DevolAccountBuilder<T> {
+    pub(crate) fixture: AccountFixture,
+    lamports: Option<u64>,
+    _account: PhantomData<T>,
+}
+
+impl<T: DevolAccountFixture> DevolAccountBuilder<T> {
+    pub fn new() -> Self {
+        let mut fixture = AccountFixture::new(Pubkey::new_unique(), FIXTURE_PROGRAM_ID, &vec![0; T::expected_size()]);
+        let header = AccountHeader {
+            tag: T::expected_tag() as u32,
+            version: T::expected_version(),
+            root: FIXTURE_ROOT_ADDRESS,
+        };
+        T::view_mut(fixture.data_mut()).unwrap().write_at(0, &header).unwrap();
+        T::init_data(fixture.data_mut());
+        Self { fixture, lamports: None, _account: PhantomData }
+    }
+
+    pub fn key(mut self, key: Pubkey) -> Self {
+        self.fixture.key = key;
+        self
+    }
+
+    pub fn program_id(mut self, program_id: Pubkey) -> Self {
+        self.fixture.owner = program_id;
+        self
+    }
+
+    /// Lamports of the account, rent-exempt balance of the final data size by default.
+    pub fn lamports(mut self, lamports: u64) -> Self {
+        self.lamports = Some(lamports);
+        self
+    }
+
+    pub fn root(self, root: Pubkey) -> Self {
+        self.header(|header| header.root = root)
+    }
+
+    /// Overrides the current version, e.g. to check the version errors.
+    pub fn version(self, version: u32) -> Self {
+        self.header(|header| header.version = version)
+    }
+
+    pub fn header(mut self, modify: impl FnOnce(&mut AccountHeader)) -> Self {
+        let mut view = T::view_mut(self.fixture.data_mut()).unwrap();
+        let mut header: AccountHeader = view.as_view().read_at(0).unwrap();
+        modify(&mut header);
+        view.write_at(0, &header).unwrap();
+        self
+    }
+
+    /// Modifies the account in place.
+    pub fn with(mut self, modify: impl FnOnce(&mut T)) -> Self {
+        modify(T::view_mut(self.fixture.padded_data_mut()).unwrap().into_mut().unwrap());
+        self
+    }
+
+    /// Copy of the account built so far.
+    pub fn account(&self) -> Box<T> {
+        T::view(self.fixture.data()).unwrap().read_boxed().unwrap()
+    }
+
+    pub fn data(&self) -> &[u8] {
+        self.fixture.data()
+    }
+
+    pub fn build(mut self) -> AccountFixture {
+        let len = self.fixture.data().len();
+        self.fixture.lamports = self.lamports.unwrap_or_else(|| Rent::default().minimum_balance(len));
+        self.fixture
+    }
+}
+
+impl<T: DevolAccountFixture> Default for DevolAccountBuilder<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl DevolAccountFixture for RootAccount {}
+impl DevolAccountFixture for MintsAccount {}
+impl DevolAccountFixture for InstrumentsAccount {}
+impl DevolAccountFixture for AllWorkersAccount {}
+impl DevolAccountFixture for PoolsTraceAccount {}
+impl DevolAccountFixture for TasksTraceAccount {}
+impl DevolAccountFixture for PoolLogsAccount {}
+impl DevolAccountFixture for PoolLogsAccountV8 {}
+impl DevolAccountFixture for ClientAccount {}
+impl DevolAccountFixture for PayoffLogAccount {}
+impl DevolAccountFixture for MintLogAccount {}
+impl DevolAccountFixture for TasksLogAccount {}
+impl DevolAccountFixture for TradeLogAccount {}
+impl DevolAccountFixture for OraclesAccount {}
+
+impl DevolAccountFixture for WorkerAccount {
+    fn init_data(data: &mut [u8]) {
+        Self::view_mut(data).unwrap().write_at(WORKER_ACCOUNT_STATE_OFFSET, &WorkerState::Unassigned).unwrap();
+    }
+}
+
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::devol_any_account::decode_any;
+    use crate::accounts::devol_regular_account::DevolRegularAccount;
+
+    fn check_decode_any<T: DevolAccountFixture>() {
+        let fixture = DevolAccountBuilder::<T>::new().build();
+        let account = decode_any(fixture.data()).unwrap();
+        assert_eq!(account.header().tag, T::expected_tag() as u32);
+        assert_eq!(account.header().root, FIXTURE_ROOT_ADDRESS);
+    }
+
+    #[test]
+    fn test_devol_account_builder_all_accounts() {
+        check_decode_any::<RootAccount>();
+        check_decode_any::<MintsAccount>();
+        check_decode_any::<InstrumentsAccount>();
+        check_decode_any::<AllWorkersAccount>();
+        check_decode_any::<PoolsTraceAccount>();
+        check_decode_any::<TasksTraceAccount>();
+        check_decode_any::<PoolLogsAccount>();
+        check_decode_any::<PoolLogsAccountV8>();
+        check_decode_any::<WorkerAccount>();
+        check_decode_any::<ClientAccount>();
+        check_decode_any::<PayoffLogAccount>();
+        check_decode_any::<MintLogAccount>();
+        check_decode_any::<TasksLogAccount>();
+        check_decode_any::<TradeLogAccount>();
+        check_decode_any::<OraclesAccount>();
+    }
+
+    #[test]
+    fn test_devol_account_builder_checks() {
+        let root = Pubkey::new_unique();
+        let mut fixture = DevolAccountBuilder::<MintsAccount>::new()
+            .root(root)
+            .with(|account| account.count = 2)
+            .lamports(5)
+            .build();
+        assert_eq!(fixture.lamports, 5);
+        let account_info = fixture.account_info(false, false);
+        let account = MintsAccount::from_account_info(&account_info, &root, &FIXTURE_PROGRAM_ID).unwrap();
+        assert_eq!(account.count, 2);
+        assert!(MintsAccount::from_account_info(&account_info, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID).is_err());
+        assert!(MintsAccount::from_account_info(&account_info, &root, &Pubkey::new_unique()).is_err());
+        assert!(MintsAccount::from_account_info_mut(&account_info, &root, &FIXTURE_PROGRAM_ID).is_err());
+
+        let mut fixture = DevolAccountBuilder::<MintsAccount>::new().version(MintsAccount::expected_version() - 1).build();
+        let account_info = fixture.account_info(false, false);
+        assert!(MintsAccount::from_account_info(&account_info, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID).is_err());
+    }
+}
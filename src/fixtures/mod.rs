@@ -0,0 +1,5 @@
+pub mod account_fixture;
+pub mod devol_account_builder;
+pub mod worker_account_builder;
+pub mod client_account_builder;
+pub mod pool_logs_account_builder;
@@ -0,0 +1,47 @@
+use crate::accounts::worker::pool_logs::pool_log_record::PoolLogRecord;
+use crate::accounts::worker::pool_logs::pool_logs_account::{POOLS_LOG_BUFFER_CAPACITY, PoolLogsAccount};
+use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+pub type PoolLogsAccountBuilder = DevolAccountBuilder<PoolLogsAccount>;
+
+impl DevolAccountBuilder<PoolLogsAccount> {
+    pub fn worker_id(self, worker_id: u32) -> Self {
+        self.with(|account| account.worker_id = worker_id)
+    }
+
+    /// Writes the record the way the program does: to the `pools_count` position of the ring
+    /// buffer, then counts it and makes its pool the last one.
+    pub fn push_record(self, record: &PoolLogRecord) -> Self {
+        self.with(|account| {
+            let index = account.pools_count as usize % POOLS_LOG_BUFFER_CAPACITY;
+            account.pool_log_records[index] = *record;
+            account.pools_count += 1;
+            account.last_pool_id = record.pool_id;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::devol_any_account::{decode_any, DevolAnyAccount};
+
+    #[test]
+    fn test_pool_logs_account_builder() {
+        let mut builder = PoolLogsAccountBuilder::new().worker_id(4);
+        for pool_id in 0..POOLS_LOG_BUFFER_CAPACITY as u32 + 2 {
+            builder = builder.push_record(&PoolLogRecord { pool_id, ..PoolLogRecord::default() });
+        }
+        let fixture = builder.build();
+        match decode_any(fixture.data()).unwrap() {
+            DevolAnyAccount::PoolsLog(account) => {
+                assert_eq!(account.worker_id, 4);
+                assert_eq!(account.pools_count, POOLS_LOG_BUFFER_CAPACITY as u32 + 2);
+                assert_eq!(account.last_pool_id, POOLS_LOG_BUFFER_CAPACITY as u32 + 1);
+                assert_eq!(account.pool_log_records[1].pool_id, POOLS_LOG_BUFFER_CAPACITY as u32 + 1);
+                assert_eq!(account.pool_log_records[2].pool_id, 2);
+            }
+            _ => panic!("Pools log account expected"),
+        }
+    }
+}
@@ -0,0 +1,55 @@
+use crate::accounts::worker::worker_account::WorkerAccount;
+use crate::accounts::worker::worker_state::WorkerState;
+use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+
+pub type WorkerAccountBuilder = DevolAccountBuilder<WorkerAccount>;
+
+impl DevolAccountBuilder<WorkerAccount> {
+    pub fn id(self, id: u32) -> Self {
+        self.with(|account| account.id = id)
+    }
+
+    pub fn state(self, state: WorkerState) -> Self {
+        self.with(|account| account.state = state)
+    }
+
+    /// Assigns the worker to the task of the instrument.
+    pub fn task(self, instr_id: u32, task_id: u32) -> Self {
+        self.with(|account| {
+            account.instr_id = instr_id;
+            account.task_id = task_id;
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::devol_indexed_account::DevolIndexedAccount;
+    use crate::fixtures::devol_account_builder::{FIXTURE_PROGRAM_ID, FIXTURE_ROOT_ADDRESS};
+
+    #[test]
+    fn test_worker_account_builder() {
+        let mut fixture = WorkerAccountBuilder::new().id(3).state(WorkerState::Active).task(2, 17).build();
+        let account_info = fixture.account_info(false, true);
+        let account = WorkerAccount::from_account_info_mut(&account_info, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID, Some(3)).unwrap();
+        assert_eq!(account.state, WorkerState::Active);
+        assert_eq!((account.instr_id, account.task_id), (2, 17));
+        assert!(account_info.data.try_borrow().is_err());
+        drop(account);
+        assert!(WorkerAccount::from_account_info(&account_info, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID, Some(4)).is_err());
+
+        let account = WorkerAccountBuilder::new().account();
+        assert_eq!(account.state, WorkerState::Unassigned);
+    }
+
+    #[cfg(feature = "solana-sdk")]
+    #[test]
+    fn test_worker_account_builder_rpc_account() {
+        let fixture = WorkerAccountBuilder::new().id(5).build();
+        let mut account = fixture.to_account();
+        let worker = WorkerAccount::from_account(&fixture.key, &mut account, &FIXTURE_ROOT_ADDRESS, &FIXTURE_PROGRAM_ID, Some(5)).unwrap();
+        assert_eq!(worker.id, 5);
+        assert_eq!(account.lamports, fixture.lamports);
+    }
+}
@@ -2,11 +2,6 @@ use std::error::Error;
 use async_trait::async_trait;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
-use crate::account_readers::dvl_readable::DvlIndexParam;
-use crate::accounts::root::root_account::RootAccount;
-use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
-use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
-use crate::accounts::worker::worker_account::WorkerAccount;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
 use crate::instructions_data::dvl_instruction_data::DvlInstructionData;
@@ -27,10 +22,8 @@ impl AsTransactionInstruction for InstructionFinPool {
         transaction_params: Self::DvlTransactionInstructionParams,
     ) -> Result<Box<Instruction>, Box<dyn Error>> {
         let data = self.to_vec_le();
-        let root_acc_key = client.account_public_key::<RootAccount>(()).await?;
-        let worker_acc_key = client.account_public_key::<WorkerAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let pools_trace_key = client.account_public_key::<PoolsTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let tasks_trace_key = client.account_public_key::<TasksTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
+        let platform = client.get_platform_accounts().await?;
+        let worker = platform.worker(transaction_params.worker_id);
         let account_metas = Vec::from([
             AccountMeta {
                 pubkey: *signer,
@@ -38,22 +31,22 @@ impl AsTransactionInstruction for InstructionFinPool {
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *root_acc_key,
+                pubkey: platform.root_key,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *worker_acc_key,
+                pubkey: worker.address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *tasks_trace_key,
+                pubkey: worker.tasks_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *pools_trace_key,
+                pubkey: worker.pools_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
@@ -2,13 +2,6 @@ use std::error::Error;
 use async_trait::async_trait;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
-use crate::account_readers::dvl_readable::DvlIndexParam;
-use crate::accounts::instruments::instruments_account::InstrumentsAccount;
-use crate::accounts::root::root_account::RootAccount;
-use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
-use crate::accounts::worker::tasks_log::tasks_log_account::TasksLogAccount;
-use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
-use crate::accounts::worker::worker_account::WorkerAccount;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
 use crate::instructions_data::dvl_instruction_data::DvlInstructionData;
@@ -30,13 +23,9 @@ impl AsTransactionInstruction for InstructionLpTrade {
         transaction_params: Self::DvlTransactionInstructionParams,
     ) -> Result<Box<Instruction>, Box<dyn Error>> {
         let data = self.to_vec_le();
-        let root_acc_key = client.account_public_key::<RootAccount>(()).await?;
-        let instruments_acc_key = client.account_public_key::<InstrumentsAccount>(()).await?;
+        let platform = client.get_platform_accounts().await?;
+        let worker = platform.worker(transaction_params.worker_id);
         let client_acc_key = transaction_params.client_key;
-        let worker_acc_key = client.account_public_key::<WorkerAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let pools_trace_key = client.account_public_key::<PoolsTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let tasks_trace_key = client.account_public_key::<TasksTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let tasks_log_key = client.account_public_key::<TasksLogAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
         let account_metas = Vec::from([
             AccountMeta {
                 pubkey: *signer,
@@ -44,12 +33,12 @@ impl AsTransactionInstruction for InstructionLpTrade {
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *root_acc_key,
+                pubkey: platform.root_key,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *instruments_acc_key,
+                pubkey: platform.instruments_key(),
                 is_signer: false,
                 is_writable: false,
             },
@@ -59,22 +48,22 @@ impl AsTransactionInstruction for InstructionLpTrade {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *worker_acc_key,
+                pubkey: worker.address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *tasks_trace_key,
+                pubkey: worker.tasks_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *pools_trace_key,
+                pubkey: worker.pools_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *tasks_log_key,
+                pubkey: worker.tasks_log_address,
                 is_signer: false,
                 is_writable: true,
             }
@@ -3,15 +3,8 @@ use async_trait::async_trait;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
 use solana_program::system_program;
-use crate::account_readers::dvl_readable::{DvlClientParams, DvlIndexParam};
+use crate::account_readers::dvl_readable::DvlClientParams;
 use crate::accounts::client::client_account::client_account::ClientAccount;
-use crate::accounts::instruments::instruments_account::InstrumentsAccount;
-use crate::accounts::oracles::oracles_account::OraclesAccount;
-use crate::accounts::root::root_account::RootAccount;
-use crate::accounts::worker::pool_logs::pool_logs_account::PoolLogsAccount;
-use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
-use crate::accounts::worker::tasks_trace::tasks_trace_account::TasksTraceAccount;
-use crate::accounts::worker::worker_account::WorkerAccount;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
 use crate::instructions_data::dvl_instruction_data::DvlInstructionData;
@@ -33,23 +26,17 @@ impl AsTransactionInstruction for InstructionOptionTrade {
         transaction_params: Self::DvlTransactionInstructionParams,
     ) -> Result<Box<Instruction>, Box<dyn Error>> {
         let data = self.to_vec_le();
-        let root_acc_key = client.account_public_key::<RootAccount>(()).await?;
+        let platform = client.get_platform_accounts().await?;
+        let worker = platform.worker(transaction_params.worker_id);
         let client_acc_key = client.account_public_key::<ClientAccount>(DvlClientParams {
             client_address: &transaction_params.client_key,
             signer_account_params: None,
         }).await?;
-        let instruments_acc_key = client.account_public_key::<InstrumentsAccount>(()).await?;
-        let worker_acc_key = client.account_public_key::<WorkerAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let pools_trace_key = client.account_public_key::<PoolsTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let tasks_trace_key = client.account_public_key::<TasksTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let pools_log_key = client.account_public_key::<PoolLogsAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let worker_account = client.get_account::<WorkerAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
+        let worker_account = platform.fetch_worker(client, transaction_params.worker_id).await?;
         let instr_id = worker_account.instr_id;
-        let instrument_account = client.get_account::<InstrumentsAccount>(()).await?;
-        let oracle_id = instrument_account.data[instr_id as usize].assigned_oracle_num;
-        let oracle_acc = client.get_account::<OraclesAccount>(()).await?;
+        let oracle_id = platform.instruments.data[instr_id as usize].assigned_oracle_num;
+        let oracle_acc = &platform.oracles;
         let oracle_data = oracle_acc.data[oracle_id as usize];
-        let oracles_account_key = client.account_public_key::<OraclesAccount>(()).await?;
         let ext_oracle_account1_key = oracle_data.params[0].account;
         let ext_oracle_account2_key = oracle_data.params[1].account;
         let ext_oracle_account3_key = oracle_data.params[2].account;
@@ -68,7 +55,7 @@ impl AsTransactionInstruction for InstructionOptionTrade {
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *root_acc_key,
+                pubkey: platform.root_key,
                 is_signer: false,
                 is_writable: false,
             },
@@ -78,33 +65,33 @@ impl AsTransactionInstruction for InstructionOptionTrade {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *instruments_acc_key,
+                pubkey: platform.instruments_key(),
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *worker_acc_key,
+                pubkey: worker.address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *tasks_trace_key,
+                pubkey: worker.tasks_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *pools_trace_key,
+                pubkey: worker.pools_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *pools_log_key,
+                pubkey: worker.pools_log_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta::new_readonly(system_program::id(), false),
             AccountMeta {
-                pubkey: *oracles_account_key,
+                pubkey: platform.oracles_key,
                 is_signer: false,
                 is_writable: false,
             },
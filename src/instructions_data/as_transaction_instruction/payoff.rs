@@ -2,14 +2,13 @@ use std::error::Error;
 use async_trait::async_trait;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
-use crate::account_readers::dvl_readable::{DvlClientParams, DvlIndexParam};
+use crate::account_readers::dvl_readable::DvlClientParams;
 use crate::accounts::client::client_account::client_account::ClientAccount;
-use crate::accounts::client::client_account::signer_account_params::SignerAccountParams;
-use crate::accounts::instruments::instruments_account::InstrumentsAccount;
-use crate::accounts::root::root_account::RootAccount;
-use crate::accounts::worker::pools_trace::pools_trace_account::PoolsTraceAccount;
-use crate::accounts::worker::worker_account::WorkerAccount;
+use crate::accounts::client::client_account::client_account_snapshot::ClientAccountSnapshot;
 use crate::dvl_client::dvl_client::DvlClient;
+use crate::dvl_client::platform_accounts::take_account;
+use crate::dvl_error::DvlError;
+use crate::errors::{AccountTag, ContractError};
 use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
 use crate::instructions_data::dvl_instruction_data::DvlInstructionData;
 use crate::instructions_data::payoff::InstructionPayoff;
@@ -30,18 +29,17 @@ impl AsTransactionInstruction for InstructionPayoff {
         transaction_params: Self::DvlTransactionInstructionParams,
     ) -> Result<Box<Instruction>, Box<dyn Error>> {
         let data = self.to_vec_le();
-        let root_acc_key = client.account_public_key::<RootAccount>(()).await?;
         let client_acc_key = transaction_params.client_key;
-        let devol_sign_flag = true;
-        let signer_account_params = SignerAccountParams {
-            signer: &client_acc_key,
-            devol_sign: devol_sign_flag,
-        };
-        let signer_account_params_option: Option<&SignerAccountParams> = Some(&signer_account_params);
-        let client_acc = client.get_account::<ClientAccount>(DvlClientParams { client_address: &transaction_params.client_key, signer_account_params: signer_account_params_option }).await?;
-        let instruments_acc_key = client.account_public_key::<InstrumentsAccount>(()).await?;
-        let worker_acc_key = client.account_public_key::<WorkerAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
-        let pools_trace_key = client.account_public_key::<PoolsTraceAccount>(DvlIndexParam { id: transaction_params.worker_id }).await?;
+        let client_acc_pda = client.account_public_key::<ClientAccount>(DvlClientParams {
+            client_address: &client_acc_key,
+            signer_account_params: None,
+        }).await?;
+        let (platform, accounts) = client.get_platform_accounts_with(&[*client_acc_pda]).await?;
+        let worker = platform.worker(transaction_params.worker_id);
+        let client_acc: Box<ClientAccountSnapshot> = take_account(&mut accounts.into_iter(), AccountTag::Client)?;
+        if client_acc.signer_address != client_acc_key {
+            return Err(Box::new(DvlError::new_with_account(AccountTag::Client, ContractError::AccountNotSigner)));
+        }
 
         let account_metas = Vec::from([
             AccountMeta {
@@ -50,7 +48,7 @@ impl AsTransactionInstruction for InstructionPayoff {
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *root_acc_key,
+                pubkey: platform.root_key,
                 is_signer: false,
                 is_writable: false,
             },
@@ -65,17 +63,17 @@ impl AsTransactionInstruction for InstructionPayoff {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *instruments_acc_key,
+                pubkey: platform.instruments_key(),
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *worker_acc_key,
+                pubkey: worker.address,
                 is_signer: false,
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *pools_trace_key,
+                pubkey: worker.pools_trace_address,
                 is_signer: false,
                 is_writable: true,
             },
@@ -86,4 +84,58 @@ impl AsTransactionInstruction for InstructionPayoff {
             account_metas,
         )))
     }
-}
\ No newline at end of file
+}
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::accounts::all_workers::all_workers_account::AllWorkersAccount;
+    use crate::accounts::instruments::instruments_account::InstrumentsAccount;
+    use crate::accounts::mints::mints_account::MintsAccount;
+    use crate::accounts::oracles::oracles_account::OraclesAccount;
+    use crate::accounts::root::root_account::RootAccount;
+    use crate::fixtures::client_account_builder::ClientAccountBuilder;
+    use crate::fixtures::devol_account_builder::DevolAccountBuilder;
+    use crate::instructions_data::constructors::payoff::PayoffParams;
+    use crate::instructions_data::dvl_instruction_data::DvlInstruction;
+    use crate::tests::tests::setup_memory_client;
+
+    #[tokio::test]
+    async fn test_payoff_instruction_requests() {
+        let (source, client) = setup_memory_client();
+        let root_key = client.root_pda.key;
+        let (instruments_key, workers_key, mints_key) = (Pubkey::new_unique(), Pubkey::new_unique(), Pubkey::new_unique());
+        let (worker_key, payoff_log) = (Pubkey::new_unique(), Pubkey::new_unique());
+        source.insert_fixture(&DevolAccountBuilder::<RootAccount>::new().key(root_key).with(|root| {
+            root.instruments_address = instruments_key;
+            root.workers_address = workers_key;
+            root.mints_address = mints_key;
+        }).build());
+        let oracles_key = *client.account_public_key::<OraclesAccount>(()).await.unwrap();
+        source.insert_fixture(&DevolAccountBuilder::<OraclesAccount>::new().key(oracles_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<InstrumentsAccount>::new().key(instruments_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<MintsAccount>::new().key(mints_key).root(root_key).build());
+        source.insert_fixture(&DevolAccountBuilder::<AllWorkersAccount>::new().key(workers_key).root(root_key).with(|all_workers| {
+            all_workers.count = 1;
+            all_workers.workers[0].address = worker_key;
+        }).build());
+        let client_key = Pubkey::new_unique();
+        let params = DvlClientParams { client_address: &client_key, signer_account_params: None };
+        let client_acc_pda = *client.account_public_key::<ClientAccount>(params).await.unwrap();
+        source.insert_fixture(&ClientAccountBuilder::new()
+            .key(client_acc_pda)
+            .root(root_key)
+            .signer_address(client_key)
+            .with(|account| account.payoff_log = payoff_log)
+            .build());
+
+        let data = DvlInstruction::new::<InstructionPayoff>(PayoffParams { worker_id: 0, pool_id: 0 }).unwrap();
+        let transaction_params = PayoffTransactionParams { worker_id: 0, client_key };
+        let instruction = data.as_transaction_instruction(&client, &client_key, transaction_params).await.unwrap();
+        assert_eq!(source.read_requests(), 2);
+        assert_eq!(instruction.accounts[3].pubkey, payoff_log);
+        assert_eq!(instruction.accounts[5].pubkey, worker_key);
+
+        let transaction_params = PayoffTransactionParams { worker_id: 0, client_key: Pubkey::new_unique() };
+        assert!(data.as_transaction_instruction(&client, &client_key, transaction_params).await.is_err());
+    }
+}
@@ -3,10 +3,6 @@ use std::str::FromStr;
 use async_trait::async_trait;
 use solana_program::instruction::{AccountMeta, Instruction};
 use solana_program::pubkey::Pubkey;
-use crate::account_readers::dvl_readable::DvlIndexParam;
-use crate::accounts::mints::mint_log::mint_log_account::MintLogAccount;
-use crate::accounts::mints::mints_account::MintsAccount;
-use crate::accounts::root::root_account::RootAccount;
 use crate::constants::TOKEN_PROGRAM_ID;
 use crate::dvl_client::dvl_client::DvlClient;
 use crate::instructions_data::as_transaction_instruction::as_transaction_instruction::AsTransactionInstruction;
@@ -29,13 +25,9 @@ impl AsTransactionInstruction for InstructionTransferToken {
         transaction_params: Self::DvlTransactionInstructionParams,
     ) -> Result<Box<Instruction>, Box<dyn Error>> {
         let data = self.to_vec_le();
-        let root_acc_key = client.account_public_key::<RootAccount>(()).await?;
-        let mint_acc_key = client.account_public_key::<MintsAccount>(()).await?;
-        let mints_acc = client.get_account::<MintsAccount>(()).await?;
-        let mints_program_acc_key = mints_acc.data[transaction_params.mint_id as usize].program_address;
-        let mints_address_acc_key = mints_acc.data[transaction_params.mint_id as usize].address;
+        let platform = client.get_platform_accounts().await?;
+        let mint = platform.mint(transaction_params.mint_id);
         let client_acc_key = transaction_params.client_key;
-        let log_acc_key = client.account_public_key::<MintLogAccount>(DvlIndexParam { id: transaction_params.mint_id }).await?;
         let token_program_id_key = Pubkey::from_str(TOKEN_PROGRAM_ID)?;
 
         let account_metas = Vec::from([
@@ -45,17 +37,17 @@ impl AsTransactionInstruction for InstructionTransferToken {
                 is_writable: false,
             },
             AccountMeta {
-                pubkey: *root_acc_key,
+                pubkey: platform.root_key,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: mints_program_acc_key,
+                pubkey: mint.program_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *mint_acc_key,
+                pubkey: platform.mints_key(),
                 is_signer: false,
                 is_writable: false,
             },
@@ -70,12 +62,12 @@ impl AsTransactionInstruction for InstructionTransferToken {
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: *log_acc_key,
+                pubkey: mint.log_address,
                 is_signer: false,
                 is_writable: true,
             },
             AccountMeta {
-                pubkey: mints_address_acc_key,
+                pubkey: mint.address,
                 is_signer: false,
                 is_writable: true,
             },
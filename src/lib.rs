@@ -6,9 +6,12 @@ pub mod utils;
 pub mod tests;
 pub mod dvl_error;
 pub mod instructions_data;
+#[cfg(any(test, feature = "fixtures"))]
+pub mod fixtures;
 cfg_if::cfg_if! {
     if #[cfg(not(feature = "on-chain"))] {
         pub mod account_readers;
+        pub mod account_views;
         pub mod dvl_off_chain_error;
         pub mod dvl_client;
     }
@@ -4,10 +4,13 @@ mod platform_test;
 #[cfg(test)]
 pub(crate) mod tests {
     use std::str::FromStr;
+    use std::sync::Arc;
     use solana_client::nonblocking::rpc_client::RpcClient;
     use solana_program::pubkey::Pubkey;
+    use crate::dvl_client::account_source::MemoryAccountSource;
     use crate::dvl_client::dvl_client::DvlClient;
     use crate::constants::test_constants::*;
+    use crate::fixtures::devol_account_builder::FIXTURE_PROGRAM_ID;
 
     pub fn setup_devol_client() -> DvlClient {
         let client = RpcClient::new(String::from(RPC_URL));
@@ -16,4 +19,12 @@ pub(crate) mod tests {
         let reader = DvlClient::new(client, INT_SEED, admin_pub_key, program_id);
         reader
     }
-}
\ No newline at end of file
+
+    /// Offline client of the fixture program over an empty `MemoryAccountSource`, which is
+    /// returned to insert the fixtures into.
+    pub fn setup_memory_client() -> (Arc<MemoryAccountSource>, DvlClient) {
+        let source = Arc::new(MemoryAccountSource::new());
+        let client = DvlClient::with_source(source.clone(), 1, Pubkey::new_unique(), FIXTURE_PROGRAM_ID);
+        (source, client)
+    }
+}
@@ -1,4 +1,6 @@
 use serde::{Deserialize, Serialize};
+use crate::accounts::devol_parse::{DevolParse, DevolWrite, LeParser, LeWriter};
+use crate::dvl_error::DvlError;
 
 #[repr(u16)]
 #[derive(Copy, Clone, PartialOrd, PartialEq, Debug, Serialize, Deserialize)]
@@ -6,3 +8,19 @@ pub enum PutOrCall {
     CALL = 0,
     PUT = 1,
 }
+
+impl DevolParse for PutOrCall {
+    fn parse_at(parser: &LeParser, offset: usize) -> Result<Self, DvlError> {
+        match parser.u16(offset)? {
+            0 => Ok(PutOrCall::CALL),
+            1 => Ok(PutOrCall::PUT),
+            _ => Err(parser.field_error()),
+        }
+    }
+}
+
+impl DevolWrite for PutOrCall {
+    fn write_at(&self, writer: &mut LeWriter, offset: usize) -> Result<(), DvlError> {
+        writer.u16(offset, *self as u16)
+    }
+}